@@ -29,6 +29,10 @@ const OCCT_LIBS: &[&str] = &[
     "TKCAF",
     "TKLCAF",
     "TKXCAF",
+    "TKVCAF",
+    "TKV3d",
+    "TKService",
+    "TKHLR",
 ];
 
 fn main() {
@@ -51,11 +55,13 @@ fn main() {
 
     // TODO(bschwind) - Iterate over the src/ directory to populate this.
     let rust_bridges = [
+        "src/approx.rs",
         "src/b_rep.rs",
         "src/b_rep_adaptor.rs",
         "src/b_rep_algo_api.rs",
         "src/b_rep_bnd_lib.rs",
         "src/b_rep_builder_api.rs",
+        "src/b_rep_check.rs",
         "src/b_rep_feat.rs",
         "src/b_rep_fillet_api.rs",
         "src/b_rep_g_prop.rs",
@@ -76,22 +82,33 @@ fn main() {
         "src/gc.rs",
         "src/gc_pnts.rs",
         "src/gp.rs",
+        "src/hlr_b_rep.rs",
         "src/if_select.rs",
+        "src/interface.rs",
         "src/iges_control.rs",
         "src/law.rs",
         "src/message.rs",
         "src/poly.rs",
+        "src/quantity.rs",
         "src/shape_analysis.rs",
+        "src/shape_fix.rs",
         "src/shape_upgrade.rs",
         "src/standard.rs",
+        "src/step_caf_control.rs",
         "src/step_control.rs",
         "src/stl_api.rs",
         "src/t_col_gp.rs",
+        "src/t_col_std.rs",
+        "src/t_data_std.rs",
+        "src/t_doc_std.rs",
+        "src/tdf.rs",
         "src/top_abs.rs",
         "src/top_exp.rs",
         "src/top_loc.rs",
         "src/top_tools.rs",
         "src/topo_ds.rs",
+        "src/xcaf_app.rs",
+        "src/xcaf_doc.rs",
     ];
 
     let mut build = cxx_build::bridges(rust_bridges);
@@ -0,0 +1,2 @@
+#include <Approx_ParametrizationType.hxx>
+#include <bindings_common.hxx>
@@ -2,6 +2,7 @@
 #include <BRep_Tool.hxx>
 #include <Geom_Curve.hxx>
 #include <Geom_Surface.hxx>
+#include <Poly_PolygonOnTriangulation.hxx>
 #include <TopLoc_Location.hxx>
 #include <TopoDS_Builder.hxx>
 #include <TopoDS_Edge.hxx>
@@ -30,3 +31,10 @@ inline std::unique_ptr<Handle_Poly_Triangulation> BRep_Tool_Triangulation(const
   return std::unique_ptr<Handle_Poly_Triangulation>(
       new opencascade::handle<Poly_Triangulation>(BRep_Tool::Triangulation(face, location)));
 }
+
+inline std::unique_ptr<Handle_Poly_PolygonOnTriangulation>
+BRep_Tool_PolygonOnTriangulation(const TopoDS_Edge &edge, const Handle_Poly_Triangulation &triangulation,
+                                 const TopLoc_Location &location) {
+  return std::unique_ptr<Handle_Poly_PolygonOnTriangulation>(new opencascade::handle<Poly_PolygonOnTriangulation>(
+      BRep_Tool::PolygonOnTriangulation(edge, triangulation, location)));
+}
@@ -1,7 +1,55 @@
 #include <BRepAdaptor_Curve.hxx>
+#include <GeomConvert.hxx>
+#include <Geom_BSplineCurve.hxx>
+#include <Geom_BezierCurve.hxx>
+#include <Precision.hxx>
 #include <bindings_common.hxx>
+#include <gp_Circ.hxx>
 #include <gp_Pnt.hxx>
 
 inline std::unique_ptr<gp_Pnt> BRepAdaptor_Curve_value(const BRepAdaptor_Curve &curve, const Standard_Real U) {
   return std::unique_ptr<gp_Pnt>(new gp_Pnt(curve.Value(U)));
 }
+
+inline std::unique_ptr<gp_Circ> BRepAdaptor_Curve_circle(const BRepAdaptor_Curve &curve) {
+  return std::unique_ptr<gp_Circ>(new gp_Circ(curve.Circle()));
+}
+
+// Extracts a B-spline or Bezier curve, trimmed to the edge and made non-periodic. Poles
+// are stored as consecutive x, y, z triples. Returns the degree.
+inline Standard_Integer BRepAdaptor_Curve_bspline_data(const BRepAdaptor_Curve &curve, rust::Vec<double> &poles,
+                                                       rust::Vec<double> &weights, rust::Vec<double> &knots,
+                                                       rust::Vec<int32_t> &multiplicities) {
+  Handle(Geom_BSplineCurve) bspline;
+  if (curve.GetType() == GeomAbs_BezierCurve) {
+    bspline = GeomConvert::CurveToBSplineCurve(curve.Bezier());
+  } else {
+    bspline = Handle(Geom_BSplineCurve)::DownCast(curve.BSpline()->Copy());
+  }
+
+  if (bspline->IsPeriodic()) {
+    bspline->SetNotPeriodic();
+  }
+
+  Standard_Real first = curve.FirstParameter();
+  Standard_Real last = curve.LastParameter();
+  if (first > bspline->FirstParameter() + Precision::PConfusion() ||
+      last < bspline->LastParameter() - Precision::PConfusion()) {
+    bspline->Segment(first, last);
+  }
+
+  for (Standard_Integer i = 1; i <= bspline->NbPoles(); i++) {
+    const gp_Pnt &pole = bspline->Pole(i);
+    poles.push_back(pole.X());
+    poles.push_back(pole.Y());
+    poles.push_back(pole.Z());
+    weights.push_back(bspline->Weight(i));
+  }
+
+  for (Standard_Integer i = 1; i <= bspline->NbKnots(); i++) {
+    knots.push_back(bspline->Knot(i));
+    multiplicities.push_back(bspline->Multiplicity(i));
+  }
+
+  return bspline->Degree();
+}
@@ -4,3 +4,4 @@
 #include <BRepAlgoAPI_Fuse.hxx>
 #include <BRepAlgoAPI_Section.hxx>
 #include <bindings_common.hxx>
+#include <gp_Pln.hxx>
@@ -0,0 +1,2 @@
+#include <BRepCheck_Analyzer.hxx>
+#include <bindings_common.hxx>
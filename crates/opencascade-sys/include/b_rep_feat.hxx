@@ -1,3 +1,6 @@
 #include <BRepFeat_MakeCylindricalHole.hxx>
 #include <BRepFeat_MakeDPrism.hxx>
+#include <BRepFeat_MakeLinearForm.hxx>
+#include <BRepFeat_MakePrism.hxx>
+#include <BRepFeat_MakeRevol.hxx>
 #include <bindings_common.hxx>
@@ -1,2 +1,30 @@
 #include <BRepMesh_IncrementalMesh.hxx>
+#include <IMeshTools_Parameters.hxx>
 #include <bindings_common.hxx>
+
+inline void IMeshTools_Parameters_set_deflection(IMeshTools_Parameters &parameters, double deflection) {
+  parameters.Deflection = deflection;
+  parameters.DeflectionInterior = deflection;
+}
+
+inline void IMeshTools_Parameters_set_angle(IMeshTools_Parameters &parameters, double angle) {
+  parameters.Angle = angle;
+  parameters.AngleInterior = angle;
+}
+
+inline void IMeshTools_Parameters_set_relative(IMeshTools_Parameters &parameters, bool relative) {
+  parameters.Relative = relative;
+}
+
+inline void IMeshTools_Parameters_set_min_size(IMeshTools_Parameters &parameters, double min_size) {
+  parameters.MinSize = min_size;
+}
+
+inline void IMeshTools_Parameters_set_internal_vertices_mode(IMeshTools_Parameters &parameters,
+                                                             bool internal_vertices_mode) {
+  parameters.InternalVerticesMode = internal_vertices_mode;
+}
+
+inline void IMeshTools_Parameters_set_in_parallel(IMeshTools_Parameters &parameters, bool in_parallel) {
+  parameters.InParallel = in_parallel;
+}
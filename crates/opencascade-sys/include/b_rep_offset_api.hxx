@@ -1,4 +1,6 @@
+#include <BRepOffsetAPI_MakeFilling.hxx>
 #include <BRepOffsetAPI_MakeOffset.hxx>
+#include <BRepOffsetAPI_MakeOffsetShape.hxx>
 #include <BRepOffsetAPI_MakePipe.hxx>
 #include <BRepOffsetAPI_MakePipeShell.hxx>
 #include <BRepOffsetAPI_MakeThickSolid.hxx>
@@ -7,3 +9,17 @@
 #include <TopTools_ListOfShape.hxx>
 #include <TopoDS_Shape.hxx>
 #include <bindings_common.hxx>
+
+// Moves every face of a shape along its normal, without trimming the faces
+// against each other. Throws when the offset fails.
+inline std::unique_ptr<TopoDS_Shape> BRepOffsetAPI_MakeOffsetShape_simple(const TopoDS_Shape &shape,
+                                                                        Standard_Real offset) {
+  BRepOffsetAPI_MakeOffsetShape make_offset;
+  make_offset.PerformBySimple(shape, offset);
+
+  if (!make_offset.IsDone()) {
+    throw std::runtime_error("simple offset failed");
+  }
+
+  return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(make_offset.Shape()));
+}
@@ -21,3 +21,23 @@ inline std::unique_ptr<TopoDS_Shape> read_brep_text(rust::String path) {
     return std::unique_ptr<TopoDS_Shape>(nullptr);
   }
 }
+
+inline void write_brep_text_stream(const TopoDS_Shape &shape, rust::Vec<uint8_t> &data) {
+  std::ostringstream stream;
+  BRepTools::Write(shape, stream);
+  string_to_vec(stream.str(), data);
+}
+
+inline std::unique_ptr<TopoDS_Shape> read_brep_text_stream(rust::Slice<const uint8_t> data) {
+  BRep_Builder builder;
+  std::istringstream stream(slice_to_string(data));
+  auto shape = std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape());
+
+  BRepTools::Read(*shape, stream, builder);
+
+  if (shape->IsNull()) {
+    return std::unique_ptr<TopoDS_Shape>(nullptr);
+  } else {
+    return shape;
+  }
+}
@@ -15,3 +15,22 @@ inline std::unique_ptr<TopoDS_Shape> read_brep_bin(rust::String path) {
     return std::unique_ptr<TopoDS_Shape>(nullptr);
   }
 }
+
+inline void write_brep_bin_stream(const TopoDS_Shape &shape, rust::Vec<uint8_t> &data) {
+  std::ostringstream stream(std::ios::out | std::ios::binary);
+  BinTools::Write(shape, stream);
+  string_to_vec(stream.str(), data);
+}
+
+inline std::unique_ptr<TopoDS_Shape> read_brep_bin_stream(rust::Slice<const uint8_t> data) {
+  std::istringstream stream(slice_to_string(data), std::ios::in | std::ios::binary);
+  auto shape = std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape());
+
+  BinTools::Read(*shape, stream);
+
+  if (shape->IsNull()) {
+    return std::unique_ptr<TopoDS_Shape>(nullptr);
+  } else {
+    return shape;
+  }
+}
@@ -2,6 +2,8 @@
 #include "rust/cxx.h"
 #include <NCollection_List.hxx>
 #include <memory>
+#include <sstream>
+#include <string>
 
 // Generic template constructor
 template <typename T, typename... Args> std::unique_ptr<T> construct_unique(Args... args) {
@@ -23,3 +25,15 @@ template <typename T> const T &handle_try_deref(const opencascade::handle<T> &ha
   }
   return *handle;
 }
+
+// Byte buffers, for reading and writing through streams
+inline std::string slice_to_string(rust::Slice<const uint8_t> data) {
+  return std::string(reinterpret_cast<const char *>(data.data()), data.size());
+}
+
+inline void string_to_vec(const std::string &data, rust::Vec<uint8_t> &out) {
+  out.reserve(out.size() + data.size());
+  for (char c : data) {
+    out.push_back(static_cast<uint8_t>(c));
+  }
+}
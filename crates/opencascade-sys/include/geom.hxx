@@ -1,10 +1,13 @@
 #include <Geom_BSplineCurve.hxx>
+#include <Geom_BSplineSurface.hxx>
 #include <Geom_BezierCurve.hxx>
 #include <Geom_BezierSurface.hxx>
+#include <Geom_ConicalSurface.hxx>
 #include <Geom_CylindricalSurface.hxx>
 #include <Geom_Plane.hxx>
 #include <Geom_Surface.hxx>
 #include <Geom_TrimmedCurve.hxx>
+#include <TColgp_HArray1OfPnt.hxx>
 #include <bindings_common.hxx>
 
 inline std::unique_ptr<Handle_Geom_CylindricalSurface> Geom_CylindricalSurface_new(const gp_Ax3 &axis, double radius) {
@@ -16,11 +19,46 @@ inline std::unique_ptr<Handle_Geom_Surface> cylinder_to_surface(const Handle_Geo
   return std::unique_ptr<Handle_Geom_Surface>(new opencascade::handle<Geom_Surface>(cylinder_handle));
 }
 
+inline std::unique_ptr<Handle_Geom_ConicalSurface> Geom_ConicalSurface_new(const gp_Ax3 &axis, double semi_angle,
+                                                                          double radius) {
+  return std::unique_ptr<Handle_Geom_ConicalSurface>(
+      new opencascade::handle<Geom_ConicalSurface>(new Geom_ConicalSurface(axis, semi_angle, radius)));
+}
+
+inline std::unique_ptr<Handle_Geom_Surface> cone_to_surface(const Handle_Geom_ConicalSurface &cone_handle) {
+  return std::unique_ptr<Handle_Geom_Surface>(new opencascade::handle<Geom_Surface>(cone_handle));
+}
+
+inline std::unique_ptr<Handle_Geom_BSplineSurface>
+Geom_BSplineSurface_new(const TColgp_Array2OfPnt &poles, const TColStd_Array2OfReal &weights,
+                        const TColStd_Array1OfReal &u_knots, const TColStd_Array1OfReal &v_knots,
+                        const TColStd_Array1OfInteger &u_mults, const TColStd_Array1OfInteger &v_mults,
+                        Standard_Integer u_degree, Standard_Integer v_degree) {
+  return std::unique_ptr<Handle_Geom_BSplineSurface>(new opencascade::handle<Geom_BSplineSurface>(
+      new Geom_BSplineSurface(poles, weights, u_knots, v_knots, u_mults, v_mults, u_degree, v_degree)));
+}
+
+inline std::unique_ptr<Handle_Geom_Surface> bspline_to_surface(const Handle_Geom_BSplineSurface &bspline_handle) {
+  return std::unique_ptr<Handle_Geom_Surface>(new opencascade::handle<Geom_Surface>(bspline_handle));
+}
+
+inline std::unique_ptr<Handle_Geom_BSplineCurve>
+Geom_BSplineCurve_new(const TColgp_HArray1OfPnt &poles, const TColStd_Array1OfReal &weights,
+                      const TColStd_Array1OfReal &knots, const TColStd_Array1OfInteger &multiplicities,
+                      Standard_Integer degree) {
+  return std::unique_ptr<Handle_Geom_BSplineCurve>(new opencascade::handle<Geom_BSplineCurve>(
+      new Geom_BSplineCurve(poles.Array1(), weights, knots, multiplicities, degree)));
+}
+
 inline std::unique_ptr<Handle_Geom_BezierSurface> Geom_BezierSurface_new(const TColgp_Array2OfPnt &poles) {
   return std::unique_ptr<Handle_Geom_BezierSurface>(
       new opencascade::handle<Geom_BezierSurface>(new Geom_BezierSurface(poles)));
 }
 
+inline std::unique_ptr<Handle_Geom_Plane> Geom_Plane_new(const gp_Pnt &point, const gp_Dir &normal) {
+  return std::unique_ptr<Handle_Geom_Plane>(new opencascade::handle<Geom_Plane>(new Geom_Plane(point, normal)));
+}
+
 inline const gp_Pnt &handle_geom_plane_location(const Handle_Geom_Plane &plane) { return plane->Location(); }
 
 inline std::unique_ptr<Handle_Geom_BezierCurve>
@@ -1,4 +1,5 @@
 #include <Geom2d_Ellipse.hxx>
+#include <Geom2d_Line.hxx>
 #include <Geom2d_TrimmedCurve.hxx>
 #include <bindings_common.hxx>
 
@@ -12,6 +13,14 @@ inline std::unique_ptr<Handle_Geom2d_Curve> ellipse_to_HandleGeom2d_Curve(const
   return std::unique_ptr<Handle_Geom2d_Curve>(new opencascade::handle<Geom2d_Curve>(ellipse_handle));
 }
 
+inline std::unique_ptr<Handle_Geom2d_Line> Geom2d_Line_new(const gp_Pnt2d &point, const gp_Dir2d &dir) {
+  return std::unique_ptr<Handle_Geom2d_Line>(new opencascade::handle<Geom2d_Line>(new Geom2d_Line(point, dir)));
+}
+
+inline std::unique_ptr<Handle_Geom2d_Curve> line_to_HandleGeom2d_Curve(const Handle_Geom2d_Line &line_handle) {
+  return std::unique_ptr<Handle_Geom2d_Curve>(new opencascade::handle<Geom2d_Curve>(line_handle));
+}
+
 inline std::unique_ptr<Handle_Geom2d_TrimmedCurve> Geom2d_TrimmedCurve_new(const Handle_Geom2d_Curve &curve, double u1,
                                                                            double u2) {
   return std::unique_ptr<Handle_Geom2d_TrimmedCurve>(
@@ -1,3 +1,4 @@
 #include <GeomAbs_CurveType.hxx>
 #include <GeomAbs_JoinType.hxx>
+#include <GeomAbs_Shape.hxx>
 #include <bindings_common.hxx>
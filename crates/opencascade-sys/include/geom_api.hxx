@@ -1,8 +1,16 @@
 #include <GeomAPI_Interpolate.hxx>
+#include <GeomAPI_PointsToBSplineSurface.hxx>
 #include <GeomAPI_ProjectPointOnSurf.hxx>
 #include <Geom_BSplineCurve.hxx>
+#include <Geom_BSplineSurface.hxx>
 #include <bindings_common.hxx>
 
 inline std::unique_ptr<Handle_Geom_BSplineCurve> GeomAPI_Interpolate_Curve(const GeomAPI_Interpolate &interpolate) {
   return std::unique_ptr<Handle_Geom_BSplineCurve>(new opencascade::handle<Geom_BSplineCurve>(interpolate.Curve()));
 }
+
+inline std::unique_ptr<Handle_Geom_BSplineSurface>
+GeomAPI_PointsToBSplineSurface_Surface(const GeomAPI_PointsToBSplineSurface &approximation) {
+  return std::unique_ptr<Handle_Geom_BSplineSurface>(
+      new opencascade::handle<Geom_BSplineSurface>(approximation.Surface()));
+}
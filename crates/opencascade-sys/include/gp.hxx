@@ -5,6 +5,15 @@
 #include <gp_Circ.hxx>
 #include <gp_GTrsf.hxx>
 #include <gp_Lin.hxx>
+#include <gp_Pln.hxx>
 #include <gp_Pnt.hxx>
 #include <gp_Trsf.hxx>
 #include <gp_Vec.hxx>
+
+inline std::unique_ptr<gp_Pnt> gp_Circ_Location(const gp_Circ &circle) {
+  return std::unique_ptr<gp_Pnt>(new gp_Pnt(circle.Location()));
+}
+
+inline std::unique_ptr<gp_Dir> gp_Circ_Normal(const gp_Circ &circle) {
+  return std::unique_ptr<gp_Dir>(new gp_Dir(circle.Axis().Direction()));
+}
@@ -0,0 +1,76 @@
+#include <BRepMesh_IncrementalMesh.hxx>
+#include <BRep_Builder.hxx>
+#include <HLRAlgo_Projector.hxx>
+#include <HLRBRep_Algo.hxx>
+#include <HLRBRep_HLRToShape.hxx>
+#include <HLRBRep_PolyAlgo.hxx>
+#include <HLRBRep_PolyHLRToShape.hxx>
+#include <HLRBRep_TypeOfResultingEdge.hxx>
+#include <TopoDS_Compound.hxx>
+#include <bindings_common.hxx>
+
+inline std::unique_ptr<TopoDS_Shape> hlr_non_null_compound(const TopoDS_Shape &shape) {
+  if (!shape.IsNull()) {
+    return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(shape));
+  }
+
+  TopoDS_Compound compound;
+  BRep_Builder().MakeCompound(compound);
+  return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(compound));
+}
+
+// Removes the hidden lines of a shape, looking along the negative main direction of `axis`.
+inline std::unique_ptr<HLRBRep_HLRToShape> HLRBRep_Algo_project(const TopoDS_Shape &shape, const gp_Ax2 &axis) {
+  Handle(HLRBRep_Algo) algo = new HLRBRep_Algo();
+  algo->Add(shape);
+  algo->Projector(HLRAlgo_Projector(axis));
+  algo->Update();
+  algo->Hide();
+
+  return std::unique_ptr<HLRBRep_HLRToShape>(new HLRBRep_HLRToShape(algo));
+}
+
+// Returns an empty compound, instead of a null shape, when there are no edges of this type.
+inline std::unique_ptr<TopoDS_Shape> HLRBRep_HLRToShape_compound(HLRBRep_HLRToShape &to_shape,
+                                                                 HLRBRep_TypeOfResultingEdge edge_type, bool visible) {
+  return hlr_non_null_compound(to_shape.CompoundOfEdges(edge_type, visible, Standard_False));
+}
+
+// The polygonal algorithm works on the triangulation of the shape, which is computed first.
+inline std::unique_ptr<HLRBRep_PolyHLRToShape> HLRBRep_PolyAlgo_project(const TopoDS_Shape &shape,
+                                                                        const gp_Ax2 &axis, double deflection) {
+  BRepMesh_IncrementalMesh(shape, deflection);
+
+  Handle(HLRBRep_PolyAlgo) algo = new HLRBRep_PolyAlgo();
+  algo->Load(shape);
+  algo->Projector(HLRAlgo_Projector(axis));
+  algo->Update();
+
+  auto to_shape = std::unique_ptr<HLRBRep_PolyHLRToShape>(new HLRBRep_PolyHLRToShape());
+  to_shape->Update(algo);
+  return to_shape;
+}
+
+inline std::unique_ptr<TopoDS_Shape> HLRBRep_PolyHLRToShape_compound(HLRBRep_PolyHLRToShape &to_shape,
+                                                                     HLRBRep_TypeOfResultingEdge edge_type,
+                                                                     bool visible) {
+  TopoDS_Shape compound;
+  switch (edge_type) {
+  case HLRBRep_Sharp:
+    compound = visible ? to_shape.VCompound() : to_shape.HCompound();
+    break;
+  case HLRBRep_Rg1Line:
+    compound = visible ? to_shape.Rg1LineVCompound() : to_shape.Rg1LineHCompound();
+    break;
+  case HLRBRep_RgNLine:
+    compound = visible ? to_shape.RgNLineVCompound() : to_shape.RgNLineHCompound();
+    break;
+  case HLRBRep_OutLine:
+    compound = visible ? to_shape.OutLineVCompound() : to_shape.OutLineHCompound();
+    break;
+  default:
+    break;
+  }
+
+  return hlr_non_null_compound(compound);
+}
@@ -14,3 +14,10 @@ inline std::unique_ptr<TopoDS_Shape> one_shape_iges(const IGESControl_Reader &re
 inline bool write_iges(IGESControl_Writer &writer, rust::String theFileName) {
   return writer.Write(theFileName.c_str());
 }
+
+inline bool write_iges_stream(IGESControl_Writer &writer, rust::Vec<uint8_t> &data) {
+  std::ostringstream stream;
+  bool success = writer.Write(stream);
+  string_to_vec(stream.str(), data);
+  return success;
+}
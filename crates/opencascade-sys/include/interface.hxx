@@ -0,0 +1,15 @@
+#include <Interface_Static.hxx>
+#include <bindings_common.hxx>
+#include <string>
+
+inline bool Interface_Static_SetCVal(rust::Str name, rust::Str value) {
+  return Interface_Static::SetCVal(std::string(name).c_str(), std::string(value).c_str());
+}
+
+inline bool Interface_Static_SetIVal(rust::Str name, Standard_Integer value) {
+  return Interface_Static::SetIVal(std::string(name).c_str(), value);
+}
+
+inline bool Interface_Static_SetRVal(rust::Str name, Standard_Real value) {
+  return Interface_Static::SetRVal(std::string(name).c_str(), value);
+}
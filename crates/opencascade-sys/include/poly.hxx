@@ -1,4 +1,5 @@
 #include <Poly_Connect.hxx>
+#include <Poly_PolygonOnTriangulation.hxx>
 #include <Poly_Triangulation.hxx>
 #include <bindings_common.hxx>
 
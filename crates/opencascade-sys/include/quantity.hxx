@@ -0,0 +1,6 @@
+#include <Quantity_Color.hxx>
+#include <bindings_common.hxx>
+
+inline std::unique_ptr<Quantity_Color> Quantity_Color_new_rgb(double r, double g, double b) {
+  return std::unique_ptr<Quantity_Color>(new Quantity_Color(r, g, b, Quantity_TOC_RGB));
+}
@@ -0,0 +1,11 @@
+#include <ShapeFix_Face.hxx>
+#include <TopoDS_Face.hxx>
+#include <bindings_common.hxx>
+
+// Fixes the orientation of the wires of a face, so that holes are oriented against the outer wire.
+inline std::unique_ptr<TopoDS_Face> ShapeFix_Face_fixed(const TopoDS_Face &face) {
+  ShapeFix_Face fix(face);
+  fix.Perform();
+
+  return std::unique_ptr<TopoDS_Face>(new TopoDS_Face(fix.Face()));
+}
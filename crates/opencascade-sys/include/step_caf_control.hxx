@@ -0,0 +1,20 @@
+#include <STEPCAFControl_Reader.hxx>
+#include <STEPCAFControl_Writer.hxx>
+#include <TDocStd_Document.hxx>
+#include <bindings_common.hxx>
+
+inline IFSelect_ReturnStatus read_step_caf(STEPCAFControl_Reader &reader, rust::String theFileName) {
+  return reader.ReadFile(theFileName.c_str());
+}
+
+inline bool transfer_document(STEPCAFControl_Reader &reader, const Handle_TDocStd_Document &document) {
+  return reader.Transfer(document);
+}
+
+inline bool transfer_document_to_writer(STEPCAFControl_Writer &writer, const Handle_TDocStd_Document &document) {
+  return writer.Transfer(document, STEPControl_AsIs);
+}
+
+inline IFSelect_ReturnStatus write_step_caf(STEPCAFControl_Writer &writer, rust::String theFileName) {
+  return writer.Write(theFileName.c_str());
+}
@@ -1,19 +1,136 @@
+#include <APIHeaderSection_MakeHeader.hxx>
+#include <HeaderSection_FileName.hxx>
+#include <Interface_Check.hxx>
+#include <Interface_CheckIterator.hxx>
 #include <STEPControl_Reader.hxx>
 #include <STEPControl_Writer.hxx>
+#include <StepBasic_Product.hxx>
+#include <StepBasic_ProductDefinition.hxx>
+#include <StepBasic_ProductDefinitionFormation.hxx>
+#include <StepData_StepModel.hxx>
+#include <TCollection_HAsciiString.hxx>
+#include <Transfer_TransientProcess.hxx>
+#include <XSControl_TransferReader.hxx>
+#include <XSControl_WorkSession.hxx>
 #include <bindings_common.hxx>
+#include <string>
 
 inline IFSelect_ReturnStatus read_step(STEPControl_Reader &reader, rust::String theFileName) {
   return reader.ReadFile(theFileName.c_str());
 }
 
+inline IFSelect_ReturnStatus read_step_stream(STEPControl_Reader &reader, rust::Slice<const uint8_t> data) {
+  std::istringstream stream(slice_to_string(data));
+  return reader.ReadStream("stream", stream);
+}
+
 inline std::unique_ptr<TopoDS_Shape> one_shape_step(const STEPControl_Reader &reader) {
   return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(reader.OneShape()));
 }
 
+inline std::unique_ptr<TopoDS_Shape> step_reader_shape(const STEPControl_Reader &reader, Standard_Integer num) {
+  return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(reader.Shape(num)));
+}
+
+// The product name of a root entity, or an empty string if it doesn't have one.
+inline rust::String step_root_name(STEPControl_Reader &reader, Standard_Integer num) {
+  Handle(StepBasic_ProductDefinition) definition =
+      Handle(StepBasic_ProductDefinition)::DownCast(reader.RootForTransfer(num));
+
+  if (definition.IsNull() || definition->Formation().IsNull() || definition->Formation()->OfProduct().IsNull() ||
+      definition->Formation()->OfProduct()->Name().IsNull()) {
+    return rust::String();
+  }
+
+  return rust::String::lossy(definition->Formation()->OfProduct()->Name()->ToCString());
+}
+
+inline void collect_checks(const Interface_CheckIterator &checks, rust::Vec<rust::String> &warnings,
+                           rust::Vec<rust::String> &fails) {
+  for (checks.Start(); checks.More(); checks.Next()) {
+    const Handle(Interface_Check) &check = checks.Value();
+
+    for (Standard_Integer i = 1; i <= check->NbWarnings(); i++) {
+      warnings.push_back(rust::String::lossy(check->CWarning(i)));
+    }
+
+    for (Standard_Integer i = 1; i <= check->NbFails(); i++) {
+      fails.push_back(rust::String::lossy(check->CFail(i)));
+    }
+  }
+}
+
+// Collects the messages from loading the file and from transferring its entities.
+inline void step_reader_checks(const STEPControl_Reader &reader, rust::Vec<rust::String> &warnings,
+                               rust::Vec<rust::String> &fails) {
+  collect_checks(reader.WS()->ModelCheckList(), warnings, fails);
+
+  Handle(Transfer_TransientProcess) process = reader.WS()->TransferReader()->TransientProcess();
+  if (!process.IsNull()) {
+    collect_checks(process->CheckList(Standard_False), warnings, fails);
+  }
+}
+
 inline IFSelect_ReturnStatus transfer_shape(STEPControl_Writer &writer, const TopoDS_Shape &theShape) {
   return writer.Transfer(theShape, STEPControl_AsIs);
 }
 
+// Transfers a shape as a new product. If a name is given, it's used as the
+// product's name and ID.
+inline IFSelect_ReturnStatus transfer_shape_as(STEPControl_Writer &writer, const TopoDS_Shape &shape,
+                                               STEPControl_StepModelType mode, rust::Str product_name) {
+  Handle(StepData_StepModel) model = writer.Model();
+  Standard_Integer first_entity = model->NbEntities() + 1;
+
+  IFSelect_ReturnStatus status = writer.Transfer(shape, mode);
+  if (status != IFSelect_RetDone || product_name.empty()) {
+    return status;
+  }
+
+  Handle(TCollection_HAsciiString) name = new TCollection_HAsciiString(std::string(product_name).c_str());
+  for (Standard_Integer i = first_entity; i <= model->NbEntities(); i++) {
+    Handle(StepBasic_Product) product = Handle(StepBasic_Product)::DownCast(model->Value(i));
+    if (!product.IsNull()) {
+      product->SetId(name);
+      product->SetName(name);
+    }
+  }
+
+  return status;
+}
+
+// Sets the fields of the FILE_NAME header entity. Empty values are left unchanged.
+inline void step_writer_set_header(STEPControl_Writer &writer, rust::Str name, rust::Str author,
+                                   rust::Str organization) {
+  Handle(StepData_StepModel) model = writer.Model();
+
+  if (!model->HasHeaderEntity(STANDARD_TYPE(HeaderSection_FileName))) {
+    APIHeaderSection_MakeHeader defaults;
+    defaults.Apply(model);
+  }
+
+  APIHeaderSection_MakeHeader header(model);
+
+  if (!name.empty()) {
+    header.SetName(new TCollection_HAsciiString(std::string(name).c_str()));
+  }
+
+  if (!author.empty()) {
+    header.SetAuthorValue(1, new TCollection_HAsciiString(std::string(author).c_str()));
+  }
+
+  if (!organization.empty()) {
+    header.SetOrganizationValue(1, new TCollection_HAsciiString(std::string(organization).c_str()));
+  }
+}
+
 inline IFSelect_ReturnStatus write_step(STEPControl_Writer &writer, rust::String theFileName) {
   return writer.Write(theFileName.c_str());
 }
+
+inline IFSelect_ReturnStatus write_step_stream(STEPControl_Writer &writer, rust::Vec<uint8_t> &data) {
+  std::ostringstream stream;
+  IFSelect_ReturnStatus status = writer.WriteStream(stream);
+  string_to_vec(stream.str(), data);
+  return status;
+}
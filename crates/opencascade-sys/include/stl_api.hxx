@@ -1,3 +1,5 @@
+#include <Poly_Triangulation.hxx>
+#include <RWStl.hxx>
 #include <StlAPI.hxx>
 #include <StlAPI_Writer.hxx>
 #include <bindings_common.hxx>
@@ -5,3 +7,8 @@
 inline bool write_stl(StlAPI_Writer &writer, const TopoDS_Shape &theShape, rust::String theFileName) {
   return writer.Write(theShape, theFileName.c_str());
 }
+
+inline std::unique_ptr<Handle_Poly_Triangulation> read_stl(rust::String theFileName) {
+  return std::unique_ptr<Handle_Poly_Triangulation>(
+      new Handle_Poly_Triangulation(RWStl::ReadFile(theFileName.c_str())));
+}
@@ -0,0 +1,23 @@
+#include <TCollection_AsciiString.hxx>
+#include <TCollection_ExtendedString.hxx>
+#include <TDF_Label.hxx>
+#include <TDataStd_Name.hxx>
+#include <bindings_common.hxx>
+#include <string>
+
+inline bool TDataStd_Name_Get(const TDF_Label &label, rust::String &name) {
+  Handle(TDataStd_Name) attribute;
+  if (!label.FindAttribute(TDataStd_Name::GetID(), attribute)) {
+    return false;
+  }
+
+  // Converts to UTF-8.
+  TCollection_AsciiString utf8(attribute->Get());
+  name = rust::String(utf8.ToCString());
+  return true;
+}
+
+inline void TDataStd_Name_Set(const TDF_Label &label, rust::Str name) {
+  std::string utf8(name);
+  TDataStd_Name::Set(label, TCollection_ExtendedString(utf8.c_str(), Standard_True));
+}
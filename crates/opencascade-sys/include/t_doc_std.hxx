@@ -0,0 +1,7 @@
+#include <TDF_Label.hxx>
+#include <TDocStd_Document.hxx>
+#include <bindings_common.hxx>
+
+inline std::unique_ptr<TDF_Label> TDocStd_Document_Main(const Handle_TDocStd_Document &document) {
+  return std::unique_ptr<TDF_Label>(new TDF_Label(document->Main()));
+}
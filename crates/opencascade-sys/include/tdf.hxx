@@ -0,0 +1,7 @@
+#include <TDF_Label.hxx>
+#include <TDF_LabelSequence.hxx>
+#include <bindings_common.hxx>
+
+inline const TDF_Label &TDF_LabelSequence_Value(const TDF_LabelSequence &sequence, Standard_Integer index) {
+  return sequence.Value(index);
+}
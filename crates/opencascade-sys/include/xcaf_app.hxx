@@ -0,0 +1,11 @@
+#include <TDocStd_Document.hxx>
+#include <XCAFApp_Application.hxx>
+#include <bindings_common.hxx>
+
+// The document is initialized by the XCAF application, but not registered with it,
+// so it doesn't need to be closed and is freed with its last handle.
+inline std::unique_ptr<Handle_TDocStd_Document> XCAFApp_Application_NewDocument() {
+  Handle(TDocStd_Document) document = new TDocStd_Document("MDTV-XCAF");
+  XCAFApp_Application::GetApplication()->InitDocument(document);
+  return std::unique_ptr<Handle_TDocStd_Document>(new Handle_TDocStd_Document(document));
+}
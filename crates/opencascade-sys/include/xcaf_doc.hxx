@@ -0,0 +1,121 @@
+#include <Quantity_Color.hxx>
+#include <TColStd_HSequenceOfExtendedString.hxx>
+#include <TCollection_AsciiString.hxx>
+#include <TCollection_ExtendedString.hxx>
+#include <TDF_Label.hxx>
+#include <TDF_LabelSequence.hxx>
+#include <TDocStd_Document.hxx>
+#include <TopLoc_Location.hxx>
+#include <TopoDS_Shape.hxx>
+#include <XCAFDoc_ColorTool.hxx>
+#include <XCAFDoc_DocumentTool.hxx>
+#include <XCAFDoc_LayerTool.hxx>
+#include <XCAFDoc_Location.hxx>
+#include <XCAFDoc_ShapeTool.hxx>
+#include <bindings_common.hxx>
+#include <string>
+
+inline std::unique_ptr<Handle_XCAFDoc_ShapeTool>
+XCAFDoc_DocumentTool_ShapeTool(const Handle_TDocStd_Document &document) {
+  return std::unique_ptr<Handle_XCAFDoc_ShapeTool>(
+      new Handle_XCAFDoc_ShapeTool(XCAFDoc_DocumentTool::ShapeTool(document->Main())));
+}
+
+inline std::unique_ptr<Handle_XCAFDoc_ColorTool>
+XCAFDoc_DocumentTool_ColorTool(const Handle_TDocStd_Document &document) {
+  return std::unique_ptr<Handle_XCAFDoc_ColorTool>(
+      new Handle_XCAFDoc_ColorTool(XCAFDoc_DocumentTool::ColorTool(document->Main())));
+}
+
+inline std::unique_ptr<Handle_XCAFDoc_LayerTool>
+XCAFDoc_DocumentTool_LayerTool(const Handle_TDocStd_Document &document) {
+  return std::unique_ptr<Handle_XCAFDoc_LayerTool>(
+      new Handle_XCAFDoc_LayerTool(XCAFDoc_DocumentTool::LayerTool(document->Main())));
+}
+
+// Shape tool
+
+inline void XCAFDoc_ShapeTool_GetFreeShapes(const Handle_XCAFDoc_ShapeTool &tool, TDF_LabelSequence &labels) {
+  tool->GetFreeShapes(labels);
+}
+
+inline std::unique_ptr<TopoDS_Shape> XCAFDoc_ShapeTool_GetShape(const TDF_Label &label) {
+  return std::unique_ptr<TopoDS_Shape>(new TopoDS_Shape(XCAFDoc_ShapeTool::GetShape(label)));
+}
+
+inline bool XCAFDoc_ShapeTool_IsAssembly(const TDF_Label &label) { return XCAFDoc_ShapeTool::IsAssembly(label); }
+
+inline bool XCAFDoc_ShapeTool_IsReference(const TDF_Label &label) { return XCAFDoc_ShapeTool::IsReference(label); }
+
+inline bool XCAFDoc_ShapeTool_GetComponents(const TDF_Label &label, TDF_LabelSequence &labels) {
+  return XCAFDoc_ShapeTool::GetComponents(label, labels);
+}
+
+inline bool XCAFDoc_ShapeTool_GetReferredShape(const TDF_Label &label, TDF_Label &referred) {
+  return XCAFDoc_ShapeTool::GetReferredShape(label, referred);
+}
+
+inline std::unique_ptr<TopLoc_Location> XCAFDoc_ShapeTool_GetLocation(const TDF_Label &label) {
+  return std::unique_ptr<TopLoc_Location>(new TopLoc_Location(XCAFDoc_ShapeTool::GetLocation(label)));
+}
+
+inline std::unique_ptr<TDF_Label> XCAFDoc_ShapeTool_NewShape(const Handle_XCAFDoc_ShapeTool &tool) {
+  return std::unique_ptr<TDF_Label>(new TDF_Label(tool->NewShape()));
+}
+
+inline std::unique_ptr<TDF_Label> XCAFDoc_ShapeTool_AddShape(const Handle_XCAFDoc_ShapeTool &tool,
+                                                             const TopoDS_Shape &shape) {
+  return std::unique_ptr<TDF_Label>(new TDF_Label(tool->AddShape(shape, Standard_False)));
+}
+
+inline std::unique_ptr<TDF_Label> XCAFDoc_ShapeTool_AddComponent(const Handle_XCAFDoc_ShapeTool &tool,
+                                                                 const TDF_Label &assembly, const TDF_Label &part,
+                                                                 const TopLoc_Location &location) {
+  return std::unique_ptr<TDF_Label>(new TDF_Label(tool->AddComponent(assembly, part, location)));
+}
+
+inline void XCAFDoc_ShapeTool_RemoveComponent(const Handle_XCAFDoc_ShapeTool &tool, const TDF_Label &component) {
+  tool->RemoveComponent(component);
+}
+
+inline void XCAFDoc_ShapeTool_UpdateAssemblies(const Handle_XCAFDoc_ShapeTool &tool) { tool->UpdateAssemblies(); }
+
+inline void XCAFDoc_Location_Set(const TDF_Label &label, const TopLoc_Location &location) {
+  XCAFDoc_Location::Set(label, location);
+}
+
+// Color tool
+
+inline bool XCAFDoc_ColorTool_GetColor(const Handle_XCAFDoc_ColorTool &tool, const TDF_Label &label,
+                                       XCAFDoc_ColorType color_type, Quantity_Color &color) {
+  return tool->GetColor(label, color_type, color);
+}
+
+inline void XCAFDoc_ColorTool_SetColor(const Handle_XCAFDoc_ColorTool &tool, const TDF_Label &label,
+                                       const Quantity_Color &color, XCAFDoc_ColorType color_type) {
+  tool->SetColor(label, color, color_type);
+}
+
+// Layer tool
+
+inline bool XCAFDoc_LayerTool_GetLayers(const Handle_XCAFDoc_LayerTool &tool, const TDF_Label &label,
+                                        rust::Vec<rust::String> &layers) {
+  Handle(TColStd_HSequenceOfExtendedString) names = new TColStd_HSequenceOfExtendedString;
+  if (!tool->GetLayers(label, names)) {
+    return false;
+  }
+
+  for (Standard_Integer i = 1; i <= names->Length(); i++) {
+    // Converts to UTF-8.
+    TCollection_AsciiString utf8(names->Value(i));
+    layers.push_back(rust::String(utf8.ToCString()));
+  }
+
+  return true;
+}
+
+inline void XCAFDoc_LayerTool_SetLayer(const Handle_XCAFDoc_LayerTool &tool, const TDF_Label &label,
+                                       rust::Str layer) {
+  std::string utf8(layer);
+  tool->SetLayer(label, TCollection_ExtendedString(utf8.c_str(), Standard_True));
+}
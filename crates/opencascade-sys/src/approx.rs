@@ -0,0 +1,18 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    #[repr(u32)]
+    #[derive(Debug)]
+    pub enum Approx_ParametrizationType {
+        Approx_ChordLength,
+        Approx_Centripetal,
+        Approx_IsoParametric,
+    }
+
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/approx.hxx");
+
+        type Approx_ParametrizationType;
+    }
+}
@@ -6,6 +6,7 @@ mod inner {
         include!("opencascade-sys/include/b_rep.hxx");
 
         type TopoDS_Builder = crate::topo_ds::TopoDS_Builder;
+        type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
         type gp_Pnt = crate::gp::gp_Pnt;
         type TopoDS_Face = crate::topo_ds::TopoDS_Face;
         type TopoDS_Edge = crate::topo_ds::TopoDS_Edge;
@@ -13,6 +14,7 @@ mod inner {
         type Handle_Geom_Surface = crate::geom::Handle_Geom_Surface;
         type Handle_Geom_Curve = crate::geom::Handle_Geom_Curve;
         type Handle_Poly_Triangulation = crate::poly::Handle_Poly_Triangulation;
+        type Handle_Poly_PolygonOnTriangulation = crate::poly::Handle_Poly_PolygonOnTriangulation;
         type TopLoc_Location = crate::top_loc::TopLoc_Location;
 
         type BRep_Builder;
@@ -21,6 +23,8 @@ mod inner {
         pub fn BRep_Builder_upcast_to_topods_builder(builder: &BRep_Builder) -> &TopoDS_Builder;
 
         type BRep_Tool;
+        #[Self = "BRep_Tool"]
+        pub fn IsClosed(shape: &TopoDS_Shape) -> bool;
         pub fn BRep_Tool_Surface(face: &TopoDS_Face) -> UniquePtr<Handle_Geom_Surface>;
         pub fn BRep_Tool_Curve(
             edge: &TopoDS_Edge,
@@ -32,5 +36,10 @@ mod inner {
             face: &TopoDS_Face,
             location: Pin<&mut TopLoc_Location>,
         ) -> UniquePtr<Handle_Poly_Triangulation>;
+        pub fn BRep_Tool_PolygonOnTriangulation(
+            edge: &TopoDS_Edge,
+            triangulation: &Handle_Poly_Triangulation,
+            location: &TopLoc_Location,
+        ) -> UniquePtr<Handle_Poly_PolygonOnTriangulation>;
     }
 }
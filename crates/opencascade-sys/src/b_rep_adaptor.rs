@@ -6,6 +6,7 @@ mod inner {
         include!("opencascade-sys/include/b_rep_adaptor.hxx");
 
         type gp_Pnt = crate::gp::gp_Pnt;
+        type gp_Circ = crate::gp::gp_Circ;
         type GeomAbs_CurveType = crate::geom_abs::GeomAbs_CurveType;
         type TopoDS_Edge = crate::topo_ds::TopoDS_Edge;
 
@@ -16,5 +17,13 @@ mod inner {
         pub fn LastParameter(self: &BRepAdaptor_Curve) -> f64;
         pub fn BRepAdaptor_Curve_value(curve: &BRepAdaptor_Curve, u: f64) -> UniquePtr<gp_Pnt>;
         pub fn GetType(self: &BRepAdaptor_Curve) -> GeomAbs_CurveType;
+        pub fn BRepAdaptor_Curve_circle(curve: &BRepAdaptor_Curve) -> Result<UniquePtr<gp_Circ>>;
+        pub fn BRepAdaptor_Curve_bspline_data(
+            curve: &BRepAdaptor_Curve,
+            poles: &mut Vec<f64>,
+            weights: &mut Vec<f64>,
+            knots: &mut Vec<f64>,
+            multiplicities: &mut Vec<i32>,
+        ) -> Result<i32>;
     }
 }
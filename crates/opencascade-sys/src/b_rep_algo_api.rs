@@ -9,6 +9,7 @@ mod inner {
         type TopTools_ListOfShape = crate::top_tools::TopTools_ListOfShape;
         type Message_ProgressRange = crate::message::Message_ProgressRange;
         type BOPAlgo_GlueEnum = crate::bop_algo::BOPAlgo_GlueEnum;
+        type gp_Pln = crate::gp::gp_Pln;
 
         pub type BRepAlgoAPI_BuilderAlgo;
         pub fn SectionEdges(self: Pin<&mut BRepAlgoAPI_BuilderAlgo>) -> &TopTools_ListOfShape;
@@ -38,6 +39,11 @@ mod inner {
             self: Pin<&'a mut BRepAlgoAPI_Cut>,
             shape: &'a TopoDS_Shape,
         ) -> &'a TopTools_ListOfShape;
+        pub fn Modified<'a>(
+            self: Pin<&'a mut BRepAlgoAPI_Cut>,
+            shape: &'a TopoDS_Shape,
+        ) -> &'a TopTools_ListOfShape;
+        pub fn IsDeleted(self: Pin<&mut BRepAlgoAPI_Cut>, shape: &TopoDS_Shape) -> bool;
         pub fn SectionEdges(self: Pin<&mut BRepAlgoAPI_Cut>) -> &TopTools_ListOfShape;
 
         type BRepAlgoAPI_Common;
@@ -57,6 +63,19 @@ mod inner {
             shape_1: &TopoDS_Shape,
             shape_2: &TopoDS_Shape,
         ) -> UniquePtr<BRepAlgoAPI_Section>;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepAlgoAPI_Section_new_unbuilt(
+            shape_1: &TopoDS_Shape,
+            shape_2: &TopoDS_Shape,
+            perform_now: bool,
+        ) -> UniquePtr<BRepAlgoAPI_Section>;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepAlgoAPI_Section_new_with_plane(
+            shape: &TopoDS_Shape,
+            plane: &gp_Pln,
+            perform_now: bool,
+        ) -> UniquePtr<BRepAlgoAPI_Section>;
+        pub fn Approximation(self: Pin<&mut BRepAlgoAPI_Section>, approximate: bool);
         pub fn Shape(self: Pin<&mut BRepAlgoAPI_Section>) -> &TopoDS_Shape;
         pub fn Build(self: Pin<&mut BRepAlgoAPI_Section>, progress: &Message_ProgressRange);
         pub fn IsDone(self: &BRepAlgoAPI_Section) -> bool;
@@ -86,6 +86,8 @@ mod inner {
             surface: &Handle_Geom_Surface,
             edge_tolerance: f64,
         ) -> UniquePtr<BRepBuilderAPI_MakeFace>;
+        #[rust_name = "add_wire"]
+        pub fn Add(self: Pin<&mut BRepBuilderAPI_MakeFace>, wire: &TopoDS_Wire);
         pub fn Face(self: &BRepBuilderAPI_MakeFace) -> &TopoDS_Face;
         pub fn Shape(self: Pin<&mut BRepBuilderAPI_MakeFace>) -> &TopoDS_Shape;
         pub fn Build(self: Pin<&mut BRepBuilderAPI_MakeFace>, progress: &Message_ProgressRange);
@@ -100,6 +102,14 @@ mod inner {
         pub fn Build(self: Pin<&mut BRepBuilderAPI_MakeSolid>, progress: &Message_ProgressRange);
         pub fn IsDone(self: &BRepBuilderAPI_MakeSolid) -> bool;
 
+        type BRepBuilderAPI_Sewing;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepBuilderAPI_Sewing_new(tolerance: f64) -> UniquePtr<BRepBuilderAPI_Sewing>;
+        pub fn Add(self: Pin<&mut BRepBuilderAPI_Sewing>, shape: &TopoDS_Shape);
+        pub fn Perform(self: Pin<&mut BRepBuilderAPI_Sewing>, progress: &Message_ProgressRange);
+        pub fn SewedShape(self: &BRepBuilderAPI_Sewing) -> &TopoDS_Shape;
+        pub fn NbFreeEdges(self: &BRepBuilderAPI_Sewing) -> i32;
+
         type BRepBuilderAPI_MakeShapeOnMesh;
         #[cxx_name = "construct_unique"]
         pub fn BRepBuilderAPI_MakeShapeOnMesh_new(
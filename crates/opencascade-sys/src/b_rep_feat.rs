@@ -6,8 +6,12 @@ mod inner {
         include!("opencascade-sys/include/b_rep_feat.hxx");
 
         type gp_Ax1 = crate::gp::gp_Ax1;
+        type gp_Dir = crate::gp::gp_Dir;
+        type gp_Vec = crate::gp::gp_Vec;
+        type Handle_Geom_Plane = crate::geom::Handle_Geom_Plane;
         type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
         type TopoDS_Face = crate::topo_ds::TopoDS_Face;
+        type TopoDS_Wire = crate::topo_ds::TopoDS_Wire;
 
         type BRepFeat_MakeDPrism;
         #[cxx_name = "construct_unique"]
@@ -26,6 +30,83 @@ mod inner {
         pub fn perform_with_height(self: Pin<&mut BRepFeat_MakeDPrism>, height: f64);
         pub fn Shape(self: Pin<&mut BRepFeat_MakeDPrism>) -> &TopoDS_Shape;
 
+        type BRepFeat_MakePrism;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepFeat_MakePrism_new(
+            shape: &TopoDS_Shape,
+            profile_base: &TopoDS_Shape,
+            sketch_base: &TopoDS_Face,
+            direction: &gp_Dir,
+            fuse: i32, // 0 = subtractive, 1 = additive
+            modify: bool,
+        ) -> UniquePtr<BRepFeat_MakePrism>;
+
+        #[cxx_name = "Perform"]
+        pub fn perform_with_length(self: Pin<&mut BRepFeat_MakePrism>, length: f64);
+        #[cxx_name = "Perform"]
+        pub fn perform_until_face(self: Pin<&mut BRepFeat_MakePrism>, until: &TopoDS_Shape);
+        #[cxx_name = "Perform"]
+        pub fn perform_from_until(
+            self: Pin<&mut BRepFeat_MakePrism>,
+            from: &TopoDS_Shape,
+            until: &TopoDS_Shape,
+        );
+        pub fn PerformUntilEnd(self: Pin<&mut BRepFeat_MakePrism>);
+        pub fn PerformThruAll(self: Pin<&mut BRepFeat_MakePrism>);
+        pub fn PerformUntilHeight(
+            self: Pin<&mut BRepFeat_MakePrism>,
+            until: &TopoDS_Shape,
+            length: f64,
+        );
+        pub fn IsDone(self: &BRepFeat_MakePrism) -> bool;
+        pub fn Shape(self: Pin<&mut BRepFeat_MakePrism>) -> &TopoDS_Shape;
+
+        type BRepFeat_MakeRevol;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepFeat_MakeRevol_new(
+            shape: &TopoDS_Shape,
+            profile_base: &TopoDS_Shape,
+            sketch_base: &TopoDS_Face,
+            axis: &gp_Ax1,
+            fuse: i32, // 0 = subtractive, 1 = additive
+            modify: bool,
+        ) -> UniquePtr<BRepFeat_MakeRevol>;
+
+        #[cxx_name = "Perform"]
+        pub fn perform_with_angle(self: Pin<&mut BRepFeat_MakeRevol>, angle: f64);
+        #[cxx_name = "Perform"]
+        pub fn perform_until_face(self: Pin<&mut BRepFeat_MakeRevol>, until: &TopoDS_Shape);
+        #[cxx_name = "Perform"]
+        pub fn perform_from_until(
+            self: Pin<&mut BRepFeat_MakeRevol>,
+            from: &TopoDS_Shape,
+            until: &TopoDS_Shape,
+        );
+        pub fn PerformThruAll(self: Pin<&mut BRepFeat_MakeRevol>);
+        pub fn PerformUntilAngle(
+            self: Pin<&mut BRepFeat_MakeRevol>,
+            until: &TopoDS_Shape,
+            angle: f64,
+        );
+        pub fn IsDone(self: &BRepFeat_MakeRevol) -> bool;
+        pub fn Shape(self: Pin<&mut BRepFeat_MakeRevol>) -> &TopoDS_Shape;
+
+        type BRepFeat_MakeLinearForm;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepFeat_MakeLinearForm_new(
+            shape: &TopoDS_Shape,
+            wire: &TopoDS_Wire,
+            plane: &Handle_Geom_Plane,
+            direction: &gp_Vec,
+            direction1: &gp_Vec,
+            fuse: i32, // 0 = subtractive, 1 = additive
+            modify: bool,
+        ) -> UniquePtr<BRepFeat_MakeLinearForm>;
+
+        pub fn Perform(self: Pin<&mut BRepFeat_MakeLinearForm>);
+        pub fn IsDone(self: &BRepFeat_MakeLinearForm) -> bool;
+        pub fn Shape(self: Pin<&mut BRepFeat_MakeLinearForm>) -> &TopoDS_Shape;
+
         type BRepFeat_MakeCylindricalHole;
         #[cxx_name = "construct_unique"]
         pub fn BRepFeat_MakeCylindricalHole_new() -> UniquePtr<BRepFeat_MakeCylindricalHole>;
@@ -7,11 +7,14 @@ mod inner {
 
         type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
         type TopoDS_Face = crate::topo_ds::TopoDS_Face;
+        type TopoDS_Solid = crate::topo_ds::TopoDS_Solid;
         type Handle_Poly_Triangulation = crate::poly::Handle_Poly_Triangulation;
 
         type BRepLib;
         #[Self = "BRepLib"]
         pub fn BuildCurves3d(shape: &TopoDS_Shape) -> bool;
+        #[Self = "BRepLib"]
+        pub fn OrientClosedSolid(solid: Pin<&mut TopoDS_Solid>) -> bool;
 
         type BRepLib_ToolTriangulatedShape;
         #[Self = "BRepLib_ToolTriangulatedShape"]
@@ -7,12 +7,45 @@ mod inner {
 
         type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
 
+        type IMeshTools_Parameters;
+        #[cxx_name = "construct_unique"]
+        pub fn IMeshTools_Parameters_new() -> UniquePtr<IMeshTools_Parameters>;
+        pub fn IMeshTools_Parameters_set_deflection(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            deflection: f64,
+        );
+        pub fn IMeshTools_Parameters_set_angle(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            angle: f64,
+        );
+        pub fn IMeshTools_Parameters_set_relative(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            relative: bool,
+        );
+        pub fn IMeshTools_Parameters_set_min_size(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            min_size: f64,
+        );
+        pub fn IMeshTools_Parameters_set_internal_vertices_mode(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            internal_vertices_mode: bool,
+        );
+        pub fn IMeshTools_Parameters_set_in_parallel(
+            parameters: Pin<&mut IMeshTools_Parameters>,
+            in_parallel: bool,
+        );
+
         type BRepMesh_IncrementalMesh;
         #[cxx_name = "construct_unique"]
         fn IncrementalMesh_new(
             shape: &TopoDS_Shape,
             deflection: f64,
         ) -> UniquePtr<BRepMesh_IncrementalMesh>;
+        #[cxx_name = "construct_unique"]
+        fn IncrementalMesh_new_with_parameters(
+            shape: &TopoDS_Shape,
+            parameters: &IMeshTools_Parameters,
+        ) -> UniquePtr<BRepMesh_IncrementalMesh>;
         fn Shape(self: &BRepMesh_IncrementalMesh) -> &TopoDS_Shape;
         fn IsDone(self: &BRepMesh_IncrementalMesh) -> bool;
     }
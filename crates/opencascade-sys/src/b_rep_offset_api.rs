@@ -10,18 +10,43 @@ mod inner {
         BRepOffset_RectoVerso,
     }
 
+    #[derive(Debug)]
+    #[repr(u32)]
+    pub enum BRepFill_TypeOfContact {
+        BRepFill_NoContact,
+        BRepFill_Contact,
+        BRepFill_ContactOnBorder,
+    }
+
+    #[derive(Debug)]
+    #[repr(u32)]
+    pub enum BRepBuilderAPI_TransitionMode {
+        BRepBuilderAPI_Transformed,
+        BRepBuilderAPI_RightCorner,
+        BRepBuilderAPI_RoundCorner,
+    }
+
     unsafe extern "C++" {
         include!("opencascade-sys/include/b_rep_offset_api.hxx");
 
         type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
         type TopoDS_Face = crate::topo_ds::TopoDS_Face;
         type TopoDS_Wire = crate::topo_ds::TopoDS_Wire;
+        type TopoDS_Vertex = crate::topo_ds::TopoDS_Vertex;
+        type TopoDS_Edge = crate::topo_ds::TopoDS_Edge;
+        type gp_Pnt = crate::gp::gp_Pnt;
+        type gp_Ax2 = crate::gp::gp_Ax2;
+        type gp_Dir = crate::gp::gp_Dir;
         type GeomAbs_JoinType = crate::geom_abs::GeomAbs_JoinType;
+        type GeomAbs_Shape = crate::geom_abs::GeomAbs_Shape;
+        type Approx_ParametrizationType = crate::approx::Approx_ParametrizationType;
         type TopTools_ListOfShape = crate::top_tools::TopTools_ListOfShape;
         type Message_ProgressRange = crate::message::Message_ProgressRange;
         type Handle_Law_Function = crate::law::Handle_Law_Function;
 
         type BRepOffset_Mode;
+        type BRepFill_TypeOfContact;
+        type BRepBuilderAPI_TransitionMode;
 
         type BRepOffsetAPI_MakeOffset;
         #[cxx_name = "construct_unique"]
@@ -39,6 +64,11 @@ mod inner {
         pub fn Build(self: Pin<&mut BRepOffsetAPI_MakeOffset>, progress: &Message_ProgressRange);
         pub fn IsDone(self: &BRepOffsetAPI_MakeOffset) -> bool;
 
+        pub fn BRepOffsetAPI_MakeOffsetShape_simple(
+            shape: &TopoDS_Shape,
+            offset: f64,
+        ) -> Result<UniquePtr<TopoDS_Shape>>;
+
         type BRepOffsetAPI_MakeThickSolid;
         #[cxx_name = "construct_unique"]
         pub fn BRepOffsetAPI_MakeThickSolid_new() -> UniquePtr<BRepOffsetAPI_MakeThickSolid>;
@@ -77,12 +107,36 @@ mod inner {
             spine: &TopoDS_Wire,
         ) -> UniquePtr<BRepOffsetAPI_MakePipeShell>;
         pub fn SetMode(self: Pin<&mut BRepOffsetAPI_MakePipeShell>, is_frenet: bool);
+        #[cxx_name = "SetMode"]
+        pub fn set_mode_auxiliary_spine(
+            self: Pin<&mut BRepOffsetAPI_MakePipeShell>,
+            auxiliary_spine: &TopoDS_Wire,
+            curvilinear_equivalence: bool,
+            keep_contact: BRepFill_TypeOfContact,
+        );
+        #[cxx_name = "SetMode"]
+        pub fn set_mode_fixed(self: Pin<&mut BRepOffsetAPI_MakePipeShell>, axis: &gp_Ax2);
+        #[cxx_name = "SetMode"]
+        pub fn set_mode_binormal(self: Pin<&mut BRepOffsetAPI_MakePipeShell>, binormal: &gp_Dir);
+        pub fn SetDiscreteMode(self: Pin<&mut BRepOffsetAPI_MakePipeShell>);
+        pub fn SetTransitionMode(
+            self: Pin<&mut BRepOffsetAPI_MakePipeShell>,
+            mode: BRepBuilderAPI_TransitionMode,
+        );
         pub fn Add(
             self: Pin<&mut BRepOffsetAPI_MakePipeShell>,
             profile: &TopoDS_Shape,
             with_contact: bool,
             with_correction: bool,
         );
+        #[cxx_name = "Add"]
+        pub fn add_at_vertex(
+            self: Pin<&mut BRepOffsetAPI_MakePipeShell>,
+            profile: &TopoDS_Shape,
+            location: &TopoDS_Vertex,
+            with_contact: bool,
+            with_correction: bool,
+        );
         pub fn SetLaw(
             self: Pin<&mut BRepOffsetAPI_MakePipeShell>,
             profile: &TopoDS_Shape,
@@ -92,14 +146,59 @@ mod inner {
         );
         pub fn Build(self: Pin<&mut BRepOffsetAPI_MakePipeShell>, progress: &Message_ProgressRange);
         pub fn MakeSolid(self: Pin<&mut BRepOffsetAPI_MakePipeShell>) -> bool;
+        pub fn IsDone(self: &BRepOffsetAPI_MakePipeShell) -> bool;
         pub fn Shape(self: Pin<&mut BRepOffsetAPI_MakePipeShell>) -> &TopoDS_Shape;
 
+        type BRepOffsetAPI_MakeFilling;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepOffsetAPI_MakeFilling_new() -> UniquePtr<BRepOffsetAPI_MakeFilling>;
+        #[cxx_name = "Add"]
+        pub fn add_edge(
+            self: Pin<&mut BRepOffsetAPI_MakeFilling>,
+            constraint: &TopoDS_Edge,
+            order: GeomAbs_Shape,
+            is_bound: bool,
+        ) -> i32;
+        #[cxx_name = "Add"]
+        pub fn add_edge_with_support(
+            self: Pin<&mut BRepOffsetAPI_MakeFilling>,
+            constraint: &TopoDS_Edge,
+            support: &TopoDS_Face,
+            order: GeomAbs_Shape,
+            is_bound: bool,
+        ) -> i32;
+        #[cxx_name = "Add"]
+        pub fn add_point(self: Pin<&mut BRepOffsetAPI_MakeFilling>, point: &gp_Pnt) -> i32;
+        pub fn Build(self: Pin<&mut BRepOffsetAPI_MakeFilling>, progress: &Message_ProgressRange);
+        pub fn IsDone(self: &BRepOffsetAPI_MakeFilling) -> bool;
+        pub fn Shape(self: Pin<&mut BRepOffsetAPI_MakeFilling>) -> &TopoDS_Shape;
+
         type BRepOffsetAPI_ThruSections;
         #[cxx_name = "construct_unique"]
         pub fn BRepOffsetAPI_ThruSections_new(
             is_solid: bool,
         ) -> UniquePtr<BRepOffsetAPI_ThruSections>;
+        #[cxx_name = "construct_unique"]
+        pub fn BRepOffsetAPI_ThruSections_new_with_options(
+            is_solid: bool,
+            ruled: bool,
+            precision: f64,
+        ) -> UniquePtr<BRepOffsetAPI_ThruSections>;
         pub fn AddWire(self: Pin<&mut BRepOffsetAPI_ThruSections>, wire: &TopoDS_Wire);
+        pub fn AddVertex(self: Pin<&mut BRepOffsetAPI_ThruSections>, vertex: &TopoDS_Vertex);
+        pub fn SetSmoothing(self: Pin<&mut BRepOffsetAPI_ThruSections>, use_smoothing: bool);
+        pub fn SetParType(
+            self: Pin<&mut BRepOffsetAPI_ThruSections>,
+            par_type: Approx_ParametrizationType,
+        );
+        pub fn SetContinuity(self: Pin<&mut BRepOffsetAPI_ThruSections>, continuity: GeomAbs_Shape);
+        pub fn SetCriteriumWeight(
+            self: Pin<&mut BRepOffsetAPI_ThruSections>,
+            w1: f64,
+            w2: f64,
+            w3: f64,
+        );
+        pub fn SetMaxDegree(self: Pin<&mut BRepOffsetAPI_ThruSections>, max_degree: i32);
         pub fn CheckCompatibility(self: Pin<&mut BRepOffsetAPI_ThruSections>, check: bool);
         pub fn Shape(self: Pin<&mut BRepOffsetAPI_ThruSections>) -> &TopoDS_Shape;
         pub fn Build(self: Pin<&mut BRepOffsetAPI_ThruSections>, progress: &Message_ProgressRange);
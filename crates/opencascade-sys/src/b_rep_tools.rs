@@ -17,5 +17,9 @@ mod inner {
         pub fn write(shape: &TopoDS_Shape, path: String) -> bool;
         #[cxx_name = "read_brep_text"]
         pub fn read(path: String) -> UniquePtr<TopoDS_Shape>;
+        #[cxx_name = "write_brep_text_stream"]
+        pub fn write_stream(shape: &TopoDS_Shape, data: &mut Vec<u8>) -> Result<()>;
+        #[cxx_name = "read_brep_text_stream"]
+        pub fn read_stream(data: &[u8]) -> Result<UniquePtr<TopoDS_Shape>>;
     }
 }
@@ -6,8 +6,12 @@ mod inner {
         include!("opencascade-sys/include/geom.hxx");
 
         type gp_Ax3 = crate::gp::gp_Ax3;
+        type gp_Dir = crate::gp::gp_Dir;
         type gp_Pnt = crate::gp::gp_Pnt;
         type TColgp_Array2OfPnt = crate::t_col_gp::TColgp_Array2OfPnt;
+        type TColStd_Array1OfInteger = crate::t_col_std::TColStd_Array1OfInteger;
+        type TColStd_Array1OfReal = crate::t_col_std::TColStd_Array1OfReal;
+        type TColStd_Array2OfReal = crate::t_col_std::TColStd_Array2OfReal;
         type TColgp_HArray1OfPnt = crate::t_col_gp::TColgp_HArray1OfPnt;
         type Handle_Standard_Type = crate::standard::Handle_Standard_Type;
 
@@ -32,11 +36,17 @@ mod inner {
         type Handle_Geom_BezierSurface;
         pub fn IsNull(self: &Handle_Geom_BezierSurface) -> bool;
 
+        type Handle_Geom_BSplineSurface;
+        pub fn IsNull(self: &Handle_Geom_BSplineSurface) -> bool;
+
         type Handle_Geom_Plane;
         pub fn IsNull(self: &Handle_Geom_Plane) -> bool;
 
         type Handle_Geom_CylindricalSurface;
         pub fn IsNull(self: &Handle_Geom_CylindricalSurface) -> bool;
+
+        type Handle_Geom_ConicalSurface;
+        pub fn IsNull(self: &Handle_Geom_ConicalSurface) -> bool;
         // End Handles
 
         type Geom_TrimmedCurve;
@@ -50,6 +60,16 @@ mod inner {
             cylinder_handle: &Handle_Geom_CylindricalSurface,
         ) -> UniquePtr<Handle_Geom_Surface>;
 
+        type Geom_ConicalSurface;
+        pub fn Geom_ConicalSurface_new(
+            axis: &gp_Ax3,
+            semi_angle: f64,
+            radius: f64,
+        ) -> UniquePtr<Handle_Geom_ConicalSurface>;
+        pub fn cone_to_surface(
+            cone_handle: &Handle_Geom_ConicalSurface,
+        ) -> UniquePtr<Handle_Geom_Surface>;
+
         type Geom_BezierSurface;
         pub fn Geom_BezierSurface_new(
             poles: &TColgp_Array2OfPnt,
@@ -58,6 +78,31 @@ mod inner {
             bezier_handle: &Handle_Geom_BezierSurface,
         ) -> UniquePtr<Handle_Geom_Surface>;
 
+        type Geom_BSplineSurface;
+        #[allow(clippy::too_many_arguments)]
+        pub fn Geom_BSplineSurface_new(
+            poles: &TColgp_Array2OfPnt,
+            weights: &TColStd_Array2OfReal,
+            u_knots: &TColStd_Array1OfReal,
+            v_knots: &TColStd_Array1OfReal,
+            u_mults: &TColStd_Array1OfInteger,
+            v_mults: &TColStd_Array1OfInteger,
+            u_degree: i32,
+            v_degree: i32,
+        ) -> Result<UniquePtr<Handle_Geom_BSplineSurface>>;
+        pub fn bspline_to_surface(
+            bspline_handle: &Handle_Geom_BSplineSurface,
+        ) -> UniquePtr<Handle_Geom_Surface>;
+
+        type Geom_BSplineCurve;
+        pub fn Geom_BSplineCurve_new(
+            poles: &TColgp_HArray1OfPnt,
+            weights: &TColStd_Array1OfReal,
+            knots: &TColStd_Array1OfReal,
+            multiplicities: &TColStd_Array1OfInteger,
+            degree: i32,
+        ) -> Result<UniquePtr<Handle_Geom_BSplineCurve>>;
+
         type Geom_BezierCurve;
         #[cxx_name = "construct_unique"]
         pub fn Geom_BezierCurve_new_points(
@@ -68,6 +113,9 @@ mod inner {
             law: UniquePtr<Geom_BezierCurve>,
         ) -> UniquePtr<Handle_Geom_BezierCurve>;
 
+        type Geom_Plane;
+        pub fn Geom_Plane_new(point: &gp_Pnt, normal: &gp_Dir) -> UniquePtr<Handle_Geom_Plane>;
+
         pub fn handle_geom_plane_location(plane: &Handle_Geom_Plane) -> &gp_Pnt;
 
         pub fn new_HandleGeomPlane_from_HandleGeomSurface(
@@ -91,7 +139,9 @@ mod inner {
     }
 
     impl UniquePtr<Handle_Geom_CylindricalSurface> {}
+    impl UniquePtr<Handle_Geom_ConicalSurface> {}
     impl UniquePtr<Handle_Geom_BezierSurface> {}
+    impl UniquePtr<Handle_Geom_BSplineSurface> {}
     impl UniquePtr<Handle_Geom_BezierCurve> {}
     impl UniquePtr<Handle_Geom_Plane> {}
     impl UniquePtr<Handle_Geom_BSplineCurve> {}
@@ -6,6 +6,7 @@ mod inner {
         include!("opencascade-sys/include/geom2d.hxx");
 
         type gp_Ax2d = crate::gp::gp_Ax2d;
+        type gp_Dir2d = crate::gp::gp_Dir2d;
         type gp_Pnt2d = crate::gp::gp_Pnt2d;
 
         // Handles
@@ -15,6 +16,9 @@ mod inner {
         type Handle_Geom2d_Ellipse;
         pub fn IsNull(self: &Handle_Geom2d_Ellipse) -> bool;
 
+        type Handle_Geom2d_Line;
+        pub fn IsNull(self: &Handle_Geom2d_Line) -> bool;
+
         type Handle_Geom2d_TrimmedCurve;
         pub fn IsNull(self: &Handle_Geom2d_TrimmedCurve) -> bool;
         // End Handles
@@ -30,6 +34,12 @@ mod inner {
         ) -> UniquePtr<Handle_Geom2d_Curve>;
         pub fn ellipse_value(ellipse: &Handle_Geom2d_Ellipse, u: f64) -> UniquePtr<gp_Pnt2d>;
 
+        type Geom2d_Line;
+        pub fn Geom2d_Line_new(point: &gp_Pnt2d, dir: &gp_Dir2d) -> UniquePtr<Handle_Geom2d_Line>;
+        pub fn line_to_HandleGeom2d_Curve(
+            line: &Handle_Geom2d_Line,
+        ) -> UniquePtr<Handle_Geom2d_Curve>;
+
         type Geom2d_Curve;
         type Geom2d_TrimmedCurve;
         pub fn Geom2d_TrimmedCurve_new(
@@ -43,6 +53,7 @@ mod inner {
     }
 
     impl UniquePtr<Handle_Geom2d_Ellipse> {}
+    impl UniquePtr<Handle_Geom2d_Line> {}
     impl UniquePtr<Handle_Geom2d_Curve> {}
     impl UniquePtr<Handle_Geom2d_TrimmedCurve> {}
 }
@@ -24,10 +24,23 @@ mod inner {
         GeomAbs_Intersection,
     }
 
+    #[repr(u32)]
+    #[derive(Debug)]
+    pub enum GeomAbs_Shape {
+        GeomAbs_C0,
+        GeomAbs_G1,
+        GeomAbs_C1,
+        GeomAbs_G2,
+        GeomAbs_C2,
+        GeomAbs_C3,
+        GeomAbs_CN,
+    }
+
     unsafe extern "C++" {
         include!("opencascade-sys/include/geom_abs.hxx");
 
         type GeomAbs_CurveType;
         type GeomAbs_JoinType;
+        type GeomAbs_Shape;
     }
 }
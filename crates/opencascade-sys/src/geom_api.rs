@@ -10,6 +10,8 @@ mod inner {
         type Handle_TColgp_HArray1OfPnt = crate::t_col_gp::Handle_TColgp_HArray1OfPnt;
         type Handle_Geom_BSplineCurve = crate::geom::Handle_Geom_BSplineCurve;
         type Handle_Geom_Surface = crate::geom::Handle_Geom_Surface;
+        type Handle_Geom_BSplineSurface = crate::geom::Handle_Geom_BSplineSurface;
+        type TColgp_Array2OfPnt = crate::t_col_gp::TColgp_Array2OfPnt;
 
         type GeomAPI_Interpolate;
         #[cxx_name = "construct_unique"]
@@ -29,6 +31,20 @@ mod inner {
             interpolate: &GeomAPI_Interpolate,
         ) -> UniquePtr<Handle_Geom_BSplineCurve>;
 
+        type GeomAPI_PointsToBSplineSurface;
+        #[cxx_name = "construct_unique"]
+        pub fn GeomAPI_PointsToBSplineSurface_new_unbuilt(
+        ) -> UniquePtr<GeomAPI_PointsToBSplineSurface>;
+        pub fn Interpolate(
+            self: Pin<&mut GeomAPI_PointsToBSplineSurface>,
+            points: &TColgp_Array2OfPnt,
+            periodic: bool,
+        ) -> Result<()>;
+        pub fn IsDone(self: &GeomAPI_PointsToBSplineSurface) -> bool;
+        pub fn GeomAPI_PointsToBSplineSurface_Surface(
+            approximation: &GeomAPI_PointsToBSplineSurface,
+        ) -> UniquePtr<Handle_Geom_BSplineSurface>;
+
         type GeomAPI_ProjectPointOnSurf;
         #[cxx_name = "construct_unique"]
         pub fn GeomAPI_ProjectPointOnSurf_new(
@@ -45,18 +45,44 @@ mod inner {
         type gp_Circ;
         #[cxx_name = "construct_unique"]
         pub fn gp_Circ_new(axis: &gp_Ax2, radius: f64) -> UniquePtr<gp_Circ>;
+        pub fn Radius(self: &gp_Circ) -> f64;
+        pub fn gp_Circ_Location(circle: &gp_Circ) -> UniquePtr<gp_Pnt>;
+        pub fn gp_Circ_Normal(circle: &gp_Circ) -> UniquePtr<gp_Dir>;
+
+        type gp_Pln;
+        #[cxx_name = "construct_unique"]
+        pub fn gp_Pln_new(point: &gp_Pnt, dir: &gp_Dir) -> UniquePtr<gp_Pln>;
+        #[cxx_name = "construct_unique"]
+        pub fn gp_Pln_from_gp_Ax3(position: &gp_Ax3) -> UniquePtr<gp_Pln>;
+        pub fn Position(self: &gp_Pln) -> &gp_Ax3;
 
         type gp_Ax1;
         #[cxx_name = "construct_unique"]
         pub fn gp_Ax1_new(origin: &gp_Pnt, main_dir: &gp_Dir) -> UniquePtr<gp_Ax1>;
+        pub fn Location(self: &gp_Ax1) -> &gp_Pnt;
+        pub fn Direction(self: &gp_Ax1) -> &gp_Dir;
 
         type gp_Ax2;
         #[cxx_name = "construct_unique"]
         pub fn gp_Ax2_new(origin: &gp_Pnt, main_dir: &gp_Dir) -> UniquePtr<gp_Ax2>;
+        #[cxx_name = "construct_unique"]
+        pub fn gp_Ax2_new_with_x_dir(
+            origin: &gp_Pnt,
+            main_dir: &gp_Dir,
+            x_dir: &gp_Dir,
+        ) -> UniquePtr<gp_Ax2>;
+        pub fn Location(self: &gp_Ax2) -> &gp_Pnt;
+        pub fn Direction(self: &gp_Ax2) -> &gp_Dir;
+        pub fn XDirection(self: &gp_Ax2) -> &gp_Dir;
 
         type gp_Ax3;
         #[cxx_name = "construct_unique"]
         pub fn gp_Ax3_from_gp_Ax2(axis: &gp_Ax2) -> UniquePtr<gp_Ax3>;
+        #[cxx_name = "construct_unique"]
+        pub fn gp_Ax3_new(origin: &gp_Pnt, main_dir: &gp_Dir, x_dir: &gp_Dir) -> UniquePtr<gp_Ax3>;
+        pub fn Location(self: &gp_Ax3) -> &gp_Pnt;
+        pub fn Direction(self: &gp_Ax3) -> &gp_Dir;
+        pub fn XDirection(self: &gp_Ax3) -> &gp_Dir;
 
         type gp_Dir2d;
         #[cxx_name = "construct_unique"]
@@ -83,6 +109,22 @@ mod inner {
         pub fn SetScale(self: Pin<&mut gp_Trsf>, point: &gp_Pnt, scale: f64);
         pub fn SetTranslation(self: Pin<&mut gp_Trsf>, point1: &gp_Pnt, point2: &gp_Pnt);
         pub fn Value(self: &gp_Trsf, the_row: i32, the_col: i32) -> f64;
+        #[allow(clippy::too_many_arguments)]
+        pub fn SetValues(
+            self: Pin<&mut gp_Trsf>,
+            a11: f64,
+            a12: f64,
+            a13: f64,
+            a14: f64,
+            a21: f64,
+            a22: f64,
+            a23: f64,
+            a24: f64,
+            a31: f64,
+            a32: f64,
+            a33: f64,
+            a34: f64,
+        ) -> Result<()>;
         #[cxx_name = "SetTranslationPart"]
         pub fn set_translation_vec(self: Pin<&mut gp_Trsf>, translation: &gp_Vec);
 
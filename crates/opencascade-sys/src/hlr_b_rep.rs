@@ -0,0 +1,47 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    #[repr(u32)]
+    #[derive(Debug)]
+    pub enum HLRBRep_TypeOfResultingEdge {
+        HLRBRep_Undefined,
+        HLRBRep_IsoLine,
+        HLRBRep_OutLine,
+        HLRBRep_Rg1Line,
+        HLRBRep_RgNLine,
+        HLRBRep_Sharp,
+    }
+
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/hlr_b_rep.hxx");
+
+        type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
+        type gp_Ax2 = crate::gp::gp_Ax2;
+
+        type HLRBRep_TypeOfResultingEdge;
+
+        type HLRBRep_HLRToShape;
+        pub fn HLRBRep_Algo_project(
+            shape: &TopoDS_Shape,
+            axis: &gp_Ax2,
+        ) -> Result<UniquePtr<HLRBRep_HLRToShape>>;
+        pub fn HLRBRep_HLRToShape_compound(
+            to_shape: Pin<&mut HLRBRep_HLRToShape>,
+            edge_type: HLRBRep_TypeOfResultingEdge,
+            visible: bool,
+        ) -> UniquePtr<TopoDS_Shape>;
+
+        type HLRBRep_PolyHLRToShape;
+        pub fn HLRBRep_PolyAlgo_project(
+            shape: &TopoDS_Shape,
+            axis: &gp_Ax2,
+            deflection: f64,
+        ) -> Result<UniquePtr<HLRBRep_PolyHLRToShape>>;
+        pub fn HLRBRep_PolyHLRToShape_compound(
+            to_shape: Pin<&mut HLRBRep_PolyHLRToShape>,
+            edge_type: HLRBRep_TypeOfResultingEdge,
+            visible: bool,
+        ) -> UniquePtr<TopoDS_Shape>;
+    }
+}
@@ -32,5 +32,6 @@ mod inner {
         ) -> bool;
         pub fn ComputeModel(self: Pin<&mut IGESControl_Writer>);
         pub fn write_iges(writer: Pin<&mut IGESControl_Writer>, filename: String) -> bool;
+        pub fn write_iges_stream(writer: Pin<&mut IGESControl_Writer>, data: &mut Vec<u8>) -> bool;
     }
 }
@@ -0,0 +1,14 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/interface.hxx");
+
+        // These set global data exchange parameters, which are read when a
+        // file is loaded or written.
+        pub fn Interface_Static_SetCVal(name: &str, value: &str) -> bool;
+        pub fn Interface_Static_SetIVal(name: &str, value: i32) -> bool;
+        pub fn Interface_Static_SetRVal(name: &str, value: f64) -> bool;
+    }
+}
@@ -1,8 +1,10 @@
+pub mod approx;
 pub mod b_rep;
 pub mod b_rep_adaptor;
 pub mod b_rep_algo_api;
 pub mod b_rep_bnd_lib;
 pub mod b_rep_builder_api;
+pub mod b_rep_check;
 pub mod b_rep_feat;
 pub mod b_rep_fillet_api;
 pub mod b_rep_g_prop;
@@ -23,19 +25,30 @@ pub mod geom2d;
 pub mod geom_abs;
 pub mod geom_api;
 pub mod gp;
+pub mod hlr_b_rep;
 pub mod if_select;
 pub mod iges_control;
+pub mod interface;
 pub mod law;
 pub mod message;
 pub mod poly;
+pub mod quantity;
 pub mod shape_analysis;
+pub mod shape_fix;
 pub mod shape_upgrade;
 pub mod standard;
+pub mod step_caf_control;
 pub mod step_control;
 pub mod stl_api;
 pub mod t_col_gp;
+pub mod t_col_std;
+pub mod t_data_std;
+pub mod t_doc_std;
+pub mod tdf;
 pub mod top_abs;
 pub mod top_exp;
 pub mod top_loc;
 pub mod top_tools;
 pub mod topo_ds;
+pub mod xcaf_app;
+pub mod xcaf_doc;
@@ -17,6 +17,13 @@ mod inner {
         pub fn Handle_Poly_Triangulation_Get(
             handle: &Handle_Poly_Triangulation,
         ) -> Result<&Poly_Triangulation>;
+
+        type Handle_Poly_PolygonOnTriangulation;
+        pub fn IsNull(self: &Handle_Poly_PolygonOnTriangulation) -> bool;
+        #[cxx_name = "handle_try_deref"]
+        pub fn Handle_Poly_PolygonOnTriangulation_Get(
+            handle: &Handle_Poly_PolygonOnTriangulation,
+        ) -> Result<&Poly_PolygonOnTriangulation>;
         // End Handles
 
         type Poly_Triangulation;
@@ -54,6 +61,10 @@ mod inner {
         fn Triangle_new(node1: i32, node2: i32, node3: i32) -> UniquePtr<Poly_Triangle>;
         fn Value(self: &Poly_Triangle, index: i32) -> i32;
 
+        type Poly_PolygonOnTriangulation;
+        fn NbNodes(self: &Poly_PolygonOnTriangulation) -> i32;
+        fn Node(self: &Poly_PolygonOnTriangulation, index: i32) -> i32;
+
         type Poly_Connect;
         #[cxx_name = "construct_unique"]
         fn Connect_new(triangulation: &Handle_Poly_Triangulation) -> UniquePtr<Poly_Connect>;
@@ -64,4 +75,5 @@ mod inner {
     }
 
     impl UniquePtr<Handle_Poly_Triangulation> {}
+    impl UniquePtr<Handle_Poly_PolygonOnTriangulation> {}
 }
@@ -0,0 +1,16 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/quantity.hxx");
+
+        type Quantity_Color;
+        #[cxx_name = "construct_unique"]
+        pub fn Quantity_Color_new() -> UniquePtr<Quantity_Color>;
+        pub fn Quantity_Color_new_rgb(r: f64, g: f64, b: f64) -> UniquePtr<Quantity_Color>;
+        pub fn Red(self: &Quantity_Color) -> f64;
+        pub fn Green(self: &Quantity_Color) -> f64;
+        pub fn Blue(self: &Quantity_Color) -> f64;
+    }
+}
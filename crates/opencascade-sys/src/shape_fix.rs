@@ -0,0 +1,12 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/shape_fix.hxx");
+
+        type TopoDS_Face = crate::topo_ds::TopoDS_Face;
+
+        pub fn ShapeFix_Face_fixed(face: &TopoDS_Face) -> UniquePtr<TopoDS_Face>;
+    }
+}
@@ -0,0 +1,41 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/step_caf_control.hxx");
+
+        type IFSelect_ReturnStatus = crate::if_select::IFSelect_ReturnStatus;
+        type Handle_TDocStd_Document = crate::t_doc_std::Handle_TDocStd_Document;
+
+        type STEPCAFControl_Reader;
+        #[cxx_name = "construct_unique"]
+        pub fn STEPCAFControl_Reader_new() -> UniquePtr<STEPCAFControl_Reader>;
+        pub fn SetColorMode(self: Pin<&mut STEPCAFControl_Reader>, color_mode: bool);
+        pub fn SetNameMode(self: Pin<&mut STEPCAFControl_Reader>, name_mode: bool);
+        pub fn SetLayerMode(self: Pin<&mut STEPCAFControl_Reader>, layer_mode: bool);
+        pub fn read_step_caf(
+            reader: Pin<&mut STEPCAFControl_Reader>,
+            filename: String,
+        ) -> IFSelect_ReturnStatus;
+        pub fn transfer_document(
+            reader: Pin<&mut STEPCAFControl_Reader>,
+            document: &Handle_TDocStd_Document,
+        ) -> Result<bool>;
+
+        type STEPCAFControl_Writer;
+        #[cxx_name = "construct_unique"]
+        pub fn STEPCAFControl_Writer_new() -> UniquePtr<STEPCAFControl_Writer>;
+        pub fn SetColorMode(self: Pin<&mut STEPCAFControl_Writer>, color_mode: bool);
+        pub fn SetNameMode(self: Pin<&mut STEPCAFControl_Writer>, name_mode: bool);
+        pub fn SetLayerMode(self: Pin<&mut STEPCAFControl_Writer>, layer_mode: bool);
+        pub fn transfer_document_to_writer(
+            writer: Pin<&mut STEPCAFControl_Writer>,
+            document: &Handle_TDocStd_Document,
+        ) -> Result<bool>;
+        pub fn write_step_caf(
+            writer: Pin<&mut STEPCAFControl_Writer>,
+            filename: String,
+        ) -> IFSelect_ReturnStatus;
+    }
+}
@@ -2,6 +2,19 @@ pub use inner::*;
 
 #[cxx::bridge]
 mod inner {
+    #[repr(u32)]
+    #[derive(Debug)]
+    pub enum STEPControl_StepModelType {
+        STEPControl_AsIs,
+        STEPControl_ManifoldSolidBrep,
+        STEPControl_BrepWithVoids,
+        STEPControl_FacetedBrep,
+        STEPControl_FacetedBrepAndBrepWithVoids,
+        STEPControl_ShellBasedSurfaceModel,
+        STEPControl_GeometricCurveSet,
+        STEPControl_Hybrid,
+    }
+
     unsafe extern "C++" {
         include!("opencascade-sys/include/step_control.hxx");
 
@@ -9,6 +22,8 @@ mod inner {
         type IFSelect_ReturnStatus = crate::if_select::IFSelect_ReturnStatus;
         type Message_ProgressRange = crate::message::Message_ProgressRange;
 
+        type STEPControl_StepModelType;
+
         type STEPControl_Reader;
         #[cxx_name = "construct_unique"]
         pub fn STEPControl_Reader_new() -> UniquePtr<STEPControl_Reader>;
@@ -16,23 +31,56 @@ mod inner {
             reader: Pin<&mut STEPControl_Reader>,
             filename: String,
         ) -> IFSelect_ReturnStatus;
+        pub fn read_step_stream(
+            reader: Pin<&mut STEPControl_Reader>,
+            data: &[u8],
+        ) -> IFSelect_ReturnStatus;
         pub fn TransferRoots(
             self: Pin<&mut STEPControl_Reader>,
             progress: &Message_ProgressRange,
         ) -> i32;
         pub fn one_shape_step(reader: &STEPControl_Reader) -> UniquePtr<TopoDS_Shape>;
+        pub fn NbRootsForTransfer(self: Pin<&mut STEPControl_Reader>) -> i32;
+        pub fn TransferRoot(
+            self: Pin<&mut STEPControl_Reader>,
+            num: i32,
+            progress: &Message_ProgressRange,
+        ) -> bool;
+        pub fn NbShapes(self: &STEPControl_Reader) -> i32;
+        pub fn step_reader_shape(reader: &STEPControl_Reader, num: i32) -> UniquePtr<TopoDS_Shape>;
+        pub fn step_root_name(reader: Pin<&mut STEPControl_Reader>, num: i32) -> String;
+        pub fn step_reader_checks(
+            reader: &STEPControl_Reader,
+            warnings: &mut Vec<String>,
+            fails: &mut Vec<String>,
+        );
 
         type STEPControl_Writer;
         #[cxx_name = "construct_unique"]
         pub fn STEPControl_Writer_new() -> UniquePtr<STEPControl_Writer>;
-        // TODO(bschwind) - Expose the full list of parameters here.
         pub fn transfer_shape(
             writer: Pin<&mut STEPControl_Writer>,
             shape: &TopoDS_Shape,
         ) -> IFSelect_ReturnStatus;
+        pub fn transfer_shape_as(
+            writer: Pin<&mut STEPControl_Writer>,
+            shape: &TopoDS_Shape,
+            mode: STEPControl_StepModelType,
+            product_name: &str,
+        ) -> Result<IFSelect_ReturnStatus>;
+        pub fn step_writer_set_header(
+            writer: Pin<&mut STEPControl_Writer>,
+            name: &str,
+            author: &str,
+            organization: &str,
+        );
         pub fn write_step(
             writer: Pin<&mut STEPControl_Writer>,
             filename: String,
         ) -> IFSelect_ReturnStatus;
+        pub fn write_step_stream(
+            writer: Pin<&mut STEPControl_Writer>,
+            data: &mut Vec<u8>,
+        ) -> IFSelect_ReturnStatus;
     }
 }
@@ -6,6 +6,7 @@ mod inner {
         include!("opencascade-sys/include/stl_api.hxx");
 
         type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
+        type Handle_Poly_Triangulation = crate::poly::Handle_Poly_Triangulation;
 
         type StlAPI_Writer;
         #[cxx_name = "construct_unique"]
@@ -15,5 +16,7 @@ mod inner {
             shape: &TopoDS_Shape,
             filename: String,
         ) -> bool;
+
+        fn read_stl(filename: String) -> UniquePtr<Handle_Poly_Triangulation>;
     }
 }
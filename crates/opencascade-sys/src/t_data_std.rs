@@ -0,0 +1,13 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/t_data_std.hxx");
+
+        type TDF_Label = crate::tdf::TDF_Label;
+
+        pub fn TDataStd_Name_Get(label: &TDF_Label, name: &mut String) -> bool;
+        pub fn TDataStd_Name_Set(label: &TDF_Label, name: &str);
+    }
+}
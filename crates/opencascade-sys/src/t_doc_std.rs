@@ -0,0 +1,23 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/t_doc_std.hxx");
+
+        type TDF_Label = crate::tdf::TDF_Label;
+
+        // Handles
+        type Handle_TDocStd_Document;
+        pub fn IsNull(self: &Handle_TDocStd_Document) -> bool;
+        #[cxx_name = "construct_unique"]
+        pub fn Handle_TDocStd_Document_to_owned(
+            document: &Handle_TDocStd_Document,
+        ) -> UniquePtr<Handle_TDocStd_Document>;
+        // End Handles
+
+        pub fn TDocStd_Document_Main(document: &Handle_TDocStd_Document) -> UniquePtr<TDF_Label>;
+    }
+
+    impl UniquePtr<Handle_TDocStd_Document> {}
+}
@@ -0,0 +1,23 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/tdf.hxx");
+
+        type TDF_Label;
+        #[cxx_name = "construct_unique"]
+        pub fn TDF_Label_new() -> UniquePtr<TDF_Label>;
+        #[cxx_name = "construct_unique"]
+        pub fn TDF_Label_to_owned(label: &TDF_Label) -> UniquePtr<TDF_Label>;
+        pub fn IsNull(self: &TDF_Label) -> bool;
+        pub fn IsEqual(self: &TDF_Label, other: &TDF_Label) -> bool;
+        pub fn Tag(self: &TDF_Label) -> i32;
+
+        type TDF_LabelSequence;
+        #[cxx_name = "construct_unique"]
+        pub fn TDF_LabelSequence_new() -> UniquePtr<TDF_LabelSequence>;
+        pub fn Length(self: &TDF_LabelSequence) -> i32;
+        pub fn TDF_LabelSequence_Value(sequence: &TDF_LabelSequence, index: i32) -> &TDF_Label;
+    }
+}
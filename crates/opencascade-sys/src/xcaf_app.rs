@@ -0,0 +1,12 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/xcaf_app.hxx");
+
+        type Handle_TDocStd_Document = crate::t_doc_std::Handle_TDocStd_Document;
+
+        pub fn XCAFApp_Application_NewDocument() -> UniquePtr<Handle_TDocStd_Document>;
+    }
+}
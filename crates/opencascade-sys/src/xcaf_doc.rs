@@ -0,0 +1,106 @@
+pub use inner::*;
+
+#[cxx::bridge]
+mod inner {
+    #[repr(u32)]
+    #[derive(Debug)]
+    pub enum XCAFDoc_ColorType {
+        XCAFDoc_ColorGen,
+        XCAFDoc_ColorSurf,
+        XCAFDoc_ColorCurv,
+    }
+
+    unsafe extern "C++" {
+        include!("opencascade-sys/include/xcaf_doc.hxx");
+
+        type TDF_Label = crate::tdf::TDF_Label;
+        type TDF_LabelSequence = crate::tdf::TDF_LabelSequence;
+        type TopoDS_Shape = crate::topo_ds::TopoDS_Shape;
+        type TopLoc_Location = crate::top_loc::TopLoc_Location;
+        type Quantity_Color = crate::quantity::Quantity_Color;
+        type Handle_TDocStd_Document = crate::t_doc_std::Handle_TDocStd_Document;
+
+        type XCAFDoc_ColorType;
+
+        // Handles
+        type Handle_XCAFDoc_ShapeTool;
+        type Handle_XCAFDoc_ColorTool;
+        type Handle_XCAFDoc_LayerTool;
+        // End Handles
+
+        pub fn XCAFDoc_DocumentTool_ShapeTool(
+            document: &Handle_TDocStd_Document,
+        ) -> UniquePtr<Handle_XCAFDoc_ShapeTool>;
+        pub fn XCAFDoc_DocumentTool_ColorTool(
+            document: &Handle_TDocStd_Document,
+        ) -> UniquePtr<Handle_XCAFDoc_ColorTool>;
+        pub fn XCAFDoc_DocumentTool_LayerTool(
+            document: &Handle_TDocStd_Document,
+        ) -> UniquePtr<Handle_XCAFDoc_LayerTool>;
+
+        // Shape tool
+        pub fn XCAFDoc_ShapeTool_GetFreeShapes(
+            tool: &Handle_XCAFDoc_ShapeTool,
+            labels: Pin<&mut TDF_LabelSequence>,
+        );
+        pub fn XCAFDoc_ShapeTool_GetShape(label: &TDF_Label) -> UniquePtr<TopoDS_Shape>;
+        pub fn XCAFDoc_ShapeTool_IsAssembly(label: &TDF_Label) -> bool;
+        pub fn XCAFDoc_ShapeTool_IsReference(label: &TDF_Label) -> bool;
+        pub fn XCAFDoc_ShapeTool_GetComponents(
+            label: &TDF_Label,
+            labels: Pin<&mut TDF_LabelSequence>,
+        ) -> bool;
+        pub fn XCAFDoc_ShapeTool_GetReferredShape(
+            label: &TDF_Label,
+            referred: Pin<&mut TDF_Label>,
+        ) -> bool;
+        pub fn XCAFDoc_ShapeTool_GetLocation(label: &TDF_Label) -> UniquePtr<TopLoc_Location>;
+        pub fn XCAFDoc_ShapeTool_NewShape(tool: &Handle_XCAFDoc_ShapeTool) -> UniquePtr<TDF_Label>;
+        pub fn XCAFDoc_ShapeTool_AddShape(
+            tool: &Handle_XCAFDoc_ShapeTool,
+            shape: &TopoDS_Shape,
+        ) -> UniquePtr<TDF_Label>;
+        pub fn XCAFDoc_ShapeTool_AddComponent(
+            tool: &Handle_XCAFDoc_ShapeTool,
+            assembly: &TDF_Label,
+            part: &TDF_Label,
+            location: &TopLoc_Location,
+        ) -> UniquePtr<TDF_Label>;
+        pub fn XCAFDoc_ShapeTool_RemoveComponent(
+            tool: &Handle_XCAFDoc_ShapeTool,
+            component: &TDF_Label,
+        );
+        pub fn XCAFDoc_ShapeTool_UpdateAssemblies(tool: &Handle_XCAFDoc_ShapeTool);
+        pub fn XCAFDoc_Location_Set(label: &TDF_Label, location: &TopLoc_Location);
+
+        // Color tool
+        pub fn XCAFDoc_ColorTool_GetColor(
+            tool: &Handle_XCAFDoc_ColorTool,
+            label: &TDF_Label,
+            color_type: XCAFDoc_ColorType,
+            color: Pin<&mut Quantity_Color>,
+        ) -> bool;
+        pub fn XCAFDoc_ColorTool_SetColor(
+            tool: &Handle_XCAFDoc_ColorTool,
+            label: &TDF_Label,
+            color: &Quantity_Color,
+            color_type: XCAFDoc_ColorType,
+        );
+
+        // Layer tool
+        pub fn XCAFDoc_LayerTool_GetLayers(
+            tool: &Handle_XCAFDoc_LayerTool,
+            label: &TDF_Label,
+            layers: &mut Vec<String>,
+        ) -> bool;
+        pub fn XCAFDoc_LayerTool_SetLayer(
+            tool: &Handle_XCAFDoc_LayerTool,
+            label: &TDF_Label,
+            layer: &str,
+        );
+    }
+
+    impl UniquePtr<Handle_XCAFDoc_ShapeTool> {}
+    impl UniquePtr<Handle_XCAFDoc_ColorTool> {}
+    impl UniquePtr<Handle_XCAFDoc_LayerTool> {}
+}
@@ -52,6 +52,13 @@ pub fn aabb(shape: &Shape) -> BoundingBox {
     bb
 }
 
+/// The highest Z coordinate of `shape`, without the gap of its bounding box.
+#[cfg(test)]
+pub(crate) fn max_z(shape: &Shape) -> f64 {
+    let bounds = aabb(shape);
+    (bounds.max() - bounds.gap_vec()).z
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,450 @@
+use crate::{
+    primitives::{BSplineGeometry, Edge, EdgeType},
+    workplane::Workplane,
+    Error,
+};
+use glam::{dvec2, dvec3, DAffine2, DVec2, DVec3};
+use std::f64::consts::TAU;
+
+/// Points closer than this are the same point to OCCT (`Precision::Confusion`),
+/// so curves between them are skipped when placing them as edges.
+const DEGENERATE_LENGTH: f64 = 1.0e-7;
+
+/// A planar curve, used by the DXF and SVG writers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve2d {
+    Line {
+        start: DVec2,
+        end: DVec2,
+    },
+    /// A counter-clockwise arc from `start_angle` to `end_angle`, in radians.
+    /// `start_angle` is in `0..TAU` and `end_angle` is greater than it.
+    Arc {
+        center: DVec2,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+    },
+    Circle {
+        center: DVec2,
+        radius: f64,
+    },
+    /// A clamped B-spline, with one weight per control point and every knot
+    /// repeated by its multiplicity.
+    Spline {
+        degree: usize,
+        control_points: Vec<DVec2>,
+        weights: Vec<f64>,
+        knots: Vec<f64>,
+    },
+    Polyline {
+        points: Vec<DVec2>,
+        closed: bool,
+    },
+}
+
+impl Curve2d {
+    /// Project an edge onto `plane`, in the plane's local coordinates. Circles
+    /// which aren't parallel to the plane, and curves without an exact planar
+    /// equivalent, are approximated with polylines.
+    pub fn from_edge(edge: &Edge, plane: &Workplane) -> Self {
+        let project = |point: DVec3| plane.to_local_pos(point).truncate();
+        let (first, last) = edge.parameter_range();
+
+        match edge.edge_type() {
+            EdgeType::Line => Self::Line {
+                start: project(edge.point_at(first)),
+                end: project(edge.point_at(last)),
+            },
+            EdgeType::Circle => match edge.circle_geometry() {
+                Some(circle) if circle.normal.dot(plane.normal()).abs() > 1.0 - 1.0e-9 => {
+                    let center = project(circle.center);
+
+                    if last - first >= TAU - 1.0e-9 {
+                        return Self::Circle { center, radius: circle.radius };
+                    }
+
+                    // Arcs go counter-clockwise around their normal, so start from the
+                    // other end when it faces away from the plane.
+                    let start = if circle.normal.dot(plane.normal()) > 0.0 { first } else { last };
+                    let start_angle = angle_of(project(edge.point_at(start)) - center);
+
+                    Self::Arc {
+                        center,
+                        radius: circle.radius,
+                        start_angle,
+                        end_angle: start_angle + (last - first),
+                    }
+                },
+                _ => Self::approximate(edge, plane),
+            },
+            EdgeType::BSplineCurve | EdgeType::BezierCurve => match edge.bspline_geometry() {
+                Some(bspline) => Self::Spline {
+                    degree: bspline.degree,
+                    control_points: bspline.poles.iter().map(|&pole| project(pole)).collect(),
+                    knots: bspline.flat_knots(),
+                    weights: bspline.weights,
+                },
+                None => Self::approximate(edge, plane),
+            },
+            _ => Self::approximate(edge, plane),
+        }
+    }
+
+    pub fn from_edges<T: AsRef<Edge>>(
+        edges: impl IntoIterator<Item = T>,
+        plane: &Workplane,
+    ) -> Vec<Self> {
+        edges.into_iter().map(|edge| Self::from_edge(edge.as_ref(), plane)).collect()
+    }
+
+    /// Place this curve on `plane`, as the inverse of [`Curve2d::from_edge`].
+    /// Polylines become one edge per segment.
+    pub fn to_edges(&self, plane: &Workplane) -> Result<Vec<Edge>, Error> {
+        let place = |point: DVec2| plane.to_world_pos(point.extend(0.0));
+
+        Ok(match self {
+            Self::Line { start, end } if start.distance(*end) <= DEGENERATE_LENGTH => vec![],
+            Self::Line { start, end } => vec![Edge::segment(place(*start), place(*end))],
+            // The arc is made through its middle, which has to be apart from both ends.
+            Self::Arc { radius, start_angle, end_angle, .. }
+                if radius * (end_angle - start_angle) / 2.0 <= DEGENERATE_LENGTH =>
+            {
+                vec![]
+            },
+            Self::Arc { center, radius, start_angle, end_angle }
+                if (end_angle - start_angle - TAU).abs() * radius <= DEGENERATE_LENGTH =>
+            {
+                // The ends of a full turn meet, so there's no arc through them.
+                let circle = Self::Circle { center: *center, radius: *radius };
+                return circle.to_edges(plane);
+            },
+            Self::Arc { center, radius, start_angle, end_angle } => {
+                let mid_angle = (start_angle + end_angle) / 2.0;
+                vec![Edge::arc(
+                    place(self.start_point()),
+                    place(point_on_circle(*center, *radius, mid_angle)),
+                    place(self.end_point()),
+                )]
+            },
+            Self::Circle { radius, .. } if *radius <= DEGENERATE_LENGTH => vec![],
+            Self::Circle { center, radius } => {
+                vec![Edge::circle(place(*center), plane.normal(), *radius)]
+            },
+            Self::Spline { degree, control_points, weights, knots } => {
+                let mut distinct: Vec<f64> = vec![];
+                let mut multiplicities = vec![];
+                for &knot in knots {
+                    match distinct.last() {
+                        Some(&last) if knot - last <= 1.0e-12 => {
+                            *multiplicities.last_mut().unwrap() += 1
+                        },
+                        _ => {
+                            distinct.push(knot);
+                            multiplicities.push(1);
+                        },
+                    }
+                }
+
+                vec![Edge::bspline(&BSplineGeometry {
+                    degree: *degree,
+                    poles: control_points.iter().map(|&point| place(point)).collect(),
+                    weights: weights.clone(),
+                    knots: distinct,
+                    multiplicities,
+                })?]
+            },
+            Self::Polyline { .. } => self
+                .sample(TAU)
+                .windows(2)
+                .filter(|segment| segment[0].distance(segment[1]) > DEGENERATE_LENGTH)
+                .map(|segment| Edge::segment(place(segment[0]), place(segment[1])))
+                .collect(),
+        })
+    }
+
+    fn approximate(edge: &Edge, plane: &Workplane) -> Self {
+        let points = edge
+            .approximation_segments()
+            .map(|point| plane.to_local_pos(point).truncate())
+            .collect();
+
+        Self::Polyline { points, closed: false }
+    }
+
+    /// Apply a transformation made of translation, rotation, uniform scaling
+    /// and mirroring.
+    #[must_use]
+    pub fn transformed(&self, transform: DAffine2) -> Self {
+        let scale = transform.matrix2.determinant().abs().sqrt();
+        let mirrored = transform.matrix2.determinant() < 0.0;
+
+        match self {
+            Self::Line { start, end } => Self::Line {
+                start: transform.transform_point2(*start),
+                end: transform.transform_point2(*end),
+            },
+            Self::Arc { center, radius, start_angle, end_angle } => {
+                let angle = |angle: f64| {
+                    angle_of(transform.transform_vector2(dvec2(angle.cos(), angle.sin())))
+                };
+
+                // Mirroring reverses the direction of the arc.
+                let start = if mirrored { *end_angle } else { *start_angle };
+                let sweep = end_angle - start_angle;
+                let start_angle = angle(start);
+
+                Self::Arc {
+                    center: transform.transform_point2(*center),
+                    radius: radius * scale,
+                    start_angle,
+                    end_angle: start_angle + sweep,
+                }
+            },
+            Self::Circle { center, radius } => {
+                Self::Circle { center: transform.transform_point2(*center), radius: radius * scale }
+            },
+            Self::Spline { degree, control_points, weights, knots } => Self::Spline {
+                degree: *degree,
+                control_points: control_points
+                    .iter()
+                    .map(|&point| transform.transform_point2(point))
+                    .collect(),
+                weights: weights.clone(),
+                knots: knots.clone(),
+            },
+            Self::Polyline { points, closed } => Self::Polyline {
+                points: points.iter().map(|&point| transform.transform_point2(point)).collect(),
+                closed: *closed,
+            },
+        }
+    }
+
+    pub fn start_point(&self) -> DVec2 {
+        match self {
+            Self::Line { start, .. } => *start,
+            Self::Arc { center, radius, start_angle, .. } => {
+                point_on_circle(*center, *radius, *start_angle)
+            },
+            Self::Circle { center, radius } => point_on_circle(*center, *radius, 0.0),
+            Self::Spline { control_points, .. } => control_points[0],
+            Self::Polyline { points, .. } => points[0],
+        }
+    }
+
+    pub fn end_point(&self) -> DVec2 {
+        match self {
+            Self::Line { end, .. } => *end,
+            Self::Arc { center, radius, end_angle, .. } => {
+                point_on_circle(*center, *radius, *end_angle)
+            },
+            Self::Circle { center, radius } => point_on_circle(*center, *radius, 0.0),
+            Self::Spline { control_points, .. } => control_points[control_points.len() - 1],
+            Self::Polyline { points, closed } => {
+                if *closed {
+                    points[0]
+                } else {
+                    points[points.len() - 1]
+                }
+            },
+        }
+    }
+
+    /// Points along the curve, with arcs split into segments of at most
+    /// `max_angle` radians.
+    pub fn sample(&self, max_angle: f64) -> Vec<DVec2> {
+        let arc_points = |center: DVec2, radius: f64, start: f64, end: f64| {
+            let count = (((end - start) / max_angle).ceil() as usize).max(1);
+            (0..=count)
+                .map(|i| {
+                    point_on_circle(center, radius, start + (end - start) * i as f64 / count as f64)
+                })
+                .collect()
+        };
+
+        match self {
+            Self::Line { start, end } => vec![*start, *end],
+            Self::Arc { center, radius, start_angle, end_angle } => {
+                arc_points(*center, *radius, *start_angle, *end_angle)
+            },
+            Self::Circle { center, radius } => arc_points(*center, *radius, 0.0, TAU),
+            Self::Spline { .. } => {
+                const SAMPLES_PER_SEGMENT: usize = 16;
+
+                let segments = self.bezier_segments();
+                let mut points = vec![self.start_point()];
+                for segment in &segments {
+                    points.extend(
+                        (1..=SAMPLES_PER_SEGMENT).map(|i| {
+                            evaluate_bezier(segment, i as f64 / SAMPLES_PER_SEGMENT as f64)
+                        }),
+                    );
+                }
+
+                points
+            },
+            Self::Polyline { points, closed } => {
+                let mut points = points.clone();
+                if *closed {
+                    points.push(points[0]);
+                }
+
+                points
+            },
+        }
+    }
+
+    /// Split a spline into Bezier segments, with control points in homogeneous
+    /// coordinates (`x * w`, `y * w`, `w`). Returns an empty list for other curves.
+    pub(crate) fn bezier_segments(&self) -> Vec<Vec<DVec3>> {
+        let Self::Spline { degree, control_points, weights, knots } = self else {
+            return vec![];
+        };
+
+        let degree = *degree;
+        let mut points: Vec<DVec3> = control_points
+            .iter()
+            .zip(weights)
+            .map(|(point, &weight)| dvec3(point.x * weight, point.y * weight, weight))
+            .collect();
+        let mut knots = knots.clone();
+
+        // Insert every interior knot until its multiplicity equals the degree.
+        let interior = knots[degree + 1..knots.len() - degree - 1].to_vec();
+        let mut distinct: Vec<f64> = vec![];
+        for knot in interior {
+            if distinct.last().is_none_or(|&last| knot - last > 1.0e-12) {
+                distinct.push(knot);
+            }
+        }
+
+        for knot in distinct {
+            let multiplicity = knots.iter().filter(|&&k| (k - knot).abs() <= 1.0e-12).count();
+            for _ in multiplicity..degree {
+                insert_knot(degree, &mut points, &mut knots, knot);
+            }
+        }
+
+        points.windows(degree + 1).step_by(degree).map(|segment| segment.to_vec()).collect()
+    }
+
+    /// Whether this is a spline which can be drawn with lines, quadratic or
+    /// cubic Bezier curves exactly.
+    pub(crate) fn is_polynomial_up_to_cubic(&self) -> bool {
+        match self {
+            Self::Spline { degree, weights, .. } => {
+                *degree <= 3 && weights.iter().all(|weight| (weight - weights[0]).abs() < 1.0e-12)
+            },
+            _ => false,
+        }
+    }
+}
+
+/// The minimum and maximum corner of the box containing all curves.
+pub fn bounds<'a>(curves: impl IntoIterator<Item = &'a Curve2d>) -> Option<(DVec2, DVec2)> {
+    const MAX_ANGLE: f64 = TAU / 64.0;
+
+    curves.into_iter().flat_map(|curve| curve.sample(MAX_ANGLE)).fold(None, |bounds, point| {
+        Some(match bounds {
+            Some((min, max)) => (point.min(min), point.max(max)),
+            None => (point, point),
+        })
+    })
+}
+
+/// The angle of `vector` in `0..TAU`.
+pub(crate) fn angle_of(vector: DVec2) -> f64 {
+    vector.y.atan2(vector.x).rem_euclid(TAU)
+}
+
+pub(crate) fn point_on_circle(center: DVec2, radius: f64, angle: f64) -> DVec2 {
+    center + radius * dvec2(angle.cos(), angle.sin())
+}
+
+/// Format a coordinate for text based file formats, without trailing zeros.
+pub(crate) fn format_number(value: f64) -> String {
+    let formatted = format!("{value:.6}");
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    match trimmed {
+        "-0" | "" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+fn insert_knot(degree: usize, points: &mut Vec<DVec3>, knots: &mut Vec<f64>, knot: f64) {
+    let span = knots.iter().rposition(|&k| k <= knot).unwrap().min(knots.len() - degree - 2);
+
+    let mut inserted = Vec::with_capacity(points.len() + 1);
+    for i in 0..=points.len() {
+        let point = if i + degree <= span {
+            points[i]
+        } else if i > span {
+            points[i - 1]
+        } else {
+            let alpha = (knot - knots[i]) / (knots[i + degree] - knots[i]);
+            points[i - 1] * (1.0 - alpha) + points[i] * alpha
+        };
+
+        inserted.push(point);
+    }
+
+    *points = inserted;
+    knots.insert(span + 1, knot);
+}
+
+fn evaluate_bezier(points: &[DVec3], t: f64) -> DVec2 {
+    let mut points = points.to_vec();
+    for level in 1..points.len() {
+        for i in 0..points.len() - level {
+            points[i] = points[i].lerp(points[i + 1], t);
+        }
+    }
+
+    points[0].truncate() / points[0].z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spline_splits_into_bezier_segments() {
+        let spline = Curve2d::Spline {
+            degree: 3,
+            control_points: vec![
+                dvec2(0.0, 0.0),
+                dvec2(1.0, 2.0),
+                dvec2(2.0, -1.0),
+                dvec2(3.0, 2.0),
+                dvec2(4.0, 0.0),
+            ],
+            weights: vec![1.0; 5],
+            knots: vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0],
+        };
+
+        let segments = spline.bezier_segments();
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().all(|segment| segment.len() == 4));
+
+        assert_eq!(segments[0][0].truncate(), dvec2(0.0, 0.0));
+        assert_eq!(segments[1][3].truncate(), dvec2(4.0, 0.0));
+        // The segments are joined.
+        assert_eq!(segments[0][3], segments[1][0]);
+    }
+
+    #[test]
+    fn mirrored_arc_stays_counter_clockwise() {
+        let arc = Curve2d::Arc {
+            center: DVec2::ZERO,
+            radius: 1.0,
+            start_angle: 0.0,
+            end_angle: TAU / 4.0,
+        };
+
+        let mirrored = arc.transformed(DAffine2::from_scale(dvec2(1.0, -1.0)));
+
+        assert!(mirrored.start_point().abs_diff_eq(dvec2(0.0, -1.0), 1.0e-12));
+        assert!(mirrored.end_point().abs_diff_eq(dvec2(1.0, 0.0), 1.0e-12));
+    }
+}
@@ -0,0 +1,382 @@
+use crate::{
+    geometry::Transform,
+    primitives::Shape,
+    step::{self, StepReader, StepWriter},
+    Error,
+};
+use cxx::UniquePtr;
+use glam::DAffine3;
+use opencascade_sys as ffi;
+use std::path::Path;
+
+/// An RGB color with components in the range `0.0..=1.0`, in linear color space.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl Color {
+    pub const fn rgb(r: f64, g: f64, b: f64) -> Self {
+        Self { r, g, b }
+    }
+
+    fn to_ffi(self) -> UniquePtr<ffi::quantity::Quantity_Color> {
+        ffi::quantity::Quantity_Color_new_rgb(self.r, self.g, self.b)
+    }
+}
+
+/// What a [`Label`] in a [`Document`] represents.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LabelKind {
+    /// A shape with geometry.
+    Part,
+    /// A shape made of components.
+    Assembly,
+    /// An instance of a part or assembly placed in an assembly.
+    Component,
+}
+
+/// A node of the shape tree of a [`Document`].
+pub struct Label {
+    pub(crate) inner: UniquePtr<ffi::tdf::TDF_Label>,
+    // Labels are only valid as long as their document is alive.
+    _document: UniquePtr<ffi::t_doc_std::Handle_TDocStd_Document>,
+}
+
+impl Label {
+    fn from_label(
+        document: &ffi::t_doc_std::Handle_TDocStd_Document,
+        label: &ffi::tdf::TDF_Label,
+    ) -> Self {
+        Self {
+            inner: ffi::tdf::TDF_Label_to_owned(label),
+            _document: ffi::t_doc_std::Handle_TDocStd_Document_to_owned(document),
+        }
+    }
+}
+
+impl PartialEq for Label {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.IsEqual(&other.inner)
+    }
+}
+
+/// An XDE document holding an assembly tree of shapes with names, colors,
+/// layers and placements, which is preserved when reading and writing STEP files.
+pub struct Document {
+    inner: UniquePtr<ffi::t_doc_std::Handle_TDocStd_Document>,
+    shape_tool: UniquePtr<ffi::xcaf_doc::Handle_XCAFDoc_ShapeTool>,
+    color_tool: UniquePtr<ffi::xcaf_doc::Handle_XCAFDoc_ColorTool>,
+    layer_tool: UniquePtr<ffi::xcaf_doc::Handle_XCAFDoc_LayerTool>,
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Document {
+    pub fn new() -> Self {
+        let inner = ffi::xcaf_app::XCAFApp_Application_NewDocument();
+        let shape_tool = ffi::xcaf_doc::XCAFDoc_DocumentTool_ShapeTool(&inner);
+        let color_tool = ffi::xcaf_doc::XCAFDoc_DocumentTool_ColorTool(&inner);
+        let layer_tool = ffi::xcaf_doc::XCAFDoc_DocumentTool_LayerTool(&inner);
+
+        Self { inner, shape_tool, color_tool, layer_tool }
+    }
+
+    pub fn read_step(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let document = Self::new();
+        let _settings = step::lock_settings();
+        let mut reader = ffi::step_caf_control::STEPCAFControl_Reader_new();
+        StepReader::new().apply_settings();
+        reader.pin_mut().SetColorMode(true);
+        reader.pin_mut().SetNameMode(true);
+        reader.pin_mut().SetLayerMode(true);
+
+        let status = ffi::step_caf_control::read_step_caf(
+            reader.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+
+        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
+            return Err(Error::StepReadFailed);
+        }
+
+        match ffi::step_caf_control::transfer_document(reader.pin_mut(), &document.inner) {
+            Ok(true) => Ok(document),
+            _ => Err(Error::StepReadFailed),
+        }
+    }
+
+    pub fn write_step(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let _settings = step::lock_settings();
+        let mut writer = ffi::step_caf_control::STEPCAFControl_Writer_new();
+        StepWriter::new().apply_settings();
+        writer.pin_mut().SetColorMode(true);
+        writer.pin_mut().SetNameMode(true);
+        writer.pin_mut().SetLayerMode(true);
+
+        match ffi::step_caf_control::transfer_document_to_writer(writer.pin_mut(), &self.inner) {
+            Ok(true) => {},
+            _ => return Err(Error::StepWriteFailed),
+        }
+
+        let status = ffi::step_caf_control::write_step_caf(
+            writer.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+
+        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
+            return Err(Error::StepWriteFailed);
+        }
+
+        Ok(())
+    }
+
+    /// The top level parts and assemblies, which aren't used as a component
+    /// of any assembly.
+    pub fn roots(&self) -> Vec<Label> {
+        let mut labels = ffi::tdf::TDF_LabelSequence_new();
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_GetFreeShapes(&self.shape_tool, labels.pin_mut());
+
+        self.labels(&labels)
+    }
+
+    /// The components of an assembly. Returns an empty list for anything else.
+    pub fn children(&self, assembly: &Label) -> Vec<Label> {
+        let mut labels = ffi::tdf::TDF_LabelSequence_new();
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_GetComponents(&assembly.inner, labels.pin_mut());
+
+        self.labels(&labels)
+    }
+
+    /// The part or assembly a component is an instance of.
+    pub fn referred(&self, component: &Label) -> Option<Label> {
+        let mut referred = ffi::tdf::TDF_Label_new();
+
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_GetReferredShape(&component.inner, referred.pin_mut())
+            .then(|| Label::from_label(&self.inner, &referred))
+    }
+
+    pub fn kind(&self, label: &Label) -> LabelKind {
+        if ffi::xcaf_doc::XCAFDoc_ShapeTool_IsReference(&label.inner) {
+            LabelKind::Component
+        } else if ffi::xcaf_doc::XCAFDoc_ShapeTool_IsAssembly(&label.inner) {
+            LabelKind::Assembly
+        } else {
+            LabelKind::Part
+        }
+    }
+
+    /// The shape of a label. Assemblies return a compound of their components,
+    /// and components return the referred shape moved to their placement.
+    #[must_use]
+    pub fn shape(&self, label: &Label) -> Shape {
+        let shape = ffi::xcaf_doc::XCAFDoc_ShapeTool_GetShape(&label.inner);
+
+        Shape::from_shape(&shape)
+    }
+
+    pub fn name(&self, label: &Label) -> Option<String> {
+        let mut name = String::new();
+        ffi::t_data_std::TDataStd_Name_Get(&label.inner, &mut name).then_some(name)
+    }
+
+    pub fn set_name(&mut self, label: &Label, name: &str) {
+        ffi::t_data_std::TDataStd_Name_Set(&label.inner, name);
+    }
+
+    /// The surface color of a label, falling back to its generic color.
+    pub fn color(&self, label: &Label) -> Option<Color> {
+        let mut color = ffi::quantity::Quantity_Color_new();
+
+        let found = [
+            ffi::xcaf_doc::XCAFDoc_ColorType::XCAFDoc_ColorSurf,
+            ffi::xcaf_doc::XCAFDoc_ColorType::XCAFDoc_ColorGen,
+        ]
+        .into_iter()
+        .any(|color_type| {
+            ffi::xcaf_doc::XCAFDoc_ColorTool_GetColor(
+                &self.color_tool,
+                &label.inner,
+                color_type,
+                color.pin_mut(),
+            )
+        });
+
+        found.then(|| Color::rgb(color.Red(), color.Green(), color.Blue()))
+    }
+
+    pub fn set_color(&mut self, label: &Label, color: Color) {
+        ffi::xcaf_doc::XCAFDoc_ColorTool_SetColor(
+            &self.color_tool,
+            &label.inner,
+            &color.to_ffi(),
+            ffi::xcaf_doc::XCAFDoc_ColorType::XCAFDoc_ColorSurf,
+        );
+    }
+
+    pub fn layers(&self, label: &Label) -> Vec<String> {
+        let mut layers = vec![];
+        ffi::xcaf_doc::XCAFDoc_LayerTool_GetLayers(&self.layer_tool, &label.inner, &mut layers);
+
+        layers
+    }
+
+    pub fn add_to_layer(&mut self, label: &Label, layer: &str) {
+        ffi::xcaf_doc::XCAFDoc_LayerTool_SetLayer(&self.layer_tool, &label.inner, layer);
+    }
+
+    /// The placement of a component within its assembly.
+    pub fn placement(&self, component: &Label) -> DAffine3 {
+        let location = ffi::xcaf_doc::XCAFDoc_ShapeTool_GetLocation(&component.inner);
+        let transform = ffi::top_loc::TopLoc_Location_Transformation(&location);
+
+        Transform::from(&*transform).into()
+    }
+
+    /// Move a component within its assembly. The placement must be a rigid
+    /// transformation, without scaling or shearing.
+    pub fn set_placement(&mut self, component: &Label, placement: DAffine3) -> Result<(), Error> {
+        let location = make_location(placement)?;
+        ffi::xcaf_doc::XCAFDoc_Location_Set(&component.inner, &location);
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_UpdateAssemblies(&self.shape_tool);
+
+        Ok(())
+    }
+
+    /// Add a top level part.
+    pub fn add_part(&mut self, name: &str, shape: &Shape) -> Label {
+        let label = ffi::xcaf_doc::XCAFDoc_ShapeTool_AddShape(&self.shape_tool, &shape.inner);
+        ffi::t_data_std::TDataStd_Name_Set(&label, name);
+
+        Label::from_label(&self.inner, &label)
+    }
+
+    /// Add an empty top level assembly.
+    pub fn add_assembly(&mut self, name: &str) -> Label {
+        let label = ffi::xcaf_doc::XCAFDoc_ShapeTool_NewShape(&self.shape_tool);
+        ffi::t_data_std::TDataStd_Name_Set(&label, name);
+
+        Label::from_label(&self.inner, &label)
+    }
+
+    /// Place an instance of a part or assembly in an assembly. The placement
+    /// must be a rigid transformation, without scaling or shearing.
+    pub fn add_component(
+        &mut self,
+        assembly: &Label,
+        part: &Label,
+        placement: DAffine3,
+    ) -> Result<Label, Error> {
+        let location = make_location(placement)?;
+        let label = ffi::xcaf_doc::XCAFDoc_ShapeTool_AddComponent(
+            &self.shape_tool,
+            &assembly.inner,
+            &part.inner,
+            &location,
+        );
+
+        if label.IsNull() {
+            return Err(Error::InvalidAssembly);
+        }
+
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_UpdateAssemblies(&self.shape_tool);
+
+        Ok(Label::from_label(&self.inner, &label))
+    }
+
+    pub fn remove_component(&mut self, component: &Label) {
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_RemoveComponent(&self.shape_tool, &component.inner);
+        ffi::xcaf_doc::XCAFDoc_ShapeTool_UpdateAssemblies(&self.shape_tool);
+    }
+
+    fn labels(&self, labels: &ffi::tdf::TDF_LabelSequence) -> Vec<Label> {
+        (1..=labels.Length())
+            .map(|i| Label::from_label(&self.inner, ffi::tdf::TDF_LabelSequence_Value(labels, i)))
+            .collect()
+    }
+}
+
+fn make_location(placement: DAffine3) -> Result<UniquePtr<ffi::top_loc::TopLoc_Location>, Error> {
+    let transform = Transform::from(placement);
+
+    // A similarity without scaling or mirroring is a rigid transformation.
+    let is_rigid =
+        transform.is_similarity() && (placement.matrix3.determinant() - 1.0).abs() <= 1.0e-9;
+    if !is_rigid {
+        return Err(Error::InvalidPlacement);
+    }
+
+    let transform = transform.to_gp_trsf().map_err(|_| Error::InvalidPlacement)?;
+
+    Ok(ffi::top_loc::Location_from_transform(&transform))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{dvec3, DQuat, DVec3};
+
+    #[test]
+    fn edit_assembly_tree() {
+        let mut document = Document::new();
+
+        let part = document.add_part("box", &Shape::box_with_dimensions(1.0, 2.0, 3.0));
+        document.set_color(&part, Color::rgb(1.0, 0.0, 0.0));
+
+        let assembly = document.add_assembly("assembly");
+        let placement =
+            DAffine3::from_rotation_translation(DQuat::from_rotation_z(0.5), dvec3(1.0, 2.0, 3.0));
+        let component = document.add_component(&assembly, &part, placement).unwrap();
+
+        assert_eq!(document.kind(&assembly), LabelKind::Assembly);
+        assert_eq!(document.kind(&component), LabelKind::Component);
+        assert!(document.roots() == vec![assembly]);
+        assert!(document.referred(&component) == Some(part));
+        assert!(document.placement(&component).abs_diff_eq(placement, 1.0e-9));
+
+        let scaled = DAffine3::from_scale(DVec3::splat(2.0));
+        assert!(document.set_placement(&component, scaled).is_err());
+    }
+
+    #[test]
+    fn step_round_trip() {
+        let red = Color::rgb(1.0, 0.0, 0.0);
+        let placement =
+            DAffine3::from_rotation_translation(DQuat::from_rotation_z(0.5), dvec3(1.0, 2.0, 3.0));
+
+        let mut document = Document::new();
+        let part = document.add_part("box", &Shape::box_with_dimensions(1.0, 2.0, 3.0));
+        document.set_color(&part, red);
+        document.add_to_layer(&part, "parts");
+        let assembly = document.add_assembly("assembly");
+        document.add_component(&assembly, &part, placement).unwrap();
+
+        let path =
+            std::env::temp_dir().join(format!("opencascade-document-{}.step", std::process::id()));
+        document.write_step(&path).unwrap();
+        let read = Document::read_step(&path);
+        let _ = std::fs::remove_file(&path);
+        let document = read.unwrap();
+
+        let roots = document.roots();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(document.name(&roots[0]).as_deref(), Some("assembly"));
+
+        let components = document.children(&roots[0]);
+        assert_eq!(components.len(), 1);
+        assert!(document.placement(&components[0]).abs_diff_eq(placement, 1.0e-6));
+
+        let part = document.referred(&components[0]).unwrap();
+        assert_eq!(document.name(&part).as_deref(), Some("box"));
+        assert_eq!(document.layers(&part), vec!["parts".to_string()]);
+
+        let color = document.color(&part).unwrap();
+        assert!(dvec3(color.r, color.g, color.b).abs_diff_eq(dvec3(red.r, red.g, red.b), 1.0e-3));
+    }
+}
@@ -0,0 +1,836 @@
+use crate::{
+    curve2d::{self, format_number, Curve2d},
+    mesh_export::xml_escape,
+    primitives::{CircleGeometry, Edge, Shape},
+    projection::view_axes,
+    svg::{ellipse_beziers, path_data},
+    workplane::Workplane,
+    Error,
+};
+use glam::{dvec2, dvec3, DAffine2, DVec2, DVec3};
+use std::{
+    f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU},
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+const BORDER: f64 = 10.0;
+const TITLE_BLOCK_WIDTH: f64 = 120.0;
+const TITLE_BLOCK_ROW: f64 = 7.0;
+const TITLE_BLOCK_ROWS: usize = 4;
+const TEXT_SIZE: f64 = 3.5;
+const LABEL_SIZE: f64 = 2.0;
+const ARROW_LENGTH: f64 = 3.0;
+const ARROW_WIDTH: f64 = 1.0;
+const EXTENSION_GAP: f64 = 1.0;
+const EXTENSION_OVERSHOOT: f64 = 2.0;
+const LEADER_LENGTH: f64 = 6.0;
+
+/// Paper sizes, in landscape orientation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SheetSize {
+    A4,
+    A3,
+    Letter,
+    /// A custom size, in millimeters.
+    Custom {
+        width: f64,
+        height: f64,
+    },
+}
+
+impl SheetSize {
+    /// The width and height of the sheet, in millimeters.
+    pub fn dimensions(&self) -> DVec2 {
+        match self {
+            Self::A4 => dvec2(297.0, 210.0),
+            Self::A3 => dvec2(420.0, 297.0),
+            Self::Letter => dvec2(279.4, 215.9),
+            Self::Custom { width, height } => dvec2(*width, *height),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StandardView {
+    Front,
+    Back,
+    Top,
+    Bottom,
+    Left,
+    Right,
+    /// Looking down at the front, right and top of the shape.
+    Isometric,
+}
+
+impl StandardView {
+    /// The direction the view looks along, and the direction which points up
+    /// in it. The front view looks along the Y axis, with Z pointing up.
+    pub fn directions(&self) -> (DVec3, DVec3) {
+        match self {
+            Self::Front => (DVec3::Y, DVec3::Z),
+            Self::Back => (DVec3::NEG_Y, DVec3::Z),
+            Self::Top => (DVec3::NEG_Z, DVec3::Y),
+            Self::Bottom => (DVec3::Z, DVec3::NEG_Y),
+            Self::Left => (DVec3::X, DVec3::Z),
+            Self::Right => (DVec3::NEG_X, DVec3::Z),
+            Self::Isometric => (dvec3(-1.0, 1.0, -1.0), DVec3::Z),
+        }
+    }
+}
+
+/// The part metadata shown in the title block, in the lower right corner of
+/// the sheet. The scale is filled in from the drawing.
+#[derive(Debug, Clone, Default)]
+pub struct TitleBlock {
+    pub title: String,
+    pub part_number: String,
+    pub material: String,
+    pub author: String,
+    pub date: String,
+    pub revision: String,
+}
+
+#[derive(Debug, Clone)]
+enum Dimension {
+    Linear { view: StandardView, start: DVec3, end: DVec3, offset: f64 },
+    Radial { view: StandardView, circle: Option<CircleGeometry> },
+    Diameter { view: StandardView, circle: Option<CircleGeometry> },
+}
+
+/// A technical drawing of a shape, with projected views, dimensions and a title
+/// block, which can be written to SVG and PDF files.
+///
+/// Positions on the sheet are in millimeters, from its lower left corner.
+pub struct Drawing<'a> {
+    shape: &'a Shape,
+    sheet: SheetSize,
+    scale: f64,
+    hidden_lines: bool,
+    views: Vec<(StandardView, DVec2)>,
+    dimensions: Vec<Dimension>,
+    title_block: Option<TitleBlock>,
+}
+
+impl<'a> Drawing<'a> {
+    pub fn new(shape: &'a Shape) -> Self {
+        Self {
+            shape,
+            sheet: SheetSize::A4,
+            scale: 1.0,
+            hidden_lines: true,
+            views: vec![],
+            dimensions: vec![],
+            title_block: None,
+        }
+    }
+
+    pub fn sheet(mut self, sheet: SheetSize) -> Self {
+        self.sheet = sheet;
+        self
+    }
+
+    /// Millimeters on the sheet per model unit, such as 0.5 for a 1:2 drawing.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Whether to draw hidden edges with dashed lines, which is the default.
+    pub fn hidden_lines(mut self, hidden_lines: bool) -> Self {
+        self.hidden_lines = hidden_lines;
+        self
+    }
+
+    /// Place a view with its center at `center` on the sheet.
+    pub fn view(mut self, view: StandardView, center: DVec2) -> Self {
+        self.views.push((view, center));
+        self
+    }
+
+    /// Place the front, top, right and isometric views in the four quarters of
+    /// the sheet above the title block, with the top view above the front view
+    /// and the right view to its right.
+    pub fn standard_views(self) -> Self {
+        let size = self.sheet.dimensions();
+        let min = dvec2(BORDER, BORDER + TITLE_BLOCK_ROW * TITLE_BLOCK_ROWS as f64);
+        let cell = (size - DVec2::splat(BORDER) - min) / 2.0;
+        let center = |column: f64, row: f64| min + cell * dvec2(column + 0.5, row + 0.5);
+
+        self.view(StandardView::Front, center(0.0, 0.0))
+            .view(StandardView::Top, center(0.0, 1.0))
+            .view(StandardView::Right, center(1.0, 0.0))
+            .view(StandardView::Isometric, center(1.0, 1.0))
+    }
+
+    /// Dimension the distance between the ends of `edge`, as seen in `view`.
+    /// The dimension line is drawn `offset` millimeters to the left of the
+    /// edge, looking from its start to its end.
+    pub fn linear_dimension(mut self, view: StandardView, edge: &Edge, offset: f64) -> Self {
+        self.dimensions.push(Dimension::Linear {
+            view,
+            start: edge.start_point(),
+            end: edge.end_point(),
+            offset,
+        });
+        self
+    }
+
+    /// Dimension the radius of a circular `edge`.
+    pub fn radial_dimension(mut self, view: StandardView, edge: &Edge) -> Self {
+        self.dimensions.push(Dimension::Radial { view, circle: edge.circle_geometry() });
+        self
+    }
+
+    /// Dimension the diameter of a circular `edge`.
+    pub fn diameter_dimension(mut self, view: StandardView, edge: &Edge) -> Self {
+        self.dimensions.push(Dimension::Diameter { view, circle: edge.circle_geometry() });
+        self
+    }
+
+    pub fn title_block(mut self, title_block: TitleBlock) -> Self {
+        self.title_block = Some(title_block);
+        self
+    }
+
+    pub fn write_svg(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_svg_to(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_svg_to(&self, mut writer: impl Write) -> Result<(), Error> {
+        let elements = self.elements()?;
+        let size = self.sheet.dimensions();
+        let point = |p: DVec2| format!("{} {}", format_number(p.x), format_number(-p.y));
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">"#,
+            w = format_number(size.x),
+            h = format_number(size.y),
+        )?;
+        // Sheet coordinates have their Y axis pointing up, from the lower left corner.
+        writeln!(
+            writer,
+            r#"<g transform="translate(0 {})" fill="none" stroke="black" stroke-linecap="round" stroke-linejoin="round">"#,
+            format_number(size.y)
+        )?;
+
+        for element in &elements {
+            match element {
+                Element::Curve { curve, stroke } => {
+                    let dashes = match stroke.dashes() {
+                        Some(dashes) => format!(r#" stroke-dasharray="{dashes}""#),
+                        None => String::new(),
+                    };
+
+                    writeln!(
+                        writer,
+                        r#"<path d="{}" stroke-width="{}"{dashes}/>"#,
+                        path_data(curve),
+                        format_number(stroke.width()),
+                    )?;
+                },
+                Element::Arrow { points } => {
+                    writeln!(
+                        writer,
+                        r#"<path d="M {} L {} L {} Z" fill="black" stroke="none"/>"#,
+                        point(points[0]),
+                        point(points[1]),
+                        point(points[2]),
+                    )?;
+                },
+                Element::Text { position, angle, size, anchor, text } => {
+                    let (x, y) = (format_number(position.x), format_number(-position.y));
+                    let anchor = match anchor {
+                        Anchor::Start => "start",
+                        Anchor::Middle => "middle",
+                    };
+
+                    writeln!(
+                        writer,
+                        r#"<text x="{x}" y="{y}" font-family="sans-serif" font-size="{}" text-anchor="{anchor}" transform="rotate({} {x} {y})" fill="black" stroke="none">{}</text>"#,
+                        format_number(*size),
+                        format_number(-angle.to_degrees()),
+                        xml_escape(text),
+                    )?;
+                },
+            }
+        }
+
+        writeln!(writer, "</g>")?;
+        writeln!(writer, "</svg>")?;
+
+        Ok(())
+    }
+
+    pub fn write_pdf(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_pdf_to(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Write a single page PDF, with the text set in the standard Helvetica font.
+    pub fn write_pdf_to(&self, mut writer: impl Write) -> Result<(), Error> {
+        const POINTS_PER_MM: f64 = 72.0 / 25.4;
+
+        let elements = self.elements()?;
+        let size = self.sheet.dimensions();
+        let point = |p: DVec2| format!("{} {}", format_number(p.x), format_number(p.y));
+
+        // Draw in millimeters, with round line caps and joins.
+        let mut content = format!("{} 0 0 {0} 0 0 cm 1 J 1 j\n", format_number(POINTS_PER_MM));
+        for element in &elements {
+            match element {
+                Element::Curve { curve, stroke } => {
+                    writeln!(
+                        content,
+                        "{} w [{}] 0 d {} S",
+                        format_number(stroke.width()),
+                        stroke.dashes().unwrap_or_default(),
+                        pdf_path(curve),
+                    )
+                    .unwrap();
+                },
+                Element::Arrow { points } => {
+                    writeln!(
+                        content,
+                        "{} m {} l {} l h f",
+                        point(points[0]),
+                        point(points[1]),
+                        point(points[2])
+                    )
+                    .unwrap();
+                },
+                Element::Text { position, angle, size, anchor, text } => {
+                    let direction = DVec2::from_angle(*angle);
+                    let start = match anchor {
+                        Anchor::Start => *position,
+                        // Helvetica characters are about half as wide as they are tall.
+                        Anchor::Middle => {
+                            *position - direction * text.chars().count() as f64 * *size * 0.25
+                        },
+                    };
+
+                    writeln!(
+                        content,
+                        "BT /F1 {} Tf {} {} {} {} {} Tm ({}) Tj ET",
+                        format_number(*size),
+                        format_number(direction.x),
+                        format_number(direction.y),
+                        format_number(-direction.y),
+                        format_number(direction.x),
+                        point(start),
+                        pdf_string(text),
+                    )
+                    .unwrap();
+                },
+            }
+        }
+
+        let media_box = size * POINTS_PER_MM;
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 4 0 R >> >> /Contents 5 0 R >>",
+                format_number(media_box.x),
+                format_number(media_box.y),
+            ),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_string(),
+            format!("<< /Length {} >>\nstream\n{content}endstream", content.chars().count()),
+        ];
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = vec![];
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n", index + 1).bytes());
+            pdf.extend(latin1(object));
+            pdf.extend(b"\nendobj\n");
+        }
+
+        let xref = pdf.len();
+        pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).bytes());
+        for offset in offsets {
+            pdf.extend(format!("{offset:010} 00000 n \n").bytes());
+        }
+        pdf.extend(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .bytes(),
+        );
+
+        writer.write_all(&pdf)?;
+
+        Ok(())
+    }
+
+    /// Everything on the sheet, in sheet coordinates.
+    fn elements(&self) -> Result<Vec<Element>, Error> {
+        let size = self.sheet.dimensions();
+        let mut elements = vec![Element::Curve {
+            curve: rectangle(DVec2::splat(BORDER), size - DVec2::splat(BORDER)),
+            stroke: Stroke::Visible,
+        }];
+
+        let plane = Workplane::xy();
+        let mut views = vec![];
+        for &(view, center) in &self.views {
+            let (view_direction, up) = view.directions();
+            let projection = self.shape.project_hlr(view_direction, up)?;
+
+            let visible = Curve2d::from_edges(projection.visible.iter(), &plane);
+            let hidden = if self.hidden_lines {
+                Curve2d::from_edges(projection.hidden.iter(), &plane)
+            } else {
+                vec![]
+            };
+
+            // Center the visible edges of the view on `center`.
+            let (min, max) = curve2d::bounds(&visible).unwrap_or((DVec2::ZERO, DVec2::ZERO));
+            let transform = DAffine2::from_translation(center)
+                * DAffine2::from_scale(DVec2::splat(self.scale))
+                * DAffine2::from_translation(-(min + max) / 2.0);
+
+            for (curves, stroke) in [(hidden, Stroke::Hidden), (visible, Stroke::Visible)] {
+                elements.extend(
+                    curves.iter().map(|curve| Element::Curve {
+                        curve: curve.transformed(transform),
+                        stroke,
+                    }),
+                );
+            }
+
+            views.push((view, transform, view_axes(view_direction, up)));
+        }
+
+        for dimension in &self.dimensions {
+            let (Dimension::Linear { view, .. }
+            | Dimension::Radial { view, .. }
+            | Dimension::Diameter { view, .. }) = dimension;
+
+            let (_, transform, (x_dir, y_dir)) =
+                views.iter().find(|(placed, ..)| placed == view).ok_or_else(|| {
+                    Error::InvalidDimension(format!("the {view:?} view isn't on the drawing"))
+                })?;
+            let project = |point: DVec3| dvec2(point.dot(*x_dir), point.dot(*y_dir));
+            let to_sheet = |point: DVec3| transform.transform_point2(project(point));
+            let (view_direction, _) = view.directions();
+            let facing_circle = |circle: &Option<CircleGeometry>| {
+                let circle = circle.ok_or_else(|| {
+                    Error::InvalidDimension("radii and diameters need a circular edge".to_string())
+                })?;
+
+                // Circles seen at an angle are ellipses, which have no single radius.
+                if circle.normal.normalize().cross(view_direction.normalize()).length() > 1.0e-6 {
+                    return Err(Error::InvalidDimension(format!(
+                        "the circle doesn't face the {view:?} view"
+                    )));
+                }
+
+                Ok(circle)
+            };
+
+            match dimension {
+                Dimension::Linear { start, end, offset, .. } => linear_dimension(
+                    &mut elements,
+                    to_sheet(*start),
+                    to_sheet(*end),
+                    *offset,
+                    project(*end).distance(project(*start)),
+                ),
+                Dimension::Radial { circle, .. } => {
+                    let circle = facing_circle(circle)?;
+                    let center = to_sheet(circle.center);
+                    let direction = DVec2::from_angle(FRAC_PI_4);
+                    let on_circle = center + direction * circle.radius * self.scale;
+
+                    elements.push(Element::arrow(on_circle, direction));
+                    leader(
+                        &mut elements,
+                        center,
+                        on_circle + direction * LEADER_LENGTH,
+                        format!("R{}", format_value(circle.radius)),
+                    );
+                },
+                Dimension::Diameter { circle, .. } => {
+                    let circle = facing_circle(circle)?;
+                    let center = to_sheet(circle.center);
+                    let direction = DVec2::from_angle(FRAC_PI_4);
+                    let radius = circle.radius * self.scale;
+
+                    elements.push(Element::arrow(center - direction * radius, -direction));
+                    elements.push(Element::arrow(center + direction * radius, direction));
+                    leader(
+                        &mut elements,
+                        center - direction * radius,
+                        center + direction * (radius + LEADER_LENGTH),
+                        format!("\u{2300}{}", format_value(circle.radius * 2.0)),
+                    );
+                },
+            }
+        }
+
+        if let Some(title_block) = &self.title_block {
+            self.title_block_elements(&mut elements, title_block);
+        }
+
+        Ok(elements)
+    }
+
+    fn title_block_elements(&self, elements: &mut Vec<Element>, title_block: &TitleBlock) {
+        let size = self.sheet.dimensions();
+        let min = dvec2(size.x - BORDER - TITLE_BLOCK_WIDTH, BORDER);
+        let max = min + dvec2(TITLE_BLOCK_WIDTH, TITLE_BLOCK_ROW * TITLE_BLOCK_ROWS as f64);
+        let middle = min.x + TITLE_BLOCK_WIDTH / 2.0;
+
+        elements.push(Element::Curve { curve: rectangle(min, max), stroke: Stroke::Visible });
+
+        let scale = scale_label(self.scale);
+        let rows: [&[(&str, &str)]; TITLE_BLOCK_ROWS] = [
+            &[("Title", &title_block.title)],
+            &[("Part number", &title_block.part_number), ("Material", &title_block.material)],
+            &[("Drawn by", &title_block.author), ("Date", &title_block.date)],
+            &[("Scale", &scale), ("Revision", &title_block.revision)],
+        ];
+
+        for (row, cells) in rows.iter().enumerate() {
+            let top = max.y - TITLE_BLOCK_ROW * row as f64;
+            let bottom = top - TITLE_BLOCK_ROW;
+
+            if row > 0 {
+                elements.push(Element::line(dvec2(min.x, top), dvec2(max.x, top), Stroke::Thin));
+            }
+
+            if cells.len() > 1 {
+                elements.push(Element::line(
+                    dvec2(middle, top),
+                    dvec2(middle, bottom),
+                    Stroke::Thin,
+                ));
+            }
+
+            for (column, (label, value)) in cells.iter().enumerate() {
+                let left = min.x + TITLE_BLOCK_WIDTH / 2.0 * column as f64 + 1.0;
+
+                elements.push(Element::text(
+                    dvec2(left, top - LABEL_SIZE - 0.5),
+                    LABEL_SIZE,
+                    label,
+                ));
+                elements.push(Element::text(dvec2(left, bottom + 1.0), TEXT_SIZE, value));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Stroke {
+    Visible,
+    Hidden,
+    Thin,
+}
+
+impl Stroke {
+    fn width(&self) -> f64 {
+        match self {
+            Self::Visible => 0.5,
+            Self::Hidden => 0.25,
+            Self::Thin => 0.18,
+        }
+    }
+
+    fn dashes(&self) -> Option<&'static str> {
+        match self {
+            Self::Hidden => Some("2 1"),
+            Self::Visible | Self::Thin => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Anchor {
+    Start,
+    Middle,
+}
+
+#[derive(Debug, Clone)]
+enum Element {
+    Curve {
+        curve: Curve2d,
+        stroke: Stroke,
+    },
+    /// A filled arrow head, with its tip first.
+    Arrow {
+        points: [DVec2; 3],
+    },
+    /// Text with its baseline at `angle` radians.
+    Text {
+        position: DVec2,
+        angle: f64,
+        size: f64,
+        anchor: Anchor,
+        text: String,
+    },
+}
+
+impl Element {
+    fn line(start: DVec2, end: DVec2, stroke: Stroke) -> Self {
+        Self::Curve { curve: Curve2d::Line { start, end }, stroke }
+    }
+
+    fn arrow(tip: DVec2, direction: DVec2) -> Self {
+        let base = tip - direction * ARROW_LENGTH;
+        let side = direction.perp() * ARROW_WIDTH / 2.0;
+
+        Self::Arrow { points: [tip, base + side, base - side] }
+    }
+
+    fn text(position: DVec2, size: f64, text: &str) -> Self {
+        Self::Text { position, angle: 0.0, size, anchor: Anchor::Start, text: text.to_string() }
+    }
+}
+
+/// A dimension line parallel to the line from `start` to `end`, with extension
+/// lines back to its ends.
+fn linear_dimension(
+    elements: &mut Vec<Element>,
+    start: DVec2,
+    end: DVec2,
+    offset: f64,
+    value: f64,
+) {
+    let Some(direction) = (end - start).try_normalize() else {
+        return;
+    };
+    let normal = direction.perp() * offset.signum();
+    let (dimension_start, dimension_end) =
+        (start + normal * offset.abs(), end + normal * offset.abs());
+
+    for (point, dimension_point) in [(start, dimension_start), (end, dimension_end)] {
+        elements.push(Element::line(
+            point + normal * EXTENSION_GAP,
+            dimension_point + normal * EXTENSION_OVERSHOOT,
+            Stroke::Thin,
+        ));
+    }
+
+    elements.push(Element::line(dimension_start, dimension_end, Stroke::Thin));
+    elements.push(Element::arrow(dimension_start, -direction));
+    elements.push(Element::arrow(dimension_end, direction));
+
+    // Keep the text readable from below or from the right, on the outside of
+    // the dimension line.
+    let mut angle = direction.y.atan2(direction.x);
+    if angle > FRAC_PI_2 + 1.0e-9 || angle <= -FRAC_PI_2 + 1.0e-9 {
+        angle = (angle + PI).rem_euclid(TAU);
+    }
+    let text_up = DVec2::from_angle(angle).perp();
+    let distance = if text_up.dot(normal) > 0.0 { 1.0 } else { 1.0 + TEXT_SIZE };
+
+    elements.push(Element::Text {
+        position: (dimension_start + dimension_end) / 2.0 + normal * distance,
+        angle,
+        size: TEXT_SIZE,
+        anchor: Anchor::Middle,
+        text: format_value(value),
+    });
+}
+
+/// A line from `start` to `end`, with a short horizontal shoulder and `text`
+/// after it.
+fn leader(elements: &mut Vec<Element>, start: DVec2, end: DVec2, text: String) {
+    let side = if end.x >= start.x { 1.0 } else { -1.0 };
+    let shoulder = end + dvec2(side * 2.0, 0.0);
+
+    elements.push(Element::line(start, end, Stroke::Thin));
+    elements.push(Element::line(end, shoulder, Stroke::Thin));
+
+    let position = if side > 0.0 {
+        shoulder + dvec2(1.0, -TEXT_SIZE / 3.0)
+    } else {
+        shoulder - dvec2(1.0 + text.chars().count() as f64 * TEXT_SIZE * 0.5, TEXT_SIZE / 3.0)
+    };
+    elements.push(Element::text(position, TEXT_SIZE, &text));
+}
+
+fn rectangle(min: DVec2, max: DVec2) -> Curve2d {
+    Curve2d::Polyline {
+        points: vec![min, dvec2(max.x, min.y), max, dvec2(min.x, max.y)],
+        closed: true,
+    }
+}
+
+/// A measurement, with at most two decimals.
+fn format_value(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn scale_label(scale: f64) -> String {
+    if scale >= 1.0 {
+        format!("{}:1", format_value(scale))
+    } else {
+        format!("1:{}", format_value(1.0 / scale))
+    }
+}
+
+/// PDF path construction operators for a curve, with arcs and splines turned
+/// into cubic Bezier curves where possible.
+fn pdf_path(curve: &Curve2d) -> String {
+    let point = |p: DVec2| format!("{} {}", format_number(p.x), format_number(p.y));
+    let mut ops = format!("{} m", point(curve.start_point()));
+
+    let cubics = match curve {
+        Curve2d::Arc { center, radius, start_angle, end_angle } => Some(ellipse_beziers(
+            *center,
+            DVec2::splat(*radius),
+            0.0,
+            *start_angle,
+            end_angle - start_angle,
+        )),
+        Curve2d::Circle { center, radius } => {
+            Some(ellipse_beziers(*center, DVec2::splat(*radius), 0.0, 0.0, TAU))
+        },
+        Curve2d::Spline { .. } if curve.is_polynomial_up_to_cubic() => Some(vec![curve.clone()]),
+        _ => None,
+    };
+
+    match cubics {
+        Some(splines) => {
+            for segment in splines.iter().flat_map(Curve2d::bezier_segments) {
+                let points: Vec<DVec2> =
+                    segment.iter().map(|point| point.truncate() / point.z).collect();
+
+                match points.as_slice() {
+                    [_, end] => write!(ops, " {} l", point(*end)).unwrap(),
+                    // Quadratic curves are elevated to cubic ones.
+                    [start, control, end] => write!(
+                        ops,
+                        " {} {} {} c",
+                        point(*start + (*control - *start) * 2.0 / 3.0),
+                        point(*end + (*control - *end) * 2.0 / 3.0),
+                        point(*end)
+                    )
+                    .unwrap(),
+                    [_, first, second, end] => {
+                        write!(ops, " {} {} {} c", point(*first), point(*second), point(*end))
+                            .unwrap()
+                    },
+                    _ => unreachable!("splines are at most cubic"),
+                }
+            }
+        },
+        None => {
+            for p in &curve.sample(TAU / 64.0)[1..] {
+                write!(ops, " {} l", point(*p)).unwrap();
+            }
+        },
+    }
+
+    if matches!(curve, Curve2d::Circle { .. } | Curve2d::Polyline { closed: true, .. }) {
+        ops.push_str(" h");
+    }
+
+    ops
+}
+
+/// A PDF string literal body, in the WinAnsi encoding of the standard fonts.
+fn pdf_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            // The diameter sign isn't in WinAnsi, so use the closest character.
+            '\u{2300}' => escaped.push('\u{d8}'),
+            c if (c as u32) < 256 => escaped.push(c),
+            _ => escaped.push('?'),
+        }
+    }
+
+    escaped
+}
+
+/// Encode text with one byte per character, which must all be below 256.
+fn latin1(text: &str) -> Vec<u8> {
+    text.chars().map(|c| c as u32 as u8).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_labels() {
+        assert_eq!(scale_label(1.0), "1:1");
+        assert_eq!(scale_label(0.5), "1:2");
+        assert_eq!(scale_label(2.5), "2.5:1");
+    }
+
+    #[test]
+    fn box_drawing() {
+        let shape = Shape::box_with_dimensions(40.0, 20.0, 10.0);
+        let long_edge = shape
+            .edges()
+            .find(|edge| {
+                (edge.end_point() - edge.start_point())
+                    .abs()
+                    .abs_diff_eq(dvec3(40.0, 0.0, 0.0), 1.0e-9)
+            })
+            .unwrap();
+
+        let drawing = Drawing::new(&shape)
+            .standard_views()
+            .linear_dimension(StandardView::Front, &long_edge, -10.0)
+            .title_block(TitleBlock { title: "Spacer".to_string(), ..Default::default() });
+
+        let mut svg = vec![];
+        drawing.write_svg_to(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(">40</text>"));
+        assert!(svg.contains(">Spacer</text>"));
+        assert!(svg.contains(">1:1</text>"));
+
+        let mut pdf = vec![];
+        drawing.write_pdf_to(&mut pdf).unwrap();
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+
+        let missing_view = Drawing::new(&shape).radial_dimension(StandardView::Top, &long_edge);
+        assert!(missing_view.write_svg_to(std::io::sink()).is_err());
+    }
+
+    #[test]
+    fn circles_must_face_the_view() {
+        let shape = Shape::cylinder_radius_height(5.0, 10.0);
+        let top_circle = shape
+            .edges()
+            .find(|edge| edge.circle_geometry().is_some_and(|circle| circle.center.z > 5.0))
+            .unwrap();
+
+        let top = Drawing::new(&shape)
+            .standard_views()
+            .diameter_dimension(StandardView::Top, &top_circle);
+        assert!(top.write_svg_to(std::io::sink()).is_ok());
+
+        let front = Drawing::new(&shape)
+            .standard_views()
+            .radial_dimension(StandardView::Front, &top_circle);
+        assert!(matches!(front.elements(), Err(Error::InvalidDimension(_))));
+    }
+}
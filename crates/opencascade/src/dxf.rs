@@ -0,0 +1,643 @@
+use crate::{
+    curve2d::{angle_of, format_number, Curve2d},
+    primitives::Edge,
+    workplane::Workplane,
+    Error,
+};
+use glam::{dvec2, DAffine2, DVec2};
+use std::{
+    f64::consts::TAU,
+    io::{Read, Write},
+    iter::once,
+};
+
+/// Writes planar curves to an AutoCAD R2000 DXF file, in millimeters.
+///
+/// Lines, arcs, circles, splines and polylines are written as `LINE`, `ARC`,
+/// `CIRCLE`, `SPLINE` and `LWPOLYLINE` entities.
+#[derive(Debug, Clone, Default)]
+pub struct DxfWriter {
+    layers: Vec<String>,
+    entities: Vec<(usize, Curve2d)>,
+}
+
+impl DxfWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add curves to a layer, which is created if it doesn't exist yet.
+    pub fn curves(mut self, layer: &str, curves: impl IntoIterator<Item = Curve2d>) -> Self {
+        let layer = match self.layers.iter().position(|name| name == layer) {
+            Some(index) => index,
+            None => {
+                self.layers.push(layer.to_string());
+                self.layers.len() - 1
+            },
+        };
+
+        self.entities.extend(curves.into_iter().map(|curve| (layer, curve)));
+        self
+    }
+
+    /// Add edges to a layer, projected onto `plane`.
+    pub fn edges<T: AsRef<Edge>>(
+        self,
+        layer: &str,
+        edges: impl IntoIterator<Item = T>,
+        plane: &Workplane,
+    ) -> Self {
+        self.curves(layer, Curve2d::from_edges(edges, plane))
+    }
+
+    pub fn write(&self, mut writer: impl Write) -> Result<(), Error> {
+        // The header holds the next free handle, so everything else is written first.
+        let mut body = DxfOutput { writer: vec![], next_handle: 1 };
+        let [model_space, paper_space] = body.tables(&self.layers)?;
+        body.blocks(&model_space, &paper_space)?;
+
+        body.pair(0, "SECTION")?;
+        body.pair(2, "ENTITIES")?;
+        for (layer, curve) in &self.entities {
+            body.entity(&model_space, &self.layers[*layer], curve)?;
+        }
+        body.pair(0, "ENDSEC")?;
+
+        body.objects()?;
+        body.pair(0, "EOF")?;
+
+        let mut out = DxfOutput { writer: &mut writer, next_handle: body.next_handle };
+
+        out.pair(0, "SECTION")?;
+        out.pair(2, "HEADER")?;
+        out.pair(9, "$ACADVER")?;
+        out.pair(1, "AC1015")?;
+        out.pair(9, "$HANDSEED")?;
+        out.pair(5, format!("{:X}", out.next_handle))?;
+        // Millimeters
+        out.pair(9, "$INSUNITS")?;
+        out.pair(70, 4)?;
+        out.pair(0, "ENDSEC")?;
+
+        out.pair(0, "SECTION")?;
+        out.pair(2, "CLASSES")?;
+        out.pair(0, "ENDSEC")?;
+
+        out.writer.write_all(&body.writer)?;
+
+        Ok(())
+    }
+}
+
+struct DxfOutput<W> {
+    writer: W,
+    next_handle: u32,
+}
+
+impl<W: Write> DxfOutput<W> {
+    fn pair(&mut self, code: u16, value: impl std::fmt::Display) -> Result<(), Error> {
+        writeln!(self.writer, "{code:>3}\n{value}")?;
+        Ok(())
+    }
+
+    fn new_handle(&mut self) -> String {
+        let handle = format!("{:X}", self.next_handle);
+        self.next_handle += 1;
+        handle
+    }
+
+    /// Start an object with the given handle, owned by the object with handle `owner`.
+    fn start_with_handle(&mut self, kind: &str, handle: &str, owner: &str) -> Result<(), Error> {
+        self.pair(0, kind)?;
+        // Dimension styles are the one place where handles have another group code.
+        self.pair(if kind == "DIMSTYLE" { 105 } else { 5 }, handle)?;
+        self.pair(330, owner)
+    }
+
+    /// Start an object with a new handle, and return the handle.
+    fn start(&mut self, kind: &str, owner: &str) -> Result<String, Error> {
+        let handle = self.new_handle();
+        self.start_with_handle(kind, &handle, owner)?;
+
+        Ok(handle)
+    }
+
+    /// Write a symbol table with an entry for each name, and return the handles
+    /// of the entries.
+    fn table(
+        &mut self,
+        table: &str,
+        record_class: &str,
+        names: &[&str],
+        mut entry: impl FnMut(&mut Self, &str) -> Result<(), Error>,
+    ) -> Result<Vec<String>, Error> {
+        let handle = self.new_handle();
+
+        self.pair(0, "TABLE")?;
+        self.pair(2, table)?;
+        self.pair(5, &handle)?;
+        self.pair(330, 0)?;
+        self.pair(100, "AcDbSymbolTable")?;
+        self.pair(70, names.len())?;
+        if table == "DIMSTYLE" {
+            self.pair(100, "AcDbDimStyleTable")?;
+        }
+
+        let mut handles = vec![];
+        for name in names {
+            handles.push(self.start(table, &handle)?);
+            self.pair(100, "AcDbSymbolTableRecord")?;
+            self.pair(100, record_class)?;
+            self.pair(2, name)?;
+            self.pair(70, 0)?;
+            entry(self, name)?;
+        }
+
+        self.pair(0, "ENDTAB")?;
+
+        Ok(handles)
+    }
+
+    /// Write every table a drawing needs, with their default entries, and return
+    /// the handles of the model and paper space block records.
+    fn tables(&mut self, layers: &[String]) -> Result<[String; 2], Error> {
+        let no_fields = |_: &mut Self, _: &str| Ok(());
+
+        self.pair(0, "SECTION")?;
+        self.pair(2, "TABLES")?;
+
+        self.table("VPORT", "AcDbViewportTableRecord", &[], no_fields)?;
+        self.table(
+            "LTYPE",
+            "AcDbLinetypeTableRecord",
+            &["ByBlock", "ByLayer", "Continuous"],
+            |out, name| {
+                out.pair(3, if name == "Continuous" { "Solid line" } else { "" })?;
+                out.pair(72, 65)?;
+                out.pair(73, 0)?;
+                out.number(40, 0.0)
+            },
+        )?;
+
+        let layers = once("0")
+            .chain(layers.iter().map(String::as_str).filter(|&layer| layer != "0"))
+            .collect::<Vec<_>>();
+        self.table("LAYER", "AcDbLayerTableRecord", &layers, |out, _| {
+            out.pair(62, 7)?;
+            out.pair(6, "Continuous")
+        })?;
+
+        self.table("STYLE", "AcDbTextStyleTableRecord", &["Standard"], |out, _| {
+            out.number(40, 0.0)?;
+            out.number(41, 1.0)?;
+            out.number(50, 0.0)?;
+            out.pair(71, 0)?;
+            out.number(42, 2.5)?;
+            out.pair(3, "txt")?;
+            out.pair(4, "")
+        })?;
+        self.table("VIEW", "AcDbViewTableRecord", &[], no_fields)?;
+        self.table("UCS", "AcDbUCSTableRecord", &[], no_fields)?;
+        self.table("APPID", "AcDbRegAppTableRecord", &["ACAD"], no_fields)?;
+        self.table("DIMSTYLE", "AcDbDimStyleTableRecord", &["Standard"], no_fields)?;
+        let block_records = self.table(
+            "BLOCK_RECORD",
+            "AcDbBlockTableRecord",
+            &["*Model_Space", "*Paper_Space"],
+            no_fields,
+        )?;
+
+        self.pair(0, "ENDSEC")?;
+
+        Ok(block_records.try_into().expect("both block records are written"))
+    }
+
+    /// Write the model and paper space blocks. Entities are written to the
+    /// entities section instead of into these.
+    fn blocks(&mut self, model_space: &str, paper_space: &str) -> Result<(), Error> {
+        self.pair(0, "SECTION")?;
+        self.pair(2, "BLOCKS")?;
+
+        for (name, record) in [("*Model_Space", model_space), ("*Paper_Space", paper_space)] {
+            self.start("BLOCK", record)?;
+            self.pair(100, "AcDbEntity")?;
+            self.pair(8, 0)?;
+            self.pair(100, "AcDbBlockBegin")?;
+            self.pair(2, name)?;
+            self.pair(70, 0)?;
+            self.point(10, 0.0, 0.0)?;
+            self.pair(3, name)?;
+            self.pair(1, "")?;
+
+            self.start("ENDBLK", record)?;
+            self.pair(100, "AcDbEntity")?;
+            self.pair(8, 0)?;
+            self.pair(100, "AcDbBlockEnd")?;
+        }
+
+        self.pair(0, "ENDSEC")
+    }
+
+    /// Write the root dictionary, with the group dictionary every drawing has.
+    fn objects(&mut self) -> Result<(), Error> {
+        let (root, groups) = (self.new_handle(), self.new_handle());
+
+        self.pair(0, "SECTION")?;
+        self.pair(2, "OBJECTS")?;
+
+        self.start_with_handle("DICTIONARY", &root, "0")?;
+        self.pair(100, "AcDbDictionary")?;
+        self.pair(281, 1)?;
+        self.pair(3, "ACAD_GROUP")?;
+        self.pair(350, &groups)?;
+
+        self.start_with_handle("DICTIONARY", &groups, &root)?;
+        self.pair(100, "AcDbDictionary")?;
+        self.pair(281, 1)?;
+
+        self.pair(0, "ENDSEC")
+    }
+
+    fn number(&mut self, code: u16, value: f64) -> Result<(), Error> {
+        self.pair(code, format_number(value))
+    }
+
+    fn point(&mut self, code: u16, x: f64, y: f64) -> Result<(), Error> {
+        self.number(code, x)?;
+        self.number(code + 10, y)?;
+        self.number(code + 20, 0.0)
+    }
+
+    fn entity(&mut self, owner: &str, layer: &str, curve: &Curve2d) -> Result<(), Error> {
+        let kind = match curve {
+            Curve2d::Line { .. } => "LINE",
+            Curve2d::Arc { .. } => "ARC",
+            Curve2d::Circle { .. } => "CIRCLE",
+            Curve2d::Spline { .. } => "SPLINE",
+            Curve2d::Polyline { .. } => "LWPOLYLINE",
+        };
+
+        self.start(kind, owner)?;
+        self.pair(100, "AcDbEntity")?;
+        self.pair(8, layer)?;
+
+        match curve {
+            Curve2d::Line { start, end } => {
+                self.pair(100, "AcDbLine")?;
+                self.point(10, start.x, start.y)?;
+                self.point(11, end.x, end.y)?;
+            },
+            Curve2d::Arc { center, radius, start_angle, end_angle } => {
+                self.pair(100, "AcDbCircle")?;
+                self.point(10, center.x, center.y)?;
+                self.number(40, *radius)?;
+                self.pair(100, "AcDbArc")?;
+                self.number(50, start_angle.to_degrees())?;
+                self.number(51, end_angle.to_degrees().rem_euclid(360.0))?;
+            },
+            Curve2d::Circle { center, radius } => {
+                self.pair(100, "AcDbCircle")?;
+                self.point(10, center.x, center.y)?;
+                self.number(40, *radius)?;
+            },
+            Curve2d::Spline { degree, control_points, weights, knots } => {
+                let rational = weights.iter().any(|weight| (weight - weights[0]).abs() > 1.0e-12);
+
+                self.pair(100, "AcDbSpline")?;
+                self.pair(210, 0)?;
+                self.pair(220, 0)?;
+                self.pair(230, 1)?;
+                // Planar, and rational if the weights differ.
+                self.pair(70, if rational { 8 | 4 } else { 8 })?;
+                self.pair(71, degree)?;
+                self.pair(72, knots.len())?;
+                self.pair(73, control_points.len())?;
+                self.pair(74, 0)?;
+
+                for knot in knots {
+                    self.number(40, *knot)?;
+                }
+
+                if rational {
+                    for weight in weights {
+                        self.number(41, *weight)?;
+                    }
+                }
+
+                for point in control_points {
+                    self.point(10, point.x, point.y)?;
+                }
+            },
+            Curve2d::Polyline { points, closed } => {
+                self.pair(100, "AcDbPolyline")?;
+                self.pair(90, points.len())?;
+                self.pair(70, if *closed { 1 } else { 0 })?;
+
+                for point in points {
+                    self.number(10, point.x)?;
+                    self.number(20, point.y)?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// A curve read from a DXF file, with the name of its layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DxfEntity {
+    pub layer: String,
+    pub curve: Curve2d,
+}
+
+/// Read the `LINE`, `ARC`, `CIRCLE`, `LWPOLYLINE` and `SPLINE` entities of a DXF
+/// file. Other entities, and entities inside of blocks, are skipped.
+///
+/// Polylines with bulges are split into lines and arcs.
+pub fn read_dxf(mut reader: impl Read) -> Result<Vec<DxfEntity>, Error> {
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes)?;
+    let text = String::from_utf8_lossy(&bytes);
+
+    let lines: Vec<&str> = text.lines().collect();
+    let pairs = lines
+        .chunks_exact(2)
+        .map(|pair| {
+            let code = pair[0].trim();
+            code.parse::<i32>()
+                .map(|code| (code, pair[1].trim()))
+                .map_err(|_| Error::DxfReadFailed(format!("invalid group code `{code}`")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut entities = vec![];
+    let mut in_entities = false;
+    let mut start = 0;
+
+    // Every entity starts with a pair with group code 0, and ends at the next one.
+    for end in 1..=pairs.len() {
+        if end < pairs.len() && pairs[end].0 != 0 {
+            continue;
+        }
+
+        let entity = DxfInput { pairs: &pairs[start..end] };
+        start = end;
+
+        match entity.kind() {
+            "SECTION" => in_entities = entity.value(2) == Some("ENTITIES"),
+            "ENDSEC" => in_entities = false,
+            "LINE" | "ARC" | "CIRCLE" | "LWPOLYLINE" | "SPLINE" if in_entities => {
+                let layer = entity.value(8).unwrap_or("0").to_string();
+                entities.extend(
+                    entity
+                        .curves()?
+                        .into_iter()
+                        .map(|curve| DxfEntity { layer: layer.clone(), curve }),
+                );
+            },
+            _ => {},
+        }
+    }
+
+    Ok(entities)
+}
+
+struct DxfInput<'a> {
+    pairs: &'a [(i32, &'a str)],
+}
+
+impl DxfInput<'_> {
+    fn kind(&self) -> &str {
+        self.pairs[0].1
+    }
+
+    fn value(&self, code: i32) -> Option<&str> {
+        self.pairs.iter().find(|(c, _)| *c == code).map(|(_, value)| *value)
+    }
+
+    fn number(&self, code: i32) -> Result<f64, Error> {
+        let value = self.value(code).ok_or_else(|| {
+            Error::DxfReadFailed(format!("{} entity without group code {code}", self.kind()))
+        })?;
+
+        parse_number(value)
+    }
+
+    fn numbers(&self, code: i32) -> Result<Vec<f64>, Error> {
+        self.pairs
+            .iter()
+            .filter(|(c, _)| *c == code)
+            .map(|(_, value)| parse_number(value))
+            .collect()
+    }
+
+    fn point(&self, code: i32) -> Result<DVec2, Error> {
+        Ok(dvec2(self.number(code)?, self.number(code + 10)?))
+    }
+
+    fn curves(&self) -> Result<Vec<Curve2d>, Error> {
+        let curves = match self.kind() {
+            "LINE" => {
+                return Ok(vec![Curve2d::Line { start: self.point(10)?, end: self.point(11)? }])
+            },
+            "ARC" => {
+                let start_angle = self.number(50)?.to_radians().rem_euclid(TAU);
+                let mut end_angle = self.number(51)?.to_radians().rem_euclid(TAU);
+                if end_angle <= start_angle {
+                    end_angle += TAU;
+                }
+
+                vec![Curve2d::Arc {
+                    center: self.point(10)?,
+                    radius: self.number(40)?,
+                    start_angle,
+                    end_angle,
+                }]
+            },
+            "CIRCLE" => vec![Curve2d::Circle { center: self.point(10)?, radius: self.number(40)? }],
+            "LWPOLYLINE" => self.polyline()?,
+            "SPLINE" => return self.spline().map(|spline| vec![spline]),
+            _ => vec![],
+        };
+
+        // Arcs, circles and polylines are in the object coordinate system, which is
+        // mirrored when the extrusion direction points down.
+        if self.value(230).map(parse_number).transpose()?.is_some_and(|z| z < 0.0) {
+            let mirror = DAffine2::from_scale(dvec2(-1.0, 1.0));
+            Ok(curves.iter().map(|curve| curve.transformed(mirror)).collect())
+        } else {
+            Ok(curves)
+        }
+    }
+
+    fn polyline(&self) -> Result<Vec<Curve2d>, Error> {
+        let closed = self.value(70).map(parse_number).transpose()?.unwrap_or(0.0) as i32 & 1 == 1;
+
+        // Each vertex is a 10 and 20 pair, optionally followed by a 42 bulge.
+        let mut vertices: Vec<(DVec2, f64)> = vec![];
+        for (code, value) in self.pairs {
+            match (code, vertices.last_mut()) {
+                (10, _) => vertices.push((dvec2(parse_number(value)?, 0.0), 0.0)),
+                (20, Some((point, _))) => point.y = parse_number(value)?,
+                (42, Some((_, bulge))) => *bulge = parse_number(value)?,
+                _ => {},
+            }
+        }
+
+        if vertices.len() < 2 {
+            return Ok(vec![]);
+        }
+
+        if vertices.iter().all(|(_, bulge)| bulge.abs() < 1.0e-12) {
+            let points = vertices.into_iter().map(|(point, _)| point).collect();
+            return Ok(vec![Curve2d::Polyline { points, closed }]);
+        }
+
+        let segment_count = if closed { vertices.len() } else { vertices.len() - 1 };
+        Ok((0..segment_count)
+            .map(|i| {
+                let (start, bulge) = vertices[i];
+                let (end, _) = vertices[(i + 1) % vertices.len()];
+                bulge_curve(start, end, bulge)
+            })
+            .collect())
+    }
+
+    fn spline(&self) -> Result<Curve2d, Error> {
+        let xs = self.numbers(10)?;
+        let ys = self.numbers(20)?;
+        if xs.is_empty() || xs.len() != ys.len() {
+            return Err(Error::DxfReadFailed(
+                "SPLINE entities without control points are not supported".to_string(),
+            ));
+        }
+
+        let control_points: Vec<DVec2> = xs.into_iter().zip(ys).map(|(x, y)| dvec2(x, y)).collect();
+        let mut weights = self.numbers(41)?;
+        if weights.len() != control_points.len() {
+            weights = vec![1.0; control_points.len()];
+        }
+
+        Ok(Curve2d::Spline {
+            degree: self.number(71)? as usize,
+            control_points,
+            weights,
+            knots: self.numbers(40)?,
+        })
+    }
+}
+
+/// A polyline segment, which is an arc with a sweep angle of `4 * atan(bulge)`
+/// when the bulge isn't zero. Negative bulges go clockwise.
+fn bulge_curve(start: DVec2, end: DVec2, bulge: f64) -> Curve2d {
+    let chord = end - start;
+    let length = chord.length();
+    if bulge.abs() < 1.0e-12 || length < 1.0e-12 {
+        return Curve2d::Line { start, end };
+    }
+
+    let sweep = 4.0 * bulge.atan();
+    let center = (start + end) / 2.0 + chord.perp() * (1.0 - bulge * bulge) / (4.0 * bulge);
+    let radius = center.distance(start);
+
+    // Arcs are counter-clockwise, so clockwise ones start at the end point.
+    let start_angle = angle_of(if bulge > 0.0 { start } else { end } - center);
+
+    Curve2d::Arc { center, radius, start_angle, end_angle: start_angle + sweep.abs() }
+}
+
+fn parse_number(value: &str) -> Result<f64, Error> {
+    value.parse().map_err(|_| Error::DxfReadFailed(format!("invalid number `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::dvec2;
+    use std::collections::HashSet;
+
+    #[test]
+    fn arc_entity() {
+        let arc = Curve2d::Arc {
+            center: dvec2(1.0, 2.0),
+            radius: 3.0,
+            start_angle: 270.0f64.to_radians(),
+            end_angle: 450.0f64.to_radians(),
+        };
+
+        let mut dxf = vec![];
+        DxfWriter::new().curves("outline", [arc]).write(&mut dxf).unwrap();
+        let dxf = String::from_utf8(dxf).unwrap();
+
+        assert!(dxf.contains("100\nAcDbEntity\n  8\noutline\n100\nAcDbCircle\n"));
+        assert!(dxf.contains(" 40\n3\n100\nAcDbArc\n 50\n270\n 51\n90\n"));
+        assert!(dxf.ends_with("  0\nEOF\n"));
+    }
+
+    #[test]
+    fn handles_are_unique() {
+        let circle = Curve2d::Circle { center: DVec2::ZERO, radius: 1.0 };
+
+        let mut dxf = vec![];
+        DxfWriter::new().curves("outline", [circle]).write(&mut dxf).unwrap();
+        let dxf = String::from_utf8(dxf).unwrap();
+
+        let lines = dxf.lines().collect::<Vec<_>>();
+        let handles = lines
+            .chunks_exact(2)
+            .filter(|pair| matches!(pair[0].trim(), "5" | "105"))
+            .map(|pair| u32::from_str_radix(pair[1], 16).unwrap())
+            .collect::<Vec<_>>();
+
+        // The first handle is the $HANDSEED of the header.
+        let (seed, handles) = handles.split_first().unwrap();
+        assert!(handles.iter().all(|handle| handle < seed));
+        assert_eq!(handles.iter().collect::<HashSet<_>>().len(), handles.len());
+
+        assert!(dxf.contains("  0\nBLOCK_RECORD\n"));
+        assert!(dxf.contains("  2\nOBJECTS\n"));
+    }
+
+    #[test]
+    fn read_written_entities() {
+        let curves = vec![
+            Curve2d::Line { start: dvec2(0.0, 0.0), end: dvec2(4.0, 0.0) },
+            Curve2d::Arc {
+                center: dvec2(4.0, 2.0),
+                radius: 2.0,
+                start_angle: 270.0f64.to_radians(),
+                end_angle: 360.0f64.to_radians(),
+            },
+            Curve2d::Circle { center: dvec2(1.0, 1.0), radius: 0.5 },
+            Curve2d::Polyline { points: vec![dvec2(0.0, 0.0), dvec2(1.0, 2.0)], closed: false },
+        ];
+
+        let mut dxf = vec![];
+        DxfWriter::new().curves("outline", curves.clone()).write(&mut dxf).unwrap();
+        let entities = read_dxf(dxf.as_slice()).unwrap();
+
+        assert_eq!(entities.len(), curves.len());
+        assert!(entities.iter().all(|entity| entity.layer == "outline"));
+        for (entity, curve) in entities.iter().zip(&curves) {
+            assert!(entity.curve.start_point().abs_diff_eq(curve.start_point(), 1.0e-9));
+            assert!(entity.curve.end_point().abs_diff_eq(curve.end_point(), 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn polyline_bulge_becomes_arc() {
+        let dxf = "0\nSECTION\n2\nENTITIES\n0\nLWPOLYLINE\n8\n0\n90\n2\n70\n0\n\
+                   10\n1\n20\n0\n42\n1\n10\n-1\n20\n0\n0\nENDSEC\n0\nEOF\n";
+        let entities = read_dxf(dxf.as_bytes()).unwrap();
+
+        let Curve2d::Arc { center, radius, start_angle, end_angle } = entities[0].curve else {
+            panic!("expected an arc, got {:?}", entities[0].curve);
+        };
+
+        assert!(center.abs_diff_eq(DVec2::ZERO, 1.0e-12));
+        assert!((radius - 1.0).abs() < 1.0e-12);
+        assert!(start_angle.abs() < 1.0e-12);
+        assert!((end_angle - TAU / 2.0).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,535 @@
+use crate::{
+    angle::Angle,
+    geometry::Axis1,
+    primitives::{make_dir, make_vec, Face, Shape},
+    Error,
+};
+use cxx::UniquePtr;
+use glam::{DQuat, DVec3};
+use opencascade_sys as ffi;
+use std::f64::consts::TAU;
+
+/// Whether a feature adds material to its base shape or removes it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FeatureMode {
+    /// Fuse the feature with the base shape.
+    Boss,
+
+    /// Cut the feature out of the base shape.
+    Cut,
+}
+
+impl FeatureMode {
+    fn fuse(self) -> i32 {
+        // 0 = subtractive, 1 = additive
+        match self {
+            Self::Boss => 1,
+            Self::Cut => 0,
+        }
+    }
+}
+
+/// Controls where a [`PrismFeature`] stops.
+#[derive(Copy, Clone)]
+pub enum ExtrudeEnd<'a> {
+    /// Extrude a fixed length along the feature direction.
+    Blind(f64),
+
+    /// Extrude a total length, split evenly on both sides of the profile.
+    Symmetric(f64),
+
+    /// Extrude through the entire base shape, in the feature direction only.
+    ThroughAll,
+
+    /// Extrude until the first face of the base shape beyond the profile.
+    UpToNext,
+
+    /// Extrude until the given face is reached.
+    UpToFace(&'a Face),
+
+    /// Extrude until the given face, offset along its normal by `offset`
+    /// back towards the profile. A negative offset extrudes past the face.
+    UpToFaceOffset { face: &'a Face, offset: f64 },
+}
+
+/// Controls where a [`RevolFeature`] stops.
+#[derive(Copy, Clone)]
+pub enum RevolveEnd<'a> {
+    /// Revolve by a fixed angle around the feature axis.
+    Blind(Angle),
+
+    /// Revolve by a total angle, split evenly on both sides of the profile.
+    Symmetric(Angle),
+
+    /// Revolve a full turn through the entire base shape.
+    ThroughAll,
+
+    /// Revolve until the first face of the base shape in the path of the
+    /// profile's center.
+    ///
+    /// `BRepFeat_MakeRevol` has no until-next mode, so the path is followed in
+    /// one degree chords. Faces which only graze the circle between a chord and
+    /// its arc, up to `radius * (1 - cos(0.5°))` (about `4e-5 * radius`) away,
+    /// can be missed.
+    UpToNext,
+
+    /// Revolve until the given face is reached.
+    UpToFace(&'a Face),
+
+    /// Revolve until `offset` short of the given face. A negative offset
+    /// revolves past the face.
+    UpToFaceOffset { face: &'a Face, offset: Angle },
+}
+
+/// A linear boss or cut, built with `BRepFeat_MakePrism`.
+///
+/// The profile face should lie on a face of the base shape (the sketch face).
+pub struct PrismFeature<'a> {
+    base: &'a Shape,
+    profile: &'a Face,
+    sketch_face: Option<&'a Face>,
+    direction: DVec3,
+    mode: FeatureMode,
+    end: ExtrudeEnd<'a>,
+}
+
+impl<'a> PrismFeature<'a> {
+    /// Add material to `base` by extruding `profile` along `direction`.
+    pub fn boss(base: &'a Shape, profile: &'a Face, direction: DVec3, end: ExtrudeEnd<'a>) -> Self {
+        Self::new(base, profile, direction, FeatureMode::Boss, end)
+    }
+
+    /// Remove material from `base` by extruding `profile` along `direction`.
+    pub fn cut(base: &'a Shape, profile: &'a Face, direction: DVec3, end: ExtrudeEnd<'a>) -> Self {
+        Self::new(base, profile, direction, FeatureMode::Cut, end)
+    }
+
+    pub fn new(
+        base: &'a Shape,
+        profile: &'a Face,
+        direction: DVec3,
+        mode: FeatureMode,
+        end: ExtrudeEnd<'a>,
+    ) -> Self {
+        Self { base, profile, sketch_face: None, direction, mode, end }
+    }
+
+    /// The face of the base shape the profile was sketched on. Providing it
+    /// lets OCCT glue the feature to that face instead of running a full boolean.
+    pub fn sketch_face(mut self, sketch_face: &'a Face) -> Self {
+        self.sketch_face = Some(sketch_face);
+        self
+    }
+
+    pub fn build(self) -> Result<Shape, Error> {
+        let direction = self.direction.normalize();
+        let null_face = ffi::topo_ds::TopoDS_Face_new();
+        let sketch_face = self.sketch_face.map(|face| &face.inner).unwrap_or(&null_face);
+        let profile = ffi::topo_ds::cast_face_to_shape(&self.profile.inner);
+        let modify = false;
+
+        let mut make_prism = ffi::b_rep_feat::BRepFeat_MakePrism_new(
+            &self.base.inner,
+            profile,
+            sketch_face,
+            &make_dir(direction),
+            self.mode.fuse(),
+            modify,
+        );
+
+        match self.end {
+            ExtrudeEnd::Blind(length) => make_prism.pin_mut().perform_with_length(length),
+            ExtrudeEnd::Symmetric(length) => {
+                let from = translated_face(self.profile, direction * (-length / 2.0));
+                let until = translated_face(self.profile, direction * (length / 2.0));
+
+                make_prism.pin_mut().perform_from_until(
+                    ffi::topo_ds::cast_face_to_shape(&from.inner),
+                    ffi::topo_ds::cast_face_to_shape(&until.inner),
+                );
+            },
+            ExtrudeEnd::ThroughAll => make_prism.pin_mut().PerformUntilEnd(),
+            ExtrudeEnd::UpToNext => {
+                let until = next_face_along(self.base, self.profile.center_of_mass(), direction)
+                    .ok_or(Error::FeatureLimitNotFound)?;
+
+                make_prism
+                    .pin_mut()
+                    .perform_until_face(ffi::topo_ds::cast_face_to_shape(&until.inner));
+            },
+            ExtrudeEnd::UpToFace(face) => {
+                make_prism
+                    .pin_mut()
+                    .perform_until_face(ffi::topo_ds::cast_face_to_shape(&face.inner));
+            },
+            ExtrudeEnd::UpToFaceOffset { face, offset } => {
+                // Offset along the face normal, back towards the profile.
+                let offset =
+                    if face.normal_at_center().dot(direction) > 0.0 { -offset } else { offset };
+                let until = ffi::b_rep_offset_api::BRepOffsetAPI_MakeOffsetShape_simple(
+                    ffi::topo_ds::cast_face_to_shape(&face.inner),
+                    offset,
+                )
+                .map_err(|_| Error::FeatureFailed)?;
+
+                make_prism.pin_mut().perform_until_face(&until);
+            },
+        }
+
+        if !make_prism.IsDone() {
+            return Err(Error::FeatureFailed);
+        }
+
+        Ok(Shape::from_shape(make_prism.pin_mut().Shape()))
+    }
+}
+
+/// A revolved boss or cut, built with `BRepFeat_MakeRevol`.
+///
+/// The profile face should lie on a face of the base shape (the sketch face).
+pub struct RevolFeature<'a> {
+    base: &'a Shape,
+    profile: &'a Face,
+    sketch_face: Option<&'a Face>,
+    axis: Axis1,
+    mode: FeatureMode,
+    end: RevolveEnd<'a>,
+}
+
+impl<'a> RevolFeature<'a> {
+    /// Add material to `base` by revolving `profile` around `axis`, given as an
+    /// [`Axis1`] or an `(origin, direction)` pair.
+    pub fn boss(
+        base: &'a Shape,
+        profile: &'a Face,
+        axis: impl Into<Axis1>,
+        end: RevolveEnd<'a>,
+    ) -> Self {
+        Self::new(base, profile, axis, FeatureMode::Boss, end)
+    }
+
+    /// Remove material from `base` by revolving `profile` around `axis`.
+    pub fn cut(
+        base: &'a Shape,
+        profile: &'a Face,
+        axis: impl Into<Axis1>,
+        end: RevolveEnd<'a>,
+    ) -> Self {
+        Self::new(base, profile, axis, FeatureMode::Cut, end)
+    }
+
+    pub fn new(
+        base: &'a Shape,
+        profile: &'a Face,
+        axis: impl Into<Axis1>,
+        mode: FeatureMode,
+        end: RevolveEnd<'a>,
+    ) -> Self {
+        Self { base, profile, sketch_face: None, axis: axis.into(), mode, end }
+    }
+
+    /// The face of the base shape the profile was sketched on.
+    pub fn sketch_face(mut self, sketch_face: &'a Face) -> Self {
+        self.sketch_face = Some(sketch_face);
+        self
+    }
+
+    pub fn build(self) -> Result<Shape, Error> {
+        let axis = self.axis.to_gp_ax1();
+        let null_face = ffi::topo_ds::TopoDS_Face_new();
+        let sketch_face = self.sketch_face.map(|face| &face.inner).unwrap_or(&null_face);
+        let profile = ffi::topo_ds::cast_face_to_shape(&self.profile.inner);
+        let modify = false;
+
+        let mut make_revol = ffi::b_rep_feat::BRepFeat_MakeRevol_new(
+            &self.base.inner,
+            profile,
+            sketch_face,
+            &axis,
+            self.mode.fuse(),
+            modify,
+        );
+
+        match self.end {
+            RevolveEnd::Blind(angle) => make_revol.pin_mut().perform_with_angle(angle.radians()),
+            RevolveEnd::Symmetric(angle) => {
+                let half_angle = angle.radians() / 2.0;
+                let from = rotated_face(self.profile, &axis, -half_angle);
+                let until = rotated_face(self.profile, &axis, half_angle);
+
+                make_revol.pin_mut().perform_from_until(
+                    ffi::topo_ds::cast_face_to_shape(&from.inner),
+                    ffi::topo_ds::cast_face_to_shape(&until.inner),
+                );
+            },
+            RevolveEnd::ThroughAll => make_revol.pin_mut().PerformThruAll(),
+            RevolveEnd::UpToNext => {
+                let until = next_face_around(self.base, self.profile.center_of_mass(), self.axis)
+                    .ok_or(Error::FeatureLimitNotFound)?;
+
+                make_revol
+                    .pin_mut()
+                    .perform_until_face(ffi::topo_ds::cast_face_to_shape(&until.inner));
+            },
+            RevolveEnd::UpToFace(face) => {
+                make_revol
+                    .pin_mut()
+                    .perform_until_face(ffi::topo_ds::cast_face_to_shape(&face.inner));
+            },
+            RevolveEnd::UpToFaceOffset { face, offset } => {
+                let until = rotated_face(face, &axis, -offset.radians());
+
+                make_revol
+                    .pin_mut()
+                    .perform_until_face(ffi::topo_ds::cast_face_to_shape(&until.inner));
+            },
+        }
+
+        if !make_revol.IsDone() {
+            return Err(Error::FeatureFailed);
+        }
+
+        Ok(Shape::from_shape(make_revol.pin_mut().Shape()))
+    }
+}
+
+/// Find the closest face of `shape` hit by a ray starting at `origin`, ignoring
+/// faces touching the origin itself.
+fn next_face_along(shape: &Shape, origin: DVec3, direction: DVec3) -> Option<Face> {
+    const MIN_DISTANCE: f64 = 1.0e-6;
+
+    shape
+        .faces_along_line(origin, direction)
+        .into_iter()
+        .filter(|hit| hit.t > MIN_DISTANCE)
+        .min_by(|a, b| a.t.total_cmp(&b.t))
+        .map(|hit| hit.face)
+}
+
+/// Find the first face of `shape` hit by `origin` as it turns a full circle
+/// around an axis, ignoring faces touching the origin itself.
+///
+/// This approximates the circle with `STEPS` straight chords and casts a ray
+/// along each, so it's only as exact as the chords are close to the arc.
+fn next_face_around(shape: &Shape, origin: DVec3, axis: Axis1) -> Option<Face> {
+    const MIN_DISTANCE: f64 = 1.0e-6;
+    const STEPS: usize = 360;
+
+    let point_at = |step: usize| {
+        let rotation = DQuat::from_axis_angle(axis.direction(), TAU * step as f64 / STEPS as f64);
+        axis.origin() + rotation * (origin - axis.origin())
+    };
+
+    // Follow the circle in short straight steps.
+    (0..STEPS).find_map(|step| {
+        let (start, end) = (point_at(step), point_at(step + 1));
+        let length = start.distance(end);
+        let min_distance = if step == 0 { MIN_DISTANCE } else { 0.0 };
+
+        shape
+            .faces_along_line(start, end - start)
+            .into_iter()
+            .filter(|hit| hit.t > min_distance && hit.t <= length)
+            .min_by(|a, b| a.t.total_cmp(&b.t))
+            .map(|hit| hit.face)
+    })
+}
+
+fn translated_face(face: &Face, offset: DVec3) -> Face {
+    let mut transform = ffi::gp::new_transform();
+    transform.pin_mut().set_translation_vec(&make_vec(offset));
+
+    transformed_face(face, &transform)
+}
+
+fn rotated_face(face: &Face, axis: &ffi::gp::gp_Ax1, angle: f64) -> Face {
+    let mut transform = ffi::gp::new_transform();
+    transform.pin_mut().SetRotation(axis, angle);
+
+    transformed_face(face, &transform)
+}
+
+fn transformed_face(face: &Face, transform: &UniquePtr<ffi::gp::gp_Trsf>) -> Face {
+    let copy = true;
+    let mut brep_transform = ffi::b_rep_builder_api::BRepBuilderAPI_Transform_new(
+        ffi::topo_ds::cast_face_to_shape(&face.inner),
+        transform,
+        copy,
+    );
+
+    Face::from_face(ffi::topo_ds::TopoDS::Face(brep_transform.pin_mut().Shape()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{angle::Angle, bounding_box::max_z, workplane::Workplane};
+    use glam::dvec3;
+
+    /// A 2x2 profile on the top face of a 10x10x10 box centered on the origin.
+    fn prism_setup() -> (Shape, Face) {
+        let base = Shape::box_centered(10.0, 10.0, 10.0);
+        let profile = Workplane::xy().translated(dvec3(0.0, 0.0, 5.0)).rect(2.0, 2.0).to_face();
+
+        (base, profile)
+    }
+
+    /// A 2x2 profile on the top face of a 10x10x10 box, 1 to 3 units away from
+    /// an axis along the top face.
+    fn revol_setup() -> (Shape, Face, Axis1) {
+        let base = Shape::box_with_dimensions(10.0, 10.0, 10.0);
+        let profile = Workplane::xy().translated(dvec3(7.0, 5.0, 10.0)).rect(2.0, 2.0).to_face();
+
+        (base, profile, Axis1::new(dvec3(5.0, 0.0, 10.0), DVec3::NEG_Y))
+    }
+
+    fn plane_face(origin: DVec3, x_dir: DVec3, normal: DVec3) -> Face {
+        Workplane::new(x_dir, normal).translated(origin).rect(40.0, 40.0).to_face()
+    }
+
+    #[test]
+    fn blind_boss() {
+        let (base, profile) = prism_setup();
+        let result =
+            PrismFeature::boss(&base, &profile, DVec3::Z, ExtrudeEnd::Blind(3.0)).build().unwrap();
+
+        assert!((max_z(&result) - 8.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn symmetric_boss() {
+        let (base, profile) = prism_setup();
+        let result = PrismFeature::boss(&base, &profile, DVec3::Z, ExtrudeEnd::Symmetric(4.0))
+            .build()
+            .unwrap();
+
+        assert!((max_z(&result) - 7.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn through_all_cut_removes_every_wall() {
+        let (base, profile) = prism_setup();
+        let result = PrismFeature::cut(&base, &profile, DVec3::NEG_Z, ExtrudeEnd::ThroughAll)
+            .build()
+            .unwrap();
+
+        // 6 box faces plus the 4 walls of the square hole.
+        assert_eq!(result.faces().count(), 10);
+    }
+
+    #[test]
+    fn through_all_cut_is_one_sided() {
+        let base = Shape::box_centered(10.0, 10.0, 10.0);
+        let profile = Workplane::xy().rect(2.0, 2.0).to_face();
+
+        let result = PrismFeature::cut(&base, &profile, DVec3::NEG_Z, ExtrudeEnd::ThroughAll)
+            .build()
+            .unwrap();
+
+        // 6 box faces, plus the 4 walls and the ceiling of a hole open at the bottom only.
+        assert_eq!(result.faces().count(), 11);
+    }
+
+    #[test]
+    fn up_to_next_cut() {
+        let (base, profile) = prism_setup();
+        let result =
+            PrismFeature::cut(&base, &profile, DVec3::NEG_Z, ExtrudeEnd::UpToNext).build().unwrap();
+
+        assert_eq!(result.faces().count(), 10);
+    }
+
+    #[test]
+    fn up_to_face_boss() {
+        let (base, profile) = prism_setup();
+        let limit = plane_face(dvec3(0.0, 0.0, 8.0), DVec3::X, DVec3::Z);
+
+        let result = PrismFeature::boss(&base, &profile, DVec3::Z, ExtrudeEnd::UpToFace(&limit))
+            .build()
+            .unwrap();
+
+        assert!((max_z(&result) - 8.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn up_to_face_offset_boss() {
+        let (base, profile) = prism_setup();
+        let limit = plane_face(dvec3(0.0, 0.0, 8.0), DVec3::X, DVec3::Z);
+        let end = ExtrudeEnd::UpToFaceOffset { face: &limit, offset: 1.0 };
+
+        let result = PrismFeature::boss(&base, &profile, DVec3::Z, end).build().unwrap();
+
+        assert!((max_z(&result) - 7.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn blind_revolve_boss() {
+        let (base, profile, axis) = revol_setup();
+        let end = RevolveEnd::Blind(Angle::Degrees(90.0));
+
+        let result = RevolFeature::boss(&base, &profile, axis, end).build().unwrap();
+
+        assert!((max_z(&result) - 13.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn symmetric_revolve_boss() {
+        let (base, profile, axis) = revol_setup();
+        let end = RevolveEnd::Symmetric(Angle::Degrees(90.0));
+
+        let result = RevolFeature::boss(&base, &profile, axis, end).build().unwrap();
+
+        let expected = 10.0 + 3.0 * 45.0_f64.to_radians().sin();
+        assert!((max_z(&result) - expected).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn through_all_revolve_cut() {
+        let (base, profile, axis) = revol_setup();
+
+        let result =
+            RevolFeature::cut(&base, &profile, axis, RevolveEnd::ThroughAll).build().unwrap();
+
+        // The groove is cut into the box, nothing sticks out of it.
+        assert!((max_z(&result) - 10.0).abs() < 1.0e-6);
+        assert!(result.faces().count() > base.faces().count());
+    }
+
+    #[test]
+    fn up_to_next_revolve_boss() {
+        let (base, profile, axis) = revol_setup();
+        let wall = Shape::box_from_corners(dvec3(2.0, 0.0, 10.0), dvec3(4.0, 10.0, 16.0));
+        let base = base.union(&wall).shape;
+
+        let result =
+            RevolFeature::boss(&base, &profile, axis, RevolveEnd::UpToNext).build().unwrap();
+
+        // The boss stops at the wall, rather than wrapping into the box.
+        assert!((max_z(&result) - 16.0).abs() < 1.0e-6);
+        assert!(result.faces().count() > base.faces().count());
+    }
+
+    #[test]
+    fn up_to_face_revolve_boss() {
+        let (base, profile, axis) = revol_setup();
+        let limit = plane_face(dvec3(5.0, 5.0, 15.0), DVec3::Y, DVec3::X);
+
+        let result = RevolFeature::boss(&base, &profile, axis, RevolveEnd::UpToFace(&limit))
+            .build()
+            .unwrap();
+
+        assert!((max_z(&result) - 13.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn up_to_face_offset_revolve_boss() {
+        let (base, profile, axis) = revol_setup();
+        let limit = plane_face(dvec3(5.0, 5.0, 15.0), DVec3::Y, DVec3::X);
+        let end = RevolveEnd::UpToFaceOffset { face: &limit, offset: Angle::Degrees(30.0) };
+
+        let result = RevolFeature::boss(&base, &profile, axis, end).build().unwrap();
+
+        let expected = 10.0 + 3.0 * 60.0_f64.to_radians().sin();
+        assert!((max_z(&result) - expected).abs() < 1.0e-6);
+    }
+}
@@ -0,0 +1,535 @@
+use crate::{
+    angle::Angle,
+    primitives::{make_dir, make_point, Shape},
+    Error,
+};
+use cxx::UniquePtr;
+use glam::{dvec3, DAffine3, DMat3, DMat4, DVec3};
+use opencascade_sys as ffi;
+
+/// A point and a direction, like the axis of a cylinder or of a rotation.
+/// Corresponds to `gp_Ax1`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Axis1 {
+    origin: DVec3,
+    direction: DVec3,
+}
+
+impl Axis1 {
+    pub fn new(origin: DVec3, direction: DVec3) -> Self {
+        Self { origin, direction: direction.normalize() }
+    }
+
+    pub fn x() -> Self {
+        Self::new(DVec3::ZERO, DVec3::X)
+    }
+
+    pub fn y() -> Self {
+        Self::new(DVec3::ZERO, DVec3::Y)
+    }
+
+    pub fn z() -> Self {
+        Self::new(DVec3::ZERO, DVec3::Z)
+    }
+
+    pub fn origin(&self) -> DVec3 {
+        self.origin
+    }
+
+    pub fn direction(&self) -> DVec3 {
+        self.direction
+    }
+
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        Self::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.direction),
+        )
+    }
+
+    pub fn to_gp_ax1(&self) -> UniquePtr<ffi::gp::gp_Ax1> {
+        ffi::gp::gp_Ax1_new(&make_point(self.origin), &make_dir(self.direction))
+    }
+}
+
+impl From<(DVec3, DVec3)> for Axis1 {
+    fn from((origin, direction): (DVec3, DVec3)) -> Self {
+        Self::new(origin, direction)
+    }
+}
+
+impl From<Axis1> for (DVec3, DVec3) {
+    fn from(axis: Axis1) -> Self {
+        (axis.origin, axis.direction)
+    }
+}
+
+impl From<&ffi::gp::gp_Ax1> for Axis1 {
+    fn from(axis: &ffi::gp::gp_Ax1) -> Self {
+        Self::new(from_point(axis.Location()), from_dir(axis.Direction()))
+    }
+}
+
+/// A right-handed coordinate system, with an origin, a main (Z) direction and
+/// an X direction perpendicular to it. Corresponds to `gp_Ax2` and `gp_Ax3`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CoordinateSystem {
+    origin: DVec3,
+    z_dir: DVec3,
+    x_dir: DVec3,
+}
+
+/// The OCCT name for a [`CoordinateSystem`].
+pub type Axis2 = CoordinateSystem;
+
+impl CoordinateSystem {
+    /// A coordinate system with its main direction along `z_dir`. `x_dir` is
+    /// made perpendicular to `z_dir` if it isn't already.
+    pub fn new(origin: DVec3, z_dir: DVec3, x_dir: DVec3) -> Self {
+        let z_dir = z_dir.normalize();
+        let x_dir = x_dir - z_dir * x_dir.dot(z_dir);
+
+        if x_dir.length() < 1.0e-9 {
+            return Self::from_normal(origin, z_dir);
+        }
+
+        Self { origin, z_dir, x_dir: x_dir.normalize() }
+    }
+
+    /// A coordinate system with its main direction along `z_dir`, and the same
+    /// X direction OCCT picks for `gp_Ax2(origin, z_dir)`.
+    pub fn from_normal(origin: DVec3, z_dir: DVec3) -> Self {
+        let z_dir = z_dir.normalize();
+        let DVec3 { x: a, y: b, z: c } = z_dir;
+        let (a_abs, b_abs, c_abs) = (a.abs(), b.abs(), c.abs());
+
+        let x_dir = if b_abs <= a_abs && b_abs <= c_abs {
+            if a_abs > c_abs {
+                dvec3(-c, 0.0, a)
+            } else {
+                dvec3(c, 0.0, -a)
+            }
+        } else if a_abs <= b_abs && a_abs <= c_abs {
+            if b_abs > c_abs {
+                dvec3(0.0, -c, b)
+            } else {
+                dvec3(0.0, c, -b)
+            }
+        } else if a_abs > b_abs {
+            dvec3(-b, a, 0.0)
+        } else {
+            dvec3(b, -a, 0.0)
+        };
+
+        Self { origin, z_dir, x_dir: x_dir.normalize() }
+    }
+
+    /// The global coordinate system.
+    pub fn world() -> Self {
+        Self { origin: DVec3::ZERO, z_dir: DVec3::Z, x_dir: DVec3::X }
+    }
+
+    pub fn origin(&self) -> DVec3 {
+        self.origin
+    }
+
+    pub fn z_dir(&self) -> DVec3 {
+        self.z_dir
+    }
+
+    pub fn x_dir(&self) -> DVec3 {
+        self.x_dir
+    }
+
+    pub fn y_dir(&self) -> DVec3 {
+        self.z_dir.cross(self.x_dir)
+    }
+
+    /// The main axis, through the origin along the Z direction.
+    pub fn axis(&self) -> Axis1 {
+        Axis1::new(self.origin, self.z_dir)
+    }
+
+    /// The transform from coordinates in this system to global coordinates.
+    pub fn transform(&self) -> DAffine3 {
+        DAffine3::from_cols(self.x_dir, self.y_dir(), self.z_dir, self.origin)
+    }
+
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        Self::new(
+            transform.transform_point(self.origin),
+            transform.transform_vector(self.z_dir),
+            transform.transform_vector(self.x_dir),
+        )
+    }
+
+    pub fn to_gp_ax2(&self) -> UniquePtr<ffi::gp::gp_Ax2> {
+        ffi::gp::gp_Ax2_new_with_x_dir(
+            &make_point(self.origin),
+            &make_dir(self.z_dir),
+            &make_dir(self.x_dir),
+        )
+    }
+
+    pub fn to_gp_ax3(&self) -> UniquePtr<ffi::gp::gp_Ax3> {
+        ffi::gp::gp_Ax3_new(&make_point(self.origin), &make_dir(self.z_dir), &make_dir(self.x_dir))
+    }
+}
+
+impl From<CoordinateSystem> for DAffine3 {
+    fn from(coordinate_system: CoordinateSystem) -> Self {
+        coordinate_system.transform()
+    }
+}
+
+impl From<DAffine3> for CoordinateSystem {
+    fn from(transform: DAffine3) -> Self {
+        Self::new(transform.translation, transform.matrix3.z_axis, transform.matrix3.x_axis)
+    }
+}
+
+impl From<&ffi::gp::gp_Ax2> for CoordinateSystem {
+    fn from(axis: &ffi::gp::gp_Ax2) -> Self {
+        Self::new(
+            from_point(axis.Location()),
+            from_dir(axis.Direction()),
+            from_dir(axis.XDirection()),
+        )
+    }
+}
+
+/// Left-handed `gp_Ax3`s become right-handed, keeping their main and X
+/// directions.
+impl From<&ffi::gp::gp_Ax3> for CoordinateSystem {
+    fn from(axis: &ffi::gp::gp_Ax3) -> Self {
+        Self::new(
+            from_point(axis.Location()),
+            from_dir(axis.Direction()),
+            from_dir(axis.XDirection()),
+        )
+    }
+}
+
+/// An infinite plane, positioned by a coordinate system whose XY plane it is.
+/// Corresponds to `gp_Pln`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane {
+    position: CoordinateSystem,
+}
+
+impl Plane {
+    pub fn new(origin: DVec3, normal: DVec3) -> Self {
+        Self { position: CoordinateSystem::from_normal(origin, normal) }
+    }
+
+    pub fn xy() -> Self {
+        Self::new(DVec3::ZERO, DVec3::Z)
+    }
+
+    pub fn yz() -> Self {
+        Self::new(DVec3::ZERO, DVec3::X)
+    }
+
+    pub fn zx() -> Self {
+        Self::new(DVec3::ZERO, DVec3::Y)
+    }
+
+    pub fn position(&self) -> CoordinateSystem {
+        self.position
+    }
+
+    pub fn origin(&self) -> DVec3 {
+        self.position.origin
+    }
+
+    pub fn normal(&self) -> DVec3 {
+        self.position.z_dir
+    }
+
+    /// The distance of `point` from this plane, negative behind it.
+    pub fn signed_distance(&self, point: DVec3) -> f64 {
+        (point - self.origin()).dot(self.normal())
+    }
+
+    /// The closest point on this plane to `point`.
+    pub fn project_point(&self, point: DVec3) -> DVec3 {
+        point - self.normal() * self.signed_distance(point)
+    }
+
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        Self { position: self.position.transformed(transform) }
+    }
+
+    pub fn to_gp_pln(&self) -> UniquePtr<ffi::gp::gp_Pln> {
+        ffi::gp::gp_Pln_from_gp_Ax3(&self.position.to_gp_ax3())
+    }
+}
+
+impl From<(DVec3, DVec3)> for Plane {
+    fn from((origin, normal): (DVec3, DVec3)) -> Self {
+        Self::new(origin, normal)
+    }
+}
+
+impl From<CoordinateSystem> for Plane {
+    fn from(position: CoordinateSystem) -> Self {
+        Self { position }
+    }
+}
+
+impl From<&ffi::gp::gp_Pln> for Plane {
+    fn from(plane: &ffi::gp::gp_Pln) -> Self {
+        Self { position: plane.Position().into() }
+    }
+}
+
+/// An affine transformation. Rotations, translations, mirrors and uniform
+/// scales correspond to `gp_Trsf`, and anything else to `gp_GTrsf`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transform {
+    affine: DAffine3,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self { affine: DAffine3::IDENTITY };
+
+    pub fn translation(offset: DVec3) -> Self {
+        Self { affine: DAffine3::from_translation(offset) }
+    }
+
+    /// Rotate counter-clockwise by `angle` around `axis`.
+    pub fn rotation(axis: impl Into<Axis1>, angle: Angle) -> Self {
+        let axis = axis.into();
+        let rotation = DAffine3::from_axis_angle(axis.direction, angle.radians());
+
+        Self::around(axis.origin, rotation)
+    }
+
+    /// Scale by `factor` towards `center`.
+    pub fn scale(center: DVec3, factor: f64) -> Self {
+        Self::around(center, DAffine3::from_scale(DVec3::splat(factor)))
+    }
+
+    /// Scale by a different factor along each axis, towards `center`.
+    pub fn non_uniform_scale(center: DVec3, factors: DVec3) -> Self {
+        Self::around(center, DAffine3::from_scale(factors))
+    }
+
+    /// Mirror through a point.
+    pub fn mirror_point(point: DVec3) -> Self {
+        Self::around(point, DAffine3::from_scale(DVec3::NEG_ONE))
+    }
+
+    /// Mirror around an axis, which is the same as rotating a half turn around it.
+    pub fn mirror_axis(axis: impl Into<Axis1>) -> Self {
+        Self::rotation(axis, Angle::Radians(std::f64::consts::PI))
+    }
+
+    /// Mirror across a plane.
+    pub fn mirror_plane(plane: &Plane) -> Self {
+        let normal = plane.normal();
+        let reflection = DMat3::IDENTITY - 2.0 * outer_product(normal, normal);
+
+        Self::around(plane.origin(), DAffine3::from_mat3(reflection))
+    }
+
+    /// Move shapes positioned in `from` to the same position in `to`.
+    pub fn between(from: &CoordinateSystem, to: &CoordinateSystem) -> Self {
+        Self { affine: to.transform() * from.transform().inverse() }
+    }
+
+    /// This transform followed by `next`.
+    #[must_use]
+    pub fn then(&self, next: &Transform) -> Self {
+        Self { affine: next.affine * self.affine }
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        Self { affine: self.affine.inverse() }
+    }
+
+    pub fn transform_point(&self, point: DVec3) -> DVec3 {
+        self.affine.transform_point3(point)
+    }
+
+    pub fn transform_vector(&self, vector: DVec3) -> DVec3 {
+        self.affine.transform_vector3(vector)
+    }
+
+    /// Whether this transform keeps angles, so it can be a `gp_Trsf`.
+    pub fn is_similarity(&self) -> bool {
+        let matrix = self.affine.matrix3;
+        let gram = matrix.transpose() * matrix;
+        let scale_squared = (gram.x_axis.x + gram.y_axis.y + gram.z_axis.z) / 3.0;
+
+        scale_squared > 1.0e-18
+            && gram.abs_diff_eq(
+                DMat3::from_diagonal(DVec3::splat(scale_squared)),
+                1.0e-9 * scale_squared,
+            )
+    }
+
+    pub fn to_gp_trsf(&self) -> Result<UniquePtr<ffi::gp::gp_Trsf>, Error> {
+        if !self.is_similarity() {
+            return Err(Error::NonUniformTransform);
+        }
+
+        let DAffine3 { matrix3: m, translation: t } = self.affine;
+        let mut transform = ffi::gp::new_transform();
+        transform
+            .pin_mut()
+            .SetValues(
+                m.x_axis.x, m.y_axis.x, m.z_axis.x, t.x, m.x_axis.y, m.y_axis.y, m.z_axis.y, t.y,
+                m.x_axis.z, m.y_axis.z, m.z_axis.z, t.z,
+            )
+            .map_err(|_| Error::NonUniformTransform)?;
+
+        Ok(transform)
+    }
+
+    pub fn to_gp_gtrsf(&self) -> UniquePtr<ffi::gp::gp_GTrsf> {
+        let columns = self.affine.to_cols_array_2d();
+        let mut transform = ffi::gp::new_gp_GTrsf();
+
+        for (col, values) in columns.iter().enumerate() {
+            for (row, value) in values.iter().enumerate() {
+                transform.pin_mut().SetValue(row as i32 + 1, col as i32 + 1, *value);
+            }
+        }
+
+        transform
+    }
+
+    /// Apply this transform to a copy of `shape`.
+    pub(crate) fn apply(&self, shape: &ffi::topo_ds::TopoDS_Shape) -> Shape {
+        let copy = true;
+
+        match self.to_gp_trsf() {
+            Ok(transform) => {
+                let mut operation =
+                    ffi::b_rep_builder_api::BRepBuilderAPI_Transform_new(shape, &transform, copy);
+
+                Shape::from_shape(operation.pin_mut().Shape())
+            },
+            Err(_) => {
+                let mut operation = ffi::b_rep_builder_api::BRepBuilderAPI_GTransform_new(
+                    shape,
+                    &self.to_gp_gtrsf(),
+                    copy,
+                );
+
+                Shape::from_shape(operation.pin_mut().Shape())
+            },
+        }
+    }
+
+    /// Build `transform` in a frame centered on `center`.
+    fn around(center: DVec3, transform: DAffine3) -> Self {
+        Self {
+            affine: DAffine3::from_translation(center)
+                * transform
+                * DAffine3::from_translation(-center),
+        }
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<DAffine3> for Transform {
+    fn from(affine: DAffine3) -> Self {
+        Self { affine }
+    }
+}
+
+impl From<Transform> for DAffine3 {
+    fn from(transform: Transform) -> Self {
+        transform.affine
+    }
+}
+
+impl From<Transform> for DMat4 {
+    fn from(transform: Transform) -> Self {
+        DMat4::from(transform.affine)
+    }
+}
+
+impl From<&ffi::gp::gp_Trsf> for Transform {
+    fn from(transform: &ffi::gp::gp_Trsf) -> Self {
+        Self { affine: affine_from_values(|row, col| transform.Value(row, col)) }
+    }
+}
+
+impl From<&ffi::gp::gp_GTrsf> for Transform {
+    fn from(transform: &ffi::gp::gp_GTrsf) -> Self {
+        Self { affine: affine_from_values(|row, col| transform.Value(row, col)) }
+    }
+}
+
+/// Build an affine transform from a 3x4 matrix with 1-based indices.
+fn affine_from_values(value: impl Fn(i32, i32) -> f64) -> DAffine3 {
+    let column = |col| dvec3(value(1, col), value(2, col), value(3, col));
+
+    DAffine3::from_cols(column(1), column(2), column(3), column(4))
+}
+
+fn outer_product(a: DVec3, b: DVec3) -> DMat3 {
+    DMat3::from_cols(a * b.x, a * b.y, a * b.z)
+}
+
+fn from_point(point: &ffi::gp::gp_Pnt) -> DVec3 {
+    dvec3(point.X(), point.Y(), point.Z())
+}
+
+fn from_dir(dir: &ffi::gp::gp_Dir) -> DVec3 {
+    dvec3(dir.X(), dir.Y(), dir.Z())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bounding_box;
+
+    #[test]
+    fn gp_round_trips() {
+        let axis = Axis1::new(dvec3(1.0, 2.0, 3.0), dvec3(0.0, 3.0, 4.0));
+        assert!(Axis1::from(&*axis.to_gp_ax1()).origin().abs_diff_eq(axis.origin(), 1.0e-9));
+
+        let system = CoordinateSystem::from_normal(dvec3(1.0, 2.0, 3.0), dvec3(1.0, 1.0, 0.0));
+        let from_gp = CoordinateSystem::from(&*system.to_gp_ax2());
+        assert!(from_gp.x_dir().abs_diff_eq(system.x_dir(), 1.0e-9));
+
+        let occt_default = ffi::gp::gp_Ax2_new(&make_point(DVec3::ZERO), &make_dir(system.z_dir()));
+        let from_default = CoordinateSystem::from(&*occt_default);
+        assert!(from_default.x_dir().abs_diff_eq(system.x_dir(), 1.0e-9));
+
+        let transform = Transform::rotation(axis, Angle::Degrees(30.0))
+            .then(&Transform::mirror_plane(&Plane::new(DVec3::ONE, DVec3::X)))
+            .then(&Transform::scale(DVec3::ZERO, 2.0));
+        let from_gp = Transform::from(&*transform.to_gp_trsf().unwrap());
+        assert!(DAffine3::from(from_gp).abs_diff_eq(transform.into(), 1.0e-9));
+
+        let stretch = Transform::non_uniform_scale(DVec3::ZERO, dvec3(1.0, 2.0, 3.0));
+        assert!(stretch.to_gp_trsf().is_err());
+        let from_gp = Transform::from(&*stretch.to_gp_gtrsf());
+        assert!(DAffine3::from(from_gp).abs_diff_eq(stretch.into(), 1.0e-9));
+    }
+
+    #[test]
+    fn transform_shape() {
+        let shape = Shape::box_with_dimensions(1.0, 1.0, 1.0)
+            .transformed(&Transform::non_uniform_scale(DVec3::ZERO, dvec3(1.0, 2.0, 3.0)))
+            .transformed(&Transform::translation(DVec3::ONE));
+
+        let bounds = bounding_box::aabb(&shape);
+        assert!(bounds.min().abs_diff_eq(DVec3::ONE, 1.0e-3));
+        assert!(bounds.max().abs_diff_eq(dvec3(2.0, 3.0, 4.0), 1.0e-3));
+    }
+}
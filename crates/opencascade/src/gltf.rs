@@ -0,0 +1,520 @@
+use crate::{
+    geometry::Transform,
+    mesh::{Mesh, MeshParameters},
+    primitives::Shape,
+    Error,
+};
+use glam::{DAffine3, DMat3, DMat4, DVec3};
+use std::{fmt::Write as _, path::Path};
+
+const DEFAULT_COLOR: [f32; 4] = [0.8, 0.8, 0.8, 1.0];
+
+/// A named node of a glTF scene, with an optional shape and child nodes.
+pub struct GltfNode<'a> {
+    pub name: String,
+    pub shape: Option<&'a Shape>,
+    /// The RGBA base color of the faces of `shape`.
+    pub color: [f32; 4],
+    /// Colors for individual faces of `shape`, by their index in [`Shape::faces`].
+    pub face_colors: Vec<(usize, [f32; 4])>,
+    /// The placement of the node relative to its parent, in model units.
+    pub transform: Option<Transform>,
+    pub children: Vec<GltfNode<'a>>,
+}
+
+impl<'a> GltfNode<'a> {
+    pub fn new(name: impl Into<String>, shape: &'a Shape) -> Self {
+        Self { shape: Some(shape), ..Self::group(name) }
+    }
+
+    /// A node without geometry, used to group other nodes.
+    pub fn group(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            shape: None,
+            color: DEFAULT_COLOR,
+            face_colors: vec![],
+            transform: None,
+            children: vec![],
+        }
+    }
+
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn face_color(mut self, face_id: usize, color: [f32; 4]) -> Self {
+        self.face_colors.push((face_id, color));
+        self
+    }
+
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    pub fn child(mut self, child: GltfNode<'a>) -> Self {
+        self.children.push(child);
+        self
+    }
+}
+
+/// Writes shapes to glTF 2.0 files.
+///
+/// Files with a `.glb` extension are written in the binary format, anything
+/// else is written as JSON with the geometry embedded as a data URI.
+/// Coordinates are converted from the Z-up convention used by CAD to glTF's Y-up.
+pub struct GltfWriter<'a> {
+    parameters: MeshParameters,
+    unit_scale: f64,
+    nodes: Vec<GltfNode<'a>>,
+}
+
+impl<'a> GltfWriter<'a> {
+    pub fn new(parameters: MeshParameters) -> Self {
+        Self { parameters, unit_scale: 0.001, nodes: vec![] }
+    }
+
+    /// The factor to convert model units to meters, the unit of glTF. The default
+    /// of 0.001 assumes the model is in millimeters.
+    pub fn unit_scale(mut self, unit_scale: f64) -> Self {
+        self.unit_scale = unit_scale;
+        self
+    }
+
+    /// Add a root node to the scene.
+    pub fn node(mut self, node: GltfNode<'a>) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let binary =
+            path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("glb"));
+
+        let bytes = self.to_bytes(binary)?;
+        std::fs::write(path, bytes)?;
+
+        Ok(())
+    }
+
+    /// Encode the scene as a GLB file if `binary` is set, or as glTF JSON otherwise.
+    pub fn to_bytes(&self, binary: bool) -> Result<Vec<u8>, Error> {
+        if self.nodes.is_empty() {
+            return Err(Error::EmptyGltfScene);
+        }
+
+        let mut document = Document::default();
+
+        let mut root_nodes = vec![];
+        for node in &self.nodes {
+            root_nodes.push(document.add_node(node, &self.parameters, self.unit_scale)?);
+        }
+
+        Ok(document.encode(&root_nodes, binary))
+    }
+}
+
+#[derive(Default)]
+struct Document {
+    nodes: Vec<String>,
+    meshes: Vec<String>,
+    materials: Vec<[f32; 4]>,
+    accessors: Vec<String>,
+    buffer_views: Vec<String>,
+    buffer: Vec<u8>,
+}
+
+impl Document {
+    fn add_node(
+        &mut self,
+        node: &GltfNode,
+        parameters: &MeshParameters,
+        unit_scale: f64,
+    ) -> Result<usize, Error> {
+        let mut children = vec![];
+        for child in &node.children {
+            children.push(self.add_node(child, parameters, unit_scale)?);
+        }
+
+        let mesh = match node.shape {
+            Some(shape) => {
+                let mesh = shape.mesh_with_parameters(parameters)?;
+                self.add_mesh(&node.name, &mesh, node, unit_scale)
+            },
+            None => None,
+        };
+
+        let mut json = format!(r#"{{"name":{}"#, json_string(&node.name));
+        if let Some(transform) = node.transform {
+            let matrix: Vec<String> = to_y_up_matrix(transform, unit_scale)
+                .to_cols_array()
+                .iter()
+                // Adding zero turns -0 into 0.
+                .map(|value| (*value as f32 + 0.0).to_string())
+                .collect();
+            write!(json, r#","matrix":[{}]"#, matrix.join(",")).unwrap();
+        }
+        if let Some(mesh) = mesh {
+            write!(json, r#","mesh":{mesh}"#).unwrap();
+        }
+        if !children.is_empty() {
+            write!(json, r#","children":{}"#, json_array(&children)).unwrap();
+        }
+        json.push('}');
+
+        self.nodes.push(json);
+        Ok(self.nodes.len() - 1)
+    }
+
+    fn add_mesh(
+        &mut self,
+        name: &str,
+        mesh: &Mesh,
+        node: &GltfNode,
+        unit_scale: f64,
+    ) -> Option<usize> {
+        if mesh.indices.is_empty() {
+            return None;
+        }
+
+        let positions: Vec<DVec3> =
+            mesh.vertices.iter().map(|vertex| to_y_up(*vertex) * unit_scale).collect();
+        let normals: Vec<DVec3> = mesh.normals.iter().map(|normal| to_y_up(*normal)).collect();
+
+        let min = positions.iter().fold(DVec3::INFINITY, |min, position| min.min(*position));
+        let max = positions.iter().fold(DVec3::NEG_INFINITY, |max, position| max.max(*position));
+
+        let position_accessor = self.add_accessor(
+            &vec3_bytes(&positions),
+            ARRAY_BUFFER,
+            FLOAT,
+            positions.len(),
+            "VEC3",
+            Some((min, max)),
+        );
+
+        let mut attributes = format!(r#""POSITION":{position_accessor}"#);
+
+        if normals.len() == positions.len() {
+            let normal_accessor = self.add_accessor(
+                &vec3_bytes(&normals),
+                ARRAY_BUFFER,
+                FLOAT,
+                normals.len(),
+                "VEC3",
+                None,
+            );
+            write!(attributes, r#","NORMAL":{normal_accessor}"#).unwrap();
+        }
+
+        if mesh.uvs.len() == positions.len() {
+            let uv_bytes: Vec<u8> = mesh
+                .uvs
+                .iter()
+                .flat_map(|uv| [uv.x as f32, uv.y as f32])
+                .flat_map(f32::to_le_bytes)
+                .collect();
+            let uv_accessor =
+                self.add_accessor(&uv_bytes, ARRAY_BUFFER, FLOAT, mesh.uvs.len(), "VEC2", None);
+            write!(attributes, r#","TEXCOORD_0":{uv_accessor}"#).unwrap();
+        }
+
+        // One primitive for each distinct material.
+        let face_color = |face_id: usize| {
+            node.face_colors
+                .iter()
+                .rev()
+                .find(|(id, _)| *id == face_id)
+                .map(|(_, color)| *color)
+                .unwrap_or(node.color)
+        };
+
+        let mut primitives: Vec<(usize, Vec<u32>)> = vec![];
+        for (triangle, face_id) in mesh.indices.chunks_exact(3).zip(&mesh.face_ids) {
+            let material = self.material(face_color(*face_id));
+
+            let primitive_index = match primitives.iter().position(|(m, _)| *m == material) {
+                Some(index) => index,
+                None => {
+                    primitives.push((material, vec![]));
+                    primitives.len() - 1
+                },
+            };
+
+            primitives[primitive_index].1.extend(triangle.iter().map(|index| *index as u32));
+        }
+
+        let mut primitive_json = vec![];
+        for (material, indices) in primitives {
+            let index_bytes: Vec<u8> = indices.iter().copied().flat_map(u32::to_le_bytes).collect();
+            let index_accessor = self.add_accessor(
+                &index_bytes,
+                ELEMENT_ARRAY_BUFFER,
+                UNSIGNED_INT,
+                indices.len(),
+                "SCALAR",
+                None,
+            );
+
+            primitive_json.push(format!(
+                r#"{{"attributes":{{{attributes}}},"indices":{index_accessor},"material":{material}}}"#
+            ));
+        }
+
+        self.meshes.push(format!(
+            r#"{{"name":{},"primitives":[{}]}}"#,
+            json_string(name),
+            primitive_json.join(",")
+        ));
+
+        Some(self.meshes.len() - 1)
+    }
+
+    fn material(&mut self, color: [f32; 4]) -> usize {
+        match self.materials.iter().position(|material| *material == color) {
+            Some(index) => index,
+            None => {
+                self.materials.push(color);
+                self.materials.len() - 1
+            },
+        }
+    }
+
+    fn add_accessor(
+        &mut self,
+        bytes: &[u8],
+        target: u32,
+        component_type: u32,
+        count: usize,
+        accessor_type: &str,
+        bounds: Option<(DVec3, DVec3)>,
+    ) -> usize {
+        // Every component is 4 bytes wide, so the views stay aligned.
+        let byte_offset = self.buffer.len();
+        self.buffer.extend_from_slice(bytes);
+
+        self.buffer_views.push(format!(
+            r#"{{"buffer":0,"byteOffset":{byte_offset},"byteLength":{},"target":{target}}}"#,
+            bytes.len()
+        ));
+
+        let mut json = format!(
+            r#"{{"bufferView":{},"componentType":{component_type},"count":{count},"type":"{accessor_type}""#,
+            self.buffer_views.len() - 1
+        );
+        if let Some((min, max)) = bounds {
+            write!(
+                json,
+                r#","min":[{},{},{}],"max":[{},{},{}]"#,
+                min.x as f32, min.y as f32, min.z as f32, max.x as f32, max.y as f32, max.z as f32
+            )
+            .unwrap();
+        }
+        json.push('}');
+
+        self.accessors.push(json);
+        self.accessors.len() - 1
+    }
+
+    fn encode(self, root_nodes: &[usize], binary: bool) -> Vec<u8> {
+        let materials: Vec<String> = self
+            .materials
+            .iter()
+            .map(|[r, g, b, a]| {
+                let alpha_mode = if *a < 1.0 { r#","alphaMode":"BLEND""# } else { "" };
+                format!(
+                    r#"{{"pbrMetallicRoughness":{{"baseColorFactor":[{r},{g},{b},{a}],"metallicFactor":0,"roughnessFactor":0.5}}{alpha_mode}}}"#
+                )
+            })
+            .collect();
+
+        let buffer = if binary {
+            format!(r#"{{"byteLength":{}}}"#, self.buffer.len())
+        } else {
+            format!(
+                r#"{{"byteLength":{},"uri":"data:application/octet-stream;base64,{}"}}"#,
+                self.buffer.len(),
+                base64(&self.buffer)
+            )
+        };
+
+        let mut json = format!(
+            r#"{{"asset":{{"version":"2.0","generator":"opencascade-rs"}},"scene":0,"scenes":[{{"nodes":{}}}],"nodes":[{}]"#,
+            json_array(root_nodes),
+            self.nodes.join(",")
+        );
+        if !self.meshes.is_empty() {
+            write!(
+                json,
+                r#","meshes":[{}],"materials":[{}],"accessors":[{}],"bufferViews":[{}],"buffers":[{}]"#,
+                self.meshes.join(","),
+                materials.join(","),
+                self.accessors.join(","),
+                self.buffer_views.join(","),
+                buffer
+            )
+            .unwrap();
+        }
+        json.push('}');
+
+        if !binary {
+            return json.into_bytes();
+        }
+
+        let mut json = json.into_bytes();
+        pad_to_4(&mut json, b' ');
+
+        let mut bin = self.buffer;
+        pad_to_4(&mut bin, 0);
+
+        let has_bin = !bin.is_empty();
+        let total_length = 12 + 8 + json.len() + if has_bin { 8 + bin.len() } else { 0 };
+
+        let mut glb = Vec::with_capacity(total_length);
+        glb.extend_from_slice(b"glTF");
+        glb.extend_from_slice(&2u32.to_le_bytes());
+        glb.extend_from_slice(&(total_length as u32).to_le_bytes());
+
+        glb.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        glb.extend_from_slice(b"JSON");
+        glb.extend_from_slice(&json);
+
+        if has_bin {
+            glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+            glb.extend_from_slice(b"BIN\0");
+            glb.extend_from_slice(&bin);
+        }
+
+        glb
+    }
+}
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+
+fn to_y_up(v: DVec3) -> DVec3 {
+    DVec3::new(v.x, v.z, -v.y)
+}
+
+/// Convert a transform between Z-up model coordinates into one between the
+/// Y-up coordinates in meters used by glTF.
+fn to_y_up_matrix(transform: Transform, unit_scale: f64) -> DMat4 {
+    let to_y_up = DAffine3::from_mat3(DMat3::from_cols(DVec3::X, DVec3::NEG_Z, DVec3::Y))
+        * DAffine3::from_scale(DVec3::splat(unit_scale));
+
+    (to_y_up * DAffine3::from(transform) * to_y_up.inverse()).into()
+}
+
+fn vec3_bytes(values: &[DVec3]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|v| [v.x as f32, v.y as f32, v.z as f32])
+        .flat_map(f32::to_le_bytes)
+        .collect()
+}
+
+fn pad_to_4(bytes: &mut Vec<u8>, padding: u8) {
+    bytes.resize(bytes.len().next_multiple_of(4), padding);
+}
+
+fn json_array(values: &[usize]) -> String {
+    let values: Vec<String> = values.iter().map(usize::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b"glTF"), "Z2xURg==");
+        assert_eq!(base64(b"mesh!"), "bWVzaCE=");
+        assert_eq!(base64(b"box"), "Ym94");
+    }
+
+    #[test]
+    fn glb_header_and_chunks() {
+        let shape = Shape::box_centered(1.0, 1.0, 1.0);
+        let glb = GltfWriter::new(MeshParameters::default())
+            .node(GltfNode::new("box", &shape).face_color(0, [1.0, 0.0, 0.0, 1.0]))
+            .to_bytes(true)
+            .unwrap();
+
+        assert_eq!(&glb[0..4], b"glTF");
+        assert_eq!(u32::from_le_bytes(glb[8..12].try_into().unwrap()) as usize, glb.len());
+        assert_eq!(&glb[16..20], b"JSON");
+
+        let json_length = u32::from_le_bytes(glb[12..16].try_into().unwrap()) as usize;
+        let json = std::str::from_utf8(&glb[20..20 + json_length]).unwrap();
+        assert!(json.contains(r#""name":"box""#));
+        // The red face and the default color.
+        assert!(json.contains(r#""materials":[{"#));
+        assert_eq!(json.matches("baseColorFactor").count(), 2);
+    }
+
+    #[test]
+    fn node_transform_is_written_as_y_up_matrix() {
+        let shape = Shape::box_centered(1.0, 1.0, 1.0);
+        let node = GltfNode::new("box", &shape).transform(Transform::translation(DVec3::Y * 10.0));
+
+        let json = GltfWriter::new(MeshParameters::default()).node(node).to_bytes(false).unwrap();
+        let json = String::from_utf8(json).unwrap();
+
+        // 10 mm along Z-up's Y is 0.01 m along Y-up's -Z.
+        assert!(json.contains(r#""matrix":[1,0,0,0,0,1,0,0,0,0,1,0,0,0,-0.01,1]"#));
+    }
+
+    #[test]
+    fn empty_scene_is_rejected() {
+        let writer = GltfWriter::new(MeshParameters::default());
+
+        assert!(matches!(writer.to_bytes(true), Err(Error::EmptyGltfScene)));
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{
+    angle::{Angle, ToAngle},
+    primitives::{Face, Shape},
+    Error,
+};
+
+/// How far above the entry point the cutting tool of a hole starts.
+const ENTRY_CLEARANCE: f64 = 0.1;
+
+/// ISO metric coarse thread sizes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MetricSize {
+    M2,
+    M2_5,
+    M3,
+    M4,
+    M5,
+    M6,
+    M8,
+    M10,
+    M12,
+}
+
+/// Clearance hole fit classes from ISO 273.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ClearanceFit {
+    Fine,
+    #[default]
+    Medium,
+    Coarse,
+}
+
+impl MetricSize {
+    pub fn nominal_diameter(self) -> f64 {
+        match self {
+            Self::M2 => 2.0,
+            Self::M2_5 => 2.5,
+            Self::M3 => 3.0,
+            Self::M4 => 4.0,
+            Self::M5 => 5.0,
+            Self::M6 => 6.0,
+            Self::M8 => 8.0,
+            Self::M10 => 10.0,
+            Self::M12 => 12.0,
+        }
+    }
+
+    /// The coarse thread pitch.
+    pub fn pitch(self) -> f64 {
+        match self {
+            Self::M2 => 0.4,
+            Self::M2_5 => 0.45,
+            Self::M3 => 0.5,
+            Self::M4 => 0.7,
+            Self::M5 => 0.8,
+            Self::M6 => 1.0,
+            Self::M8 => 1.25,
+            Self::M10 => 1.5,
+            Self::M12 => 1.75,
+        }
+    }
+
+    /// The drill diameter to use before tapping a thread of this size.
+    pub fn tap_drill_diameter(self) -> f64 {
+        self.nominal_diameter() - self.pitch()
+    }
+
+    /// The clearance hole diameter for a bolt of this size, per ISO 273.
+    pub fn clearance_diameter(self, fit: ClearanceFit) -> f64 {
+        let (fine, medium, coarse) = match self {
+            Self::M2 => (2.2, 2.4, 2.6),
+            Self::M2_5 => (2.7, 2.9, 3.1),
+            Self::M3 => (3.2, 3.4, 3.6),
+            Self::M4 => (4.3, 4.5, 4.8),
+            Self::M5 => (5.3, 5.5, 5.8),
+            Self::M6 => (6.4, 6.6, 7.0),
+            Self::M8 => (8.4, 9.0, 10.0),
+            Self::M10 => (10.5, 11.0, 12.0),
+            Self::M12 => (13.0, 13.5, 14.5),
+        };
+
+        match fit {
+            ClearanceFit::Fine => fine,
+            ClearanceFit::Medium => medium,
+            ClearanceFit::Coarse => coarse,
+        }
+    }
+
+    /// The counterbore `(diameter, depth)` for an ISO 4762 socket head cap screw,
+    /// with the diameter taken from DIN 974-1.
+    pub fn counterbore(self) -> (f64, f64) {
+        let diameter = match self {
+            Self::M2 => 4.3,
+            Self::M2_5 => 5.0,
+            Self::M3 => 6.5,
+            Self::M4 => 8.0,
+            Self::M5 => 10.0,
+            Self::M6 => 11.0,
+            Self::M8 => 15.0,
+            Self::M10 => 18.0,
+            Self::M12 => 20.0,
+        };
+
+        // ISO 4762 head height is equal to the nominal diameter.
+        (diameter, self.nominal_diameter())
+    }
+
+    /// The 90 degree countersink diameter for an ISO 10642 countersunk screw
+    /// (the theoretical maximum head diameter).
+    pub fn countersink_diameter(self) -> f64 {
+        2.24 * self.nominal_diameter()
+    }
+}
+
+/// How deep a [`Hole`] goes.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum HoleDepth {
+    /// Go through the entire shape.
+    Through,
+
+    /// Stop at the given depth, measured to the shoulder of the drill point.
+    Blind(f64),
+}
+
+/// The optional recess at the entrance of a [`Hole`].
+#[derive(Debug, Copy, Clone)]
+pub enum HoleHead {
+    None,
+    Counterbore { diameter: f64, depth: f64 },
+    Countersink { diameter: f64, angle: Angle },
+}
+
+/// A description of a hole feature. This also serves as the metadata attached
+/// to the faces of the resulting [`HoleShape`], see [`HoleFace`].
+#[derive(Debug, Copy, Clone)]
+pub struct Hole {
+    pub diameter: f64,
+    pub depth: HoleDepth,
+    pub point_angle: Angle,
+    pub head: HoleHead,
+    /// The thread to be tapped into this hole, if any.
+    pub thread: Option<MetricSize>,
+}
+
+impl Hole {
+    /// A plain through hole of `diameter`.
+    pub fn new(diameter: f64) -> Self {
+        Self {
+            diameter,
+            depth: HoleDepth::Through,
+            point_angle: 118.degrees(),
+            head: HoleHead::None,
+            thread: None,
+        }
+    }
+
+    /// A clearance hole for a bolt of `size`.
+    pub fn clearance(size: MetricSize, fit: ClearanceFit) -> Self {
+        Self::new(size.clearance_diameter(fit))
+    }
+
+    /// A medium fit clearance hole with a counterbore for a socket head cap screw.
+    pub fn counterbored(size: MetricSize) -> Self {
+        let (diameter, depth) = size.counterbore();
+        Self::clearance(size, ClearanceFit::Medium).counterbore(diameter, depth)
+    }
+
+    /// A medium fit clearance hole with a 90 degree countersink for a countersunk screw.
+    pub fn countersunk(size: MetricSize) -> Self {
+        Self::clearance(size, ClearanceFit::Medium)
+            .countersink(size.countersink_diameter(), 90.degrees())
+    }
+
+    /// A tap drill hole for a thread of `size`.
+    pub fn tapped(size: MetricSize) -> Self {
+        Self { thread: Some(size), ..Self::new(size.tap_drill_diameter()) }
+    }
+
+    /// Make this a blind hole of `depth`.
+    pub fn depth(mut self, depth: f64) -> Self {
+        self.depth = HoleDepth::Blind(depth);
+        self
+    }
+
+    /// The included angle of the drill point at the bottom of a blind hole.
+    /// An angle of 180 degrees gives a flat bottom.
+    pub fn point_angle(mut self, point_angle: Angle) -> Self {
+        self.point_angle = point_angle;
+        self
+    }
+
+    pub fn counterbore(mut self, diameter: f64, depth: f64) -> Self {
+        self.head = HoleHead::Counterbore { diameter, depth };
+        self
+    }
+
+    pub fn countersink(mut self, diameter: f64, angle: Angle) -> Self {
+        self.head = HoleHead::Countersink { diameter, angle };
+        self
+    }
+
+    /// Check that the head fits the hole.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        let head_depth = match self.head {
+            HoleHead::None => 0.0,
+            HoleHead::Counterbore { diameter, depth } => {
+                if diameter <= self.diameter {
+                    return Err(Error::InvalidHole(format!(
+                        "counterbore diameter {diameter} must be larger than the hole diameter {}",
+                        self.diameter
+                    )));
+                }
+
+                depth
+            },
+            HoleHead::Countersink { diameter, angle } => {
+                if diameter <= self.diameter {
+                    return Err(Error::InvalidHole(format!(
+                        "countersink diameter {diameter} must be larger than the hole diameter {}",
+                        self.diameter
+                    )));
+                }
+
+                (diameter - self.diameter) / 2.0 / (angle.radians() / 2.0).tan()
+            },
+        };
+
+        match self.depth {
+            HoleDepth::Blind(depth) if head_depth >= depth => Err(Error::InvalidHole(format!(
+                "head depth {head_depth} must be less than the hole depth {depth}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// The half profile of the hole as `(radius, depth)` pairs, starting and
+    /// ending on the hole axis.
+    ///
+    /// The profile starts a little above the entry point, so that the cutting
+    /// tool doesn't share a face with the shape being cut.
+    pub(crate) fn profile(&self, through_depth: f64) -> Vec<(f64, f64)> {
+        let radius = self.diameter / 2.0;
+        let entry = -ENTRY_CLEARANCE;
+        let mut profile = vec![(0.0, entry)];
+
+        match self.head {
+            HoleHead::None => profile.push((radius, entry)),
+            HoleHead::Counterbore { diameter, depth } => {
+                profile.push((diameter / 2.0, entry));
+                profile.push((diameter / 2.0, depth));
+                profile.push((radius, depth));
+            },
+            HoleHead::Countersink { diameter, angle } => {
+                let sink_radius = diameter / 2.0;
+                let sink_depth = (sink_radius - radius) / (angle.radians() / 2.0).tan();
+                profile.push((sink_radius, entry));
+                profile.push((sink_radius, 0.0));
+                profile.push((radius, sink_depth));
+            },
+        }
+
+        match self.depth {
+            HoleDepth::Through => {
+                profile.push((radius, through_depth));
+                profile.push((0.0, through_depth));
+            },
+            HoleDepth::Blind(depth) => {
+                profile.push((radius, depth));
+
+                let half_point_angle = self.point_angle.radians() / 2.0;
+                if half_point_angle < std::f64::consts::FRAC_PI_2 {
+                    profile.push((0.0, depth + radius / half_point_angle.tan()));
+                } else {
+                    profile.push((0.0, depth));
+                }
+            },
+        }
+
+        profile
+    }
+}
+
+/// A face of a [`HoleShape`] created by a hole, tagged with that hole.
+pub struct HoleFace {
+    pub face: Face,
+    pub hole: Hole,
+}
+
+/// The result of cutting a [`Hole`] into a shape.
+pub struct HoleShape {
+    pub shape: Shape,
+    /// The faces of `shape` which were created by the hole.
+    pub hole_faces: Vec<HoleFace>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tap_drill_sizes() {
+        assert_eq!(MetricSize::M3.tap_drill_diameter(), 2.5);
+        assert_eq!(MetricSize::M6.tap_drill_diameter(), 5.0);
+        assert!((MetricSize::M8.tap_drill_diameter() - 6.75).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn blind_hole_profile_ends_in_drill_point() {
+        let hole = Hole::new(2.0).depth(5.0).point_angle(90.degrees());
+        let profile = hole.profile(100.0);
+
+        let (tip_radius, tip_depth) = *profile.last().unwrap();
+        assert_eq!(tip_radius, 0.0);
+        assert!((tip_depth - 6.0).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn head_must_fit_the_hole() {
+        assert!(Hole::counterbored(MetricSize::M4).validate().is_ok());
+        assert!(Hole::countersunk(MetricSize::M4).validate().is_ok());
+
+        let narrow_countersink = Hole::new(5.0).countersink(4.0, 90.degrees());
+        assert!(matches!(narrow_countersink.validate(), Err(Error::InvalidHole(_))));
+
+        let deep_counterbore = Hole::new(4.0).depth(3.0).counterbore(8.0, 3.0);
+        assert!(matches!(deep_counterbore.validate(), Err(Error::InvalidHole(_))));
+    }
+}
@@ -2,10 +2,25 @@ use thiserror::Error;
 
 pub mod angle;
 pub mod bounding_box;
+pub mod curve2d;
+pub mod document;
+pub mod drawing;
+pub mod dxf;
+pub mod feature;
+pub mod geometry;
+pub mod gltf;
+pub mod hole;
 pub mod kicad;
+pub mod loft;
 pub mod mesh;
+pub mod mesh_export;
 pub mod primitives;
+pub mod projection;
 pub mod section;
+pub mod slice;
+pub mod step;
+pub mod svg;
+pub mod sweep;
 pub mod workplane;
 
 mod law_function;
@@ -15,10 +30,16 @@ mod make_pipe_shell;
 pub enum Error {
     #[error("failed to write STL file")]
     StlWriteFailed,
+    #[error("failed to read STL file")]
+    StlReadFailed,
     #[error("failed to read STEP file")]
     StepReadFailed,
     #[error("failed to read IGES file")]
     IgesReadFailed,
+    #[error("failed to read DXF file: {0}")]
+    DxfReadFailed(String),
+    #[error("failed to read SVG file: {0}")]
+    SvgReadFailed(String),
     #[error("failed to read KiCAD PCB file: {0}")]
     KicadReadFailed(#[from] kicad_parser::Error),
     #[error("failed to write STEP file")]
@@ -33,6 +54,52 @@ pub enum Error {
     TriangulationFailed,
     #[error("encountered a face with no triangulation")]
     UntriangulatedFace,
+    #[error("failed to convert mesh to a shape")]
+    MeshConversionFailed,
     #[error("at least 2 points are required for creating a wire")]
     NotEnoughPoints,
+    #[error("failed to build feature")]
+    FeatureFailed,
+    #[error("no face found to terminate the feature")]
+    FeatureLimitNotFound,
+    #[error("failed to sweep profile")]
+    SweepFailed,
+    #[error("failed to loft sections")]
+    LoftFailed,
+    #[error("failed to fill surface")]
+    FillingFailed,
+    #[error("failed to construct curve")]
+    CurveConstructionFailed,
+    #[error("failed to construct surface")]
+    SurfaceConstructionFailed,
+    #[error("a degree {degree} B-spline needs more than {degree} poles, got {pole_count}")]
+    NotEnoughPoles { pole_count: usize, degree: u32 },
+    #[error("failed to make a face from a wire")]
+    FaceConstructionFailed,
+    #[error("failed to sew faces into a shell")]
+    SewingFailed,
+    #[error("failed to make a solid from a shell")]
+    SolidConstructionFailed,
+    #[error("placement is not a rigid transformation")]
+    InvalidPlacement,
+    #[error("transform has non-uniform scaling or shearing")]
+    NonUniformTransform,
+    #[error("components can only be added to assemblies")]
+    InvalidAssembly,
+    #[error("invalid hole: {0}")]
+    InvalidHole(String),
+    #[error("invalid thread: {0}")]
+    InvalidThread(String),
+    #[error("failed to remove hidden lines")]
+    ProjectionFailed,
+    #[error("invalid dimension: {0}")]
+    InvalidDimension(String),
+    #[error("invalid slice: {0}")]
+    InvalidSlice(String),
+    #[error("a glTF scene needs at least one node")]
+    EmptyGltfScene,
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to write 3MF file: {0}")]
+    ThreeMfWriteFailed(#[from] zip::result::ZipError),
 }
@@ -0,0 +1,316 @@
+use crate::{
+    primitives::{Continuity, Shape, Vertex, Wire},
+    Error,
+};
+use opencascade_sys as ffi;
+
+/// How the parameters of the loft sections are distributed when approximating
+/// the lofted surface.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Parametrization {
+    ChordLength,
+    Centripetal,
+    IsoParametric,
+}
+
+impl From<Parametrization> for ffi::approx::Approx_ParametrizationType {
+    fn from(value: Parametrization) -> Self {
+        match value {
+            Parametrization::ChordLength => Self::Approx_ChordLength,
+            Parametrization::Centripetal => Self::Approx_Centripetal,
+            Parametrization::IsoParametric => Self::Approx_IsoParametric,
+        }
+    }
+}
+
+enum LoftSection<'a> {
+    Wire(&'a Wire),
+    Vertex(&'a Vertex),
+}
+
+impl LoftSection<'_> {
+    fn as_shape(&self) -> &ffi::topo_ds::TopoDS_Shape {
+        match self {
+            Self::Wire(wire) => ffi::topo_ds::cast_wire_to_shape(&wire.inner),
+            Self::Vertex(vertex) => ffi::topo_ds::cast_vertex_to_shape(&vertex.inner),
+        }
+    }
+}
+
+/// Builds a shape passing through a series of sections.
+///
+/// By default this wraps `BRepOffsetAPI_ThruSections`. When a spine is given with
+/// [`LoftBuilder::guide`], the sections are instead swept along the spine with
+/// `BRepOffsetAPI_MakePipeShell`, scaled so they stay in contact with the guide curve.
+pub struct LoftBuilder<'a> {
+    sections: Vec<LoftSection<'a>>,
+    solid: bool,
+    ruled: bool,
+    check_compatibility: bool,
+    smoothing: bool,
+    continuity: Option<Continuity>,
+    parametrization: Option<Parametrization>,
+    criterium_weight: Option<(f64, f64, f64)>,
+    max_degree: Option<i32>,
+    spine_and_guide: Option<(&'a Wire, &'a Wire)>,
+}
+
+impl Default for LoftBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> LoftBuilder<'a> {
+    pub fn new() -> Self {
+        Self {
+            sections: vec![],
+            solid: true,
+            ruled: false,
+            check_compatibility: true,
+            smoothing: false,
+            continuity: None,
+            parametrization: None,
+            criterium_weight: None,
+            max_degree: None,
+            spine_and_guide: None,
+        }
+    }
+
+    /// Add a wire section.
+    pub fn section(mut self, wire: &'a Wire) -> Self {
+        self.sections.push(LoftSection::Wire(wire));
+        self
+    }
+
+    /// Add several wire sections.
+    pub fn sections(mut self, wires: impl IntoIterator<Item = &'a Wire>) -> Self {
+        self.sections.extend(wires.into_iter().map(LoftSection::Wire));
+        self
+    }
+
+    /// Add a point section, which may only be the first or the last section.
+    /// Use this to close the loft in a pointed tip.
+    pub fn vertex(mut self, vertex: &'a Vertex) -> Self {
+        self.sections.push(LoftSection::Vertex(vertex));
+        self
+    }
+
+    /// Produce a solid rather than a shell. Enabled by default.
+    pub fn solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    /// Connect consecutive sections with ruled surfaces instead of one smooth surface.
+    pub fn ruled(mut self, ruled: bool) -> Self {
+        self.ruled = ruled;
+        self
+    }
+
+    /// Reorder and orient the section edges to avoid twisted results. Enabled by default.
+    pub fn check_compatibility(mut self, check_compatibility: bool) -> Self {
+        self.check_compatibility = check_compatibility;
+        self
+    }
+
+    /// Use smoothing in the approximation of the lofted surface.
+    pub fn smoothing(mut self, smoothing: bool) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn continuity(mut self, continuity: Continuity) -> Self {
+        self.continuity = Some(continuity);
+        self
+    }
+
+    pub fn parametrization(mut self, parametrization: Parametrization) -> Self {
+        self.parametrization = Some(parametrization);
+        self
+    }
+
+    /// Weights of the length, curvature and torsion criteria used when smoothing.
+    pub fn criterium_weight(mut self, w1: f64, w2: f64, w3: f64) -> Self {
+        self.criterium_weight = Some((w1, w2, w3));
+        self
+    }
+
+    pub fn max_degree(mut self, max_degree: i32) -> Self {
+        self.max_degree = Some(max_degree);
+        self
+    }
+
+    /// Sweep the sections along `spine`, scaling them to follow `guide`.
+    ///
+    /// The approximation options (ruled, smoothing, continuity, parametrization,
+    /// weights and max degree) don't apply to guided lofts.
+    pub fn guide(mut self, spine: &'a Wire, guide: &'a Wire) -> Self {
+        self.spine_and_guide = Some((spine, guide));
+        self
+    }
+
+    pub fn build(self) -> Result<Shape, Error> {
+        // A point may only cap either end of the loft. OCCT throws on one in the
+        // middle rather than failing, so reject it up front.
+        let last = self.sections.len().saturating_sub(1);
+        let has_inner_vertex =
+            self.sections.iter().enumerate().any(|(i, section)| {
+                matches!(section, LoftSection::Vertex(_)) && i != 0 && i != last
+            });
+
+        if has_inner_vertex {
+            return Err(Error::LoftFailed);
+        }
+
+        match self.spine_and_guide {
+            Some((spine, guide)) => self.build_guided(spine, guide),
+            None => self.build_thru_sections(),
+        }
+    }
+
+    fn build_thru_sections(self) -> Result<Shape, Error> {
+        if self.sections.len() < 2 {
+            return Err(Error::LoftFailed);
+        }
+
+        let precision = 1.0e-6;
+        let mut make_loft = ffi::b_rep_offset_api::BRepOffsetAPI_ThruSections_new_with_options(
+            self.solid, self.ruled, precision,
+        );
+
+        for section in &self.sections {
+            match section {
+                LoftSection::Wire(wire) => make_loft.pin_mut().AddWire(&wire.inner),
+                LoftSection::Vertex(vertex) => make_loft.pin_mut().AddVertex(&vertex.inner),
+            }
+        }
+
+        make_loft.pin_mut().CheckCompatibility(self.check_compatibility);
+        make_loft.pin_mut().SetSmoothing(self.smoothing);
+
+        if let Some(continuity) = self.continuity {
+            make_loft.pin_mut().SetContinuity(continuity.into());
+        }
+
+        if let Some(parametrization) = self.parametrization {
+            make_loft.pin_mut().SetParType(parametrization.into());
+        }
+
+        if let Some((w1, w2, w3)) = self.criterium_weight {
+            make_loft.pin_mut().SetCriteriumWeight(w1, w2, w3);
+        }
+
+        if let Some(max_degree) = self.max_degree {
+            make_loft.pin_mut().SetMaxDegree(max_degree);
+        }
+
+        make_loft.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_loft.IsDone() {
+            return Err(Error::LoftFailed);
+        }
+
+        Ok(Shape::from_shape(make_loft.pin_mut().Shape()))
+    }
+
+    fn build_guided(self, spine: &Wire, guide: &Wire) -> Result<Shape, Error> {
+        if self.sections.is_empty() {
+            return Err(Error::LoftFailed);
+        }
+
+        let mut make_pipe_shell =
+            ffi::b_rep_offset_api::BRepOffsetAPI_MakePipeShell_new(&spine.inner);
+
+        let curvilinear_equivalence = false;
+        make_pipe_shell.pin_mut().set_mode_auxiliary_spine(
+            &guide.inner,
+            curvilinear_equivalence,
+            ffi::b_rep_offset_api::BRepFill_TypeOfContact::BRepFill_Contact,
+        );
+
+        let with_contact = false;
+        let with_correction = false;
+        for section in &self.sections {
+            make_pipe_shell.pin_mut().Add(section.as_shape(), with_contact, with_correction);
+        }
+
+        make_pipe_shell.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_pipe_shell.IsDone() {
+            return Err(Error::LoftFailed);
+        }
+
+        if self.solid && !make_pipe_shell.pin_mut().MakeSolid() {
+            return Err(Error::LoftFailed);
+        }
+
+        Ok(Shape::from_shape(make_pipe_shell.pin_mut().Shape()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bounding_box, primitives::Edge, workplane::Workplane};
+    use glam::{dvec3, DVec3};
+
+    fn square(side: f64, z: f64) -> Wire {
+        Workplane::xy().translated(dvec3(0.0, 0.0, z)).rect(side, side)
+    }
+
+    #[test]
+    fn ruled_and_smooth_lofts() {
+        let (bottom, middle, top) = (square(2.0, 0.0), square(4.0, 5.0), square(2.0, 10.0));
+
+        let ruled =
+            LoftBuilder::new().sections([&bottom, &middle, &top]).ruled(true).build().unwrap();
+        let smooth = LoftBuilder::new().sections([&bottom, &middle, &top]).build().unwrap();
+
+        // Two stacked frustums, with four flat sides each and the two caps.
+        assert_eq!(ruled.faces().count(), 10);
+        let frustum_volume = 5.0 / 3.0 * (4.0 + 16.0 + 8.0);
+        assert!((ruled.volume() - 2.0 * frustum_volume).abs() < 1.0e-6);
+
+        // One curved surface per side bulges out past the flat sides.
+        assert_eq!(smooth.faces().count(), 6);
+        assert!(smooth.volume() > ruled.volume());
+    }
+
+    #[test]
+    fn loft_to_vertex_tip() {
+        let base = square(2.0, 0.0);
+        let tip = Vertex::new(dvec3(0.0, 0.0, 3.0));
+
+        let pyramid = LoftBuilder::new().section(&base).vertex(&tip).ruled(true).build().unwrap();
+
+        assert_eq!(pyramid.faces().count(), 5);
+        assert!((pyramid.volume() - 4.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn guided_loft_follows_guide() {
+        let spine = Wire::from_edges([&Edge::segment(DVec3::ZERO, dvec3(0.0, 0.0, 10.0))]);
+        let guide = Wire::from_edges([&Edge::segment(dvec3(1.0, 0.0, 0.0), dvec3(2.0, 0.0, 10.0))]);
+        let section = square(2.0, 0.0);
+
+        let shape = LoftBuilder::new().section(&section).guide(&spine, &guide).build().unwrap();
+
+        // The section grows to twice its size to stay in contact with the guide.
+        let bounds = bounding_box::aabb(&shape);
+        assert!(((bounds.max() - bounds.gap_vec()).x - 2.0).abs() < 0.05);
+        assert!(shape.volume() > 40.0);
+    }
+
+    #[test]
+    fn invalid_sections_fail() {
+        let (bottom, top) = (square(2.0, 0.0), square(2.0, 10.0));
+        let tip = Vertex::new(dvec3(0.0, 0.0, 5.0));
+
+        let single = LoftBuilder::new().section(&bottom).build();
+        assert!(matches!(single, Err(Error::LoftFailed)));
+
+        let inner_vertex = LoftBuilder::new().section(&bottom).vertex(&tip).section(&top).build();
+        assert!(matches!(inner_vertex, Err(Error::LoftFailed)));
+    }
+}
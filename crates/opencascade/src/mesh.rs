@@ -1,10 +1,12 @@
 use crate::{
-    primitives::{FaceOrientation, Shape},
+    angle::{Angle, ToAngle},
+    primitives::{Face, FaceOrientation, Shape},
     Error,
 };
 use cxx::UniquePtr;
 use glam::{dvec2, dvec3, DVec2, DVec3};
 use opencascade_sys as ffi;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Mesh {
@@ -12,6 +14,194 @@ pub struct Mesh {
     pub uvs: Vec<DVec2>,
     pub normals: Vec<DVec3>,
     pub indices: Vec<usize>,
+
+    /// The face each triangle was generated from, one entry per triangle.
+    /// Face IDs are in the order the faces are returned by [`Shape::faces`].
+    pub face_ids: Vec<usize>,
+
+    /// The discretization of each edge of the shape, as used by the triangles
+    /// along it.
+    pub edge_polylines: Vec<Vec<DVec3>>,
+}
+
+impl Mesh {
+    /// The triangles generated from the face with `face_id`, as indices into
+    /// `vertices`.
+    pub fn face_triangles(&self, face_id: usize) -> impl Iterator<Item = [usize; 3]> + '_ {
+        self.indices
+            .chunks_exact(3)
+            .zip(&self.face_ids)
+            .filter(move |(_, id)| **id == face_id)
+            .map(|(triangle, _)| [triangle[0], triangle[1], triangle[2]])
+    }
+
+    /// Merge vertices which are within `tolerance` of each other. Faces are
+    /// meshed separately, so this is needed to get a watertight mesh where
+    /// adjacent faces share the vertices along their common edges.
+    ///
+    /// The normals of merged vertices are averaged, and merged vertices keep the
+    /// UV coordinates of the first vertex merged into them. Triangles which
+    /// collapse to a line or a point are removed.
+    #[must_use]
+    pub fn welded(&self, tolerance: f64) -> Self {
+        let cell_size = tolerance.max(1.0e-12);
+        let cell_of = |point: DVec3| {
+            let cell = (point / cell_size).floor();
+            (cell.x as i64, cell.y as i64, cell.z as i64)
+        };
+
+        let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+        let mut remap = Vec::with_capacity(self.vertices.len());
+
+        let mut vertices: Vec<DVec3> = vec![];
+        let mut uvs = vec![];
+        let mut normals = vec![];
+
+        for (i, &vertex) in self.vertices.iter().enumerate() {
+            let (x, y, z) = cell_of(vertex);
+
+            let existing = (-1..=1)
+                .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+                .filter_map(|(dx, dy, dz)| grid.get(&(x + dx, y + dy, z + dz)))
+                .flatten()
+                .copied()
+                .find(|&j| vertices[j].distance(vertex) <= tolerance);
+
+            let index = existing.unwrap_or_else(|| {
+                let index = vertices.len();
+                vertices.push(vertex);
+                uvs.push(self.uvs.get(i).copied().unwrap_or(DVec2::ZERO));
+                normals.push(DVec3::ZERO);
+                grid.entry((x, y, z)).or_default().push(index);
+                index
+            });
+
+            if let Some(normal) = self.normals.get(i) {
+                normals[index] += *normal;
+            }
+
+            remap.push(index);
+        }
+
+        for normal in &mut normals {
+            *normal = normal.normalize_or_zero();
+        }
+
+        let mut indices = vec![];
+        let mut face_ids = vec![];
+
+        for (triangle, face_id) in self.indices.chunks_exact(3).zip(&self.face_ids) {
+            let [a, b, c] = [remap[triangle[0]], remap[triangle[1]], remap[triangle[2]]];
+
+            if a != b && b != c && a != c {
+                indices.extend([a, b, c]);
+                face_ids.push(*face_id);
+            }
+        }
+
+        Self {
+            vertices,
+            uvs,
+            normals,
+            indices,
+            face_ids,
+            edge_polylines: self.edge_polylines.clone(),
+        }
+    }
+}
+
+/// Controls how finely a shape is triangulated.
+#[derive(Debug, Copy, Clone)]
+pub struct MeshParameters {
+    /// The maximum distance between the mesh and the surface it approximates.
+    pub deflection: f64,
+
+    /// The maximum angle between the normals of adjacent mesh elements.
+    pub angular_deflection: Angle,
+
+    /// Interpret `deflection` as a fraction of the size of each edge rather than
+    /// as an absolute distance.
+    pub relative: bool,
+
+    /// The minimum size of mesh elements. Chosen automatically when `None`.
+    pub min_size: Option<f64>,
+
+    /// Insert vertices into the interior of faces, rather than only meshing
+    /// their boundaries.
+    pub internal_vertices: bool,
+
+    /// Mesh faces on multiple threads.
+    pub parallel: bool,
+}
+
+impl Default for MeshParameters {
+    fn default() -> Self {
+        Self {
+            deflection: 0.01,
+            angular_deflection: 0.5.radians(),
+            relative: false,
+            min_size: None,
+            internal_vertices: true,
+            parallel: false,
+        }
+    }
+}
+
+impl MeshParameters {
+    pub fn deflection(mut self, deflection: f64) -> Self {
+        self.deflection = deflection;
+        self
+    }
+
+    pub fn angular_deflection(mut self, angular_deflection: Angle) -> Self {
+        self.angular_deflection = angular_deflection;
+        self
+    }
+
+    pub fn relative(mut self, relative: bool) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    pub fn min_size(mut self, min_size: f64) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    pub fn internal_vertices(mut self, internal_vertices: bool) -> Self {
+        self.internal_vertices = internal_vertices;
+        self
+    }
+
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    fn to_ffi(self) -> UniquePtr<ffi::b_rep_mesh::IMeshTools_Parameters> {
+        let mut parameters = ffi::b_rep_mesh::IMeshTools_Parameters_new();
+
+        ffi::b_rep_mesh::IMeshTools_Parameters_set_deflection(
+            parameters.pin_mut(),
+            self.deflection,
+        );
+        ffi::b_rep_mesh::IMeshTools_Parameters_set_angle(
+            parameters.pin_mut(),
+            self.angular_deflection.radians(),
+        );
+        ffi::b_rep_mesh::IMeshTools_Parameters_set_relative(parameters.pin_mut(), self.relative);
+        ffi::b_rep_mesh::IMeshTools_Parameters_set_internal_vertices_mode(
+            parameters.pin_mut(),
+            self.internal_vertices,
+        );
+        ffi::b_rep_mesh::IMeshTools_Parameters_set_in_parallel(parameters.pin_mut(), self.parallel);
+
+        if let Some(min_size) = self.min_size {
+            ffi::b_rep_mesh::IMeshTools_Parameters_set_min_size(parameters.pin_mut(), min_size);
+        }
+
+        parameters
+    }
 }
 
 pub struct Mesher {
@@ -29,15 +219,95 @@ impl Mesher {
         }
     }
 
+    pub fn try_with_parameters(shape: &Shape, parameters: &MeshParameters) -> Result<Self, Error> {
+        let inner = ffi::b_rep_mesh::IncrementalMesh_new_with_parameters(
+            &shape.inner,
+            &parameters.to_ffi(),
+        );
+
+        if inner.IsDone() {
+            Ok(Self { inner })
+        } else {
+            Err(Error::TriangulationFailed)
+        }
+    }
+
+    /// Mesh `shape` with `parameters`, except for the given faces which use
+    /// their own parameters, finer or coarser.
+    ///
+    /// Existing triangulations of `shape` are only replaced by finer ones, so
+    /// mesh a fresh shape for coarser results.
+    pub fn try_with_face_overrides<'a>(
+        shape: &Shape,
+        parameters: &MeshParameters,
+        face_overrides: impl IntoIterator<Item = (&'a Face, MeshParameters)>,
+    ) -> Result<Self, Error> {
+        let face_overrides = face_overrides.into_iter().collect::<Vec<_>>();
+        let mut faces = shape
+            .faces()
+            .map(|face| {
+                let face_shape = ffi::topo_ds::cast_face_to_shape(&face.inner);
+                let face_parameters = face_overrides
+                    .iter()
+                    .find(|(other, _)| {
+                        face_shape.IsSame(ffi::topo_ds::cast_face_to_shape(&other.inner))
+                    })
+                    .map_or(*parameters, |(_, face_parameters)| *face_parameters);
+
+                (face, face_parameters)
+            })
+            .collect::<Vec<_>>();
+
+        // Mesh the faces one at a time, from the finest parameters to the
+        // coarsest. Each edge is discretized for the finest face next to it, and
+        // coarser faces reuse that, so the faces still line up with their neighbours.
+        // Parameters are ordered by linear deflection, then angular deflection.
+        // Relative and absolute deflections can't be compared, so absolute ones
+        // go first when the values are equal, as they're usually the finer.
+        faces.sort_by(|(_, a), (_, b)| {
+            a.deflection
+                .total_cmp(&b.deflection)
+                .then(a.angular_deflection.radians().total_cmp(&b.angular_deflection.radians()))
+                .then(a.relative.cmp(&b.relative))
+        });
+        for (face, face_parameters) in &faces {
+            let face_mesh = ffi::b_rep_mesh::IncrementalMesh_new_with_parameters(
+                ffi::topo_ds::cast_face_to_shape(&face.inner),
+                &face_parameters.to_ffi(),
+            );
+
+            if !face_mesh.IsDone() {
+                return Err(Error::TriangulationFailed);
+            }
+        }
+
+        // Every face is already meshed at least this finely, so this keeps them all.
+        let coarsest = faces.last().map_or(*parameters, |(_, face_parameters)| *face_parameters);
+        Self::try_with_parameters(shape, &coarsest)
+    }
+
     pub fn mesh(mut self) -> Result<Mesh, Error> {
         let mut vertices = vec![];
         let mut uvs = vec![];
         let mut normals = vec![];
         let mut indices = vec![];
+        let mut face_ids = vec![];
+        let mut edge_polylines = vec![];
 
         let triangulated_shape = Shape::from_shape(self.inner.pin_mut().Shape());
 
-        for face in triangulated_shape.faces() {
+        // Edges are shared between faces, so keep track of the ones which
+        // already have a polyline.
+        let mut edge_map = ffi::top_tools::new_indexed_data_map_of_shape_list_of_shape();
+        ffi::top_exp::TopExp::MapShapesAndAncestors(
+            &triangulated_shape.inner,
+            ffi::top_abs::TopAbs_ShapeEnum::TopAbs_EDGE,
+            ffi::top_abs::TopAbs_ShapeEnum::TopAbs_FACE,
+            edge_map.pin_mut(),
+        );
+        let mut visited_edges = vec![false; edge_map.Extent() as usize + 1];
+
+        for (face_id, face) in triangulated_shape.faces().enumerate() {
             let mut location = ffi::top_loc::Location_new();
 
             let triangulation_handle =
@@ -48,10 +318,11 @@ impl Mesher {
 
             let index_offset = vertices.len();
             let face_point_count = triangulation.NbNodes();
+            let transform = ffi::top_loc::TopLoc_Location_Transformation(&location);
 
             for i in 1..=face_point_count {
                 let mut point = ffi::poly::Poly_Triangulation_Node(triangulation, i);
-                point.pin_mut().Transform(&ffi::top_loc::TopLoc_Location_Transformation(&location));
+                point.pin_mut().Transform(&transform);
                 vertices.push(dvec3(point.X(), point.Y(), point.Z()));
             }
 
@@ -84,19 +355,22 @@ impl Mesher {
                 }
             }
 
-            // Add in the normals.
-            // TODO(bschwind) - Use `location` to transform the normals.
-            let normal_array = ffi::t_col_gp::TColgp_Array1OfDir_new(0, face_point_count);
-
+            // Add in the normals. They are computed from the surface in the local
+            // coordinates of the triangulation, so they need to be moved by the
+            // face location, and flipped when the face is reversed.
             ffi::b_rep_lib::BRepLib_ToolTriangulatedShape::ComputeNormals(
                 &face.inner,
                 &triangulation_handle,
             );
 
-            // TODO(bschwind) - Why do we start at 1 here?
-            for i in 1..(normal_array.Length() as usize) {
-                let normal = ffi::poly::Poly_Triangulation_Normal(triangulation, i as i32);
-                normals.push(dvec3(normal.X(), normal.Y(), normal.Z()));
+            let is_reversed = face.orientation() == FaceOrientation::Reversed;
+
+            for i in 1..=face_point_count {
+                let mut normal = ffi::poly::Poly_Triangulation_Normal(triangulation, i);
+                normal.pin_mut().Transform(&transform);
+
+                let normal = dvec3(normal.X(), normal.Y(), normal.Z());
+                normals.push(if is_reversed { -normal } else { normal });
             }
 
             for i in 1..=triangulation.NbTriangles() {
@@ -111,9 +385,170 @@ impl Mesher {
                     indices.push(index_offset + triangle.Value(2) as usize - 1);
                     indices.push(index_offset + triangle.Value(1) as usize - 1);
                 }
+
+                face_ids.push(face_id);
+            }
+
+            for edge in face.edges() {
+                let edge_index =
+                    edge_map.FindIndex(ffi::topo_ds::cast_edge_to_shape(&edge.inner)) as usize;
+
+                if std::mem::replace(&mut visited_edges[edge_index], true) {
+                    continue;
+                }
+
+                let polygon_handle = ffi::b_rep::BRep_Tool_PolygonOnTriangulation(
+                    &edge.inner,
+                    &triangulation_handle,
+                    &location,
+                );
+
+                let Ok(polygon) =
+                    ffi::poly::Handle_Poly_PolygonOnTriangulation_Get(&polygon_handle)
+                else {
+                    continue;
+                };
+
+                let polyline = (1..=polygon.NbNodes())
+                    .map(|i| vertices[index_offset + polygon.Node(i) as usize - 1])
+                    .collect();
+
+                edge_polylines.push(polyline);
+            }
+        }
+
+        Ok(Mesh { vertices, uvs, normals, indices, face_ids, edge_polylines })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Axis1,
+        primitives::{Compound, ShapeType},
+    };
+
+    #[test]
+    fn box_mesh_converts_to_solid() {
+        let mesh = Shape::box_centered(1.0, 2.0, 3.0).mesh().unwrap();
+        let shape = Shape::from_mesh(&mesh).unwrap();
+
+        assert_eq!(shape.shape_type(), ShapeType::Solid);
+        assert_eq!(shape.faces().count(), mesh.indices.len() / 3);
+        assert_eq!(shape.clean().faces().count(), 6);
+    }
+
+    #[test]
+    fn welding_box_mesh_shares_corner_vertices() {
+        let mesh = Shape::box_centered(1.0, 2.0, 3.0).mesh().unwrap();
+        let welded = mesh.welded(1.0e-6);
+
+        assert_eq!(welded.vertices.len(), 8);
+        assert_eq!(welded.indices.len(), mesh.indices.len());
+        assert_eq!(welded.face_ids, mesh.face_ids);
+        assert_eq!(mesh.edge_polylines.len(), 12);
+        assert_eq!((0..6).map(|face_id| mesh.face_triangles(face_id).count()).sum::<usize>(), 12);
+    }
+
+    #[test]
+    fn finer_parameters_add_triangles() {
+        let coarse = MeshParameters::default().deflection(0.1).angular_deflection(30.degrees());
+        let fine = coarse.deflection(0.001).angular_deflection(5.degrees());
+
+        let coarse_mesh = Shape::sphere(1.0).build().mesh_with_parameters(&coarse).unwrap();
+        let fine_mesh = Shape::sphere(1.0).build().mesh_with_parameters(&fine).unwrap();
+
+        assert!(fine_mesh.indices.len() > coarse_mesh.indices.len());
+    }
+
+    #[test]
+    fn coarser_face_override_has_fewer_triangles() {
+        let fine = MeshParameters::default().deflection(0.001).angular_deflection(5.degrees());
+        let coarse = fine.deflection(0.1).angular_deflection(30.degrees());
+
+        let fine_mesh = Shape::sphere(1.0).build().mesh_with_parameters(&fine).unwrap();
+
+        let sphere = Shape::sphere(1.0).build();
+        let face = sphere.faces().next().unwrap();
+        let mesh = Mesher::try_with_face_overrides(&sphere, &fine, [(&face, coarse)])
+            .unwrap()
+            .mesh()
+            .unwrap();
+
+        assert!(mesh.indices.len() < fine_mesh.indices.len());
+    }
+
+    #[test]
+    fn face_overrides_differing_only_in_angular_deflection() {
+        let coarse = MeshParameters::default().deflection(0.5).angular_deflection(30.degrees());
+        let fine = coarse.angular_deflection(5.degrees());
+
+        // Two separate spheres, so the triangles of each face only depend on
+        // its own parameters.
+        let spheres: Shape = Compound::from_shapes([
+            Shape::sphere(1.0).build(),
+            Shape::sphere(1.0).at(DVec3::X * 3.0).build(),
+        ])
+        .into();
+        let first = spheres.faces().next().unwrap();
+
+        for (parameters, face_parameters) in [(coarse, fine), (fine, coarse)] {
+            let mesh =
+                Mesher::try_with_face_overrides(&spheres, &parameters, [(&first, face_parameters)])
+                    .unwrap()
+                    .mesh()
+                    .unwrap();
+
+            let first_count = mesh.face_triangles(0).count();
+            let second_count = mesh.face_triangles(1).count();
+
+            if face_parameters.angular_deflection.radians()
+                < parameters.angular_deflection.radians()
+            {
+                assert!(first_count > second_count);
+            } else {
+                assert!(first_count < second_count);
             }
         }
+    }
+
+    #[test]
+    fn sphere_normals_point_outwards() {
+        let mesh = Shape::sphere(2.0).build().mesh().unwrap();
+
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+
+        for (vertex, normal) in mesh.vertices.iter().zip(&mesh.normals) {
+            assert!(normal.dot(vertex.normalize()) > 0.99);
+        }
+    }
 
-        Ok(Mesh { vertices, uvs, normals, indices })
+    #[test]
+    fn rotated_box_normals_match_triangles() {
+        let mut shape = Shape::box_centered(1.0, 2.0, 3.0);
+
+        let mut rotation = ffi::gp::new_transform();
+        rotation.pin_mut().SetRotation(&Axis1::new(DVec3::ZERO, DVec3::ONE).to_gp_ax1(), 1.0);
+        let location = ffi::top_loc::Location_from_transform(&rotation);
+        shape.inner.pin_mut().translate(&location, false);
+
+        let mesh = shape.mesh().unwrap();
+
+        assert_eq!(mesh.normals.len(), mesh.vertices.len());
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]];
+            let triangle_normal =
+                (mesh.vertices[b] - mesh.vertices[a]).cross(mesh.vertices[c] - mesh.vertices[a]);
+
+            // Points on the box surface are further from the center in the
+            // direction of their normal.
+            assert!(triangle_normal.dot(mesh.vertices[a]) > 0.0);
+
+            for vertex in [a, b, c] {
+                assert!(mesh.normals[vertex].dot(triangle_normal.normalize()) > 0.999);
+            }
+        }
     }
 }
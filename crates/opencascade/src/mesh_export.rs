@@ -0,0 +1,471 @@
+use crate::{mesh::Mesh, Error};
+use glam::{dvec3, DVec3};
+use std::{
+    fmt::Write as _,
+    io::{Read, Seek, Write},
+};
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+impl Mesh {
+    /// Write the mesh in the Wavefront OBJ format, including normals and UV
+    /// coordinates. The triangles of each face are put in their own group.
+    pub fn write_obj(&self, mut writer: impl Write) -> Result<(), Error> {
+        let has_normals = self.normals.len() == self.vertices.len();
+        let has_uvs = self.uvs.len() == self.vertices.len();
+
+        writeln!(writer, "# generated by opencascade-rs")?;
+
+        for v in &self.vertices {
+            writeln!(writer, "v {} {} {}", v.x, v.y, v.z)?;
+        }
+
+        if has_uvs {
+            for uv in &self.uvs {
+                writeln!(writer, "vt {} {}", uv.x, uv.y)?;
+            }
+        }
+
+        if has_normals {
+            for n in &self.normals {
+                writeln!(writer, "vn {} {} {}", n.x, n.y, n.z)?;
+            }
+        }
+
+        let mut current_face_id = None;
+
+        for (i, triangle) in self.indices.chunks_exact(3).enumerate() {
+            if let Some(&face_id) = self.face_ids.get(i) {
+                if current_face_id != Some(face_id) {
+                    writeln!(writer, "g face_{face_id}")?;
+                    current_face_id = Some(face_id);
+                }
+            }
+
+            write!(writer, "f")?;
+            for index in triangle {
+                // OBJ indices start at 1.
+                let index = index + 1;
+                match (has_uvs, has_normals) {
+                    (true, true) => write!(writer, " {index}/{index}/{index}")?,
+                    (true, false) => write!(writer, " {index}/{index}")?,
+                    (false, true) => write!(writer, " {index}//{index}")?,
+                    (false, false) => write!(writer, " {index}")?,
+                }
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the mesh in the binary little endian PLY format, including normals.
+    pub fn write_ply(&self, mut writer: impl Write) -> Result<(), Error> {
+        let has_normals = self.normals.len() == self.vertices.len();
+
+        let mut header = String::new();
+        writeln!(header, "ply").unwrap();
+        writeln!(header, "format binary_little_endian 1.0").unwrap();
+        writeln!(header, "comment generated by opencascade-rs").unwrap();
+        writeln!(header, "element vertex {}", self.vertices.len()).unwrap();
+        for property in ["x", "y", "z"] {
+            writeln!(header, "property float {property}").unwrap();
+        }
+        if has_normals {
+            for property in ["nx", "ny", "nz"] {
+                writeln!(header, "property float {property}").unwrap();
+            }
+        }
+        writeln!(header, "element face {}", self.indices.len() / 3).unwrap();
+        writeln!(header, "property list uchar uint vertex_indices").unwrap();
+        writeln!(header, "end_header").unwrap();
+
+        writer.write_all(header.as_bytes())?;
+
+        for (i, v) in self.vertices.iter().enumerate() {
+            let mut values = vec![v.x, v.y, v.z];
+            if has_normals {
+                let n = self.normals[i];
+                values.extend([n.x, n.y, n.z]);
+            }
+
+            for value in values {
+                writer.write_all(&(value as f32).to_le_bytes())?;
+            }
+        }
+
+        for triangle in self.indices.chunks_exact(3) {
+            writer.write_all(&[3])?;
+            for index in triangle {
+                writer.write_all(&(*index as u32).to_le_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the mesh in the binary STL format.
+    pub fn write_stl(&self, mut writer: impl Write) -> Result<(), Error> {
+        let mut header = [0u8; 80];
+        let comment = b"generated by opencascade-rs";
+        header[..comment.len()].copy_from_slice(comment);
+
+        writer.write_all(&header)?;
+        writer.write_all(&((self.indices.len() / 3) as u32).to_le_bytes())?;
+
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|i| self.vertices[i]);
+            let normal = (b - a).cross(c - a).normalize_or_zero();
+
+            for v in [normal, a, b, c] {
+                for value in [v.x, v.y, v.z] {
+                    writer.write_all(&(value as f32).to_le_bytes())?;
+                }
+            }
+
+            // Attribute byte count
+            writer.write_all(&[0, 0])?;
+        }
+
+        Ok(())
+    }
+
+    /// Read an ASCII or binary STL file. Every triangle gets its own vertices,
+    /// use [`Mesh::welded`] to merge them.
+    pub fn read_stl(mut reader: impl Read) -> Result<Self, Error> {
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        let triangles =
+            if is_binary_stl(&data) { read_binary_stl(&data) } else { read_ascii_stl(&data)? };
+
+        let mut mesh = Mesh {
+            vertices: Vec::with_capacity(triangles.len() * 3),
+            uvs: vec![],
+            normals: Vec::with_capacity(triangles.len() * 3),
+            indices: (0..triangles.len() * 3).collect(),
+            face_ids: vec![0; triangles.len()],
+            edge_polylines: vec![],
+        };
+
+        for [normal, a, b, c] in triangles {
+            let normal = if normal == DVec3::ZERO {
+                (b - a).cross(c - a).normalize_or_zero()
+            } else {
+                normal
+            };
+
+            mesh.vertices.extend([a, b, c]);
+            mesh.normals.extend([normal; 3]);
+        }
+
+        Ok(mesh)
+    }
+}
+
+fn is_binary_stl(data: &[u8]) -> bool {
+    // ASCII files start with "solid", but so do some binary files, so check the
+    // size against the triangle count as well.
+    match data.get(80..84) {
+        Some(count) => {
+            let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+            data.len() == 84 + count * 50 || !data.starts_with(b"solid")
+        },
+        None => false,
+    }
+}
+
+fn read_binary_stl(data: &[u8]) -> Vec<[DVec3; 4]> {
+    let read_vec = |bytes: &[u8]| {
+        let value = |i: usize| f32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        dvec3(value(0) as f64, value(1) as f64, value(2) as f64)
+    };
+
+    data[84..]
+        .chunks_exact(50)
+        .map(|triangle| {
+            [
+                read_vec(&triangle[0..12]),
+                read_vec(&triangle[12..24]),
+                read_vec(&triangle[24..36]),
+                read_vec(&triangle[36..48]),
+            ]
+        })
+        .collect()
+}
+
+fn read_ascii_stl(data: &[u8]) -> Result<Vec<[DVec3; 4]>, Error> {
+    let text = std::str::from_utf8(data).map_err(|_| Error::StlReadFailed)?;
+
+    let mut triangles = vec![];
+    let mut current = vec![];
+
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+
+        let keyword = match tokens.next() {
+            Some(keyword @ ("facet" | "vertex")) => keyword,
+            Some("endfacet") => {
+                let triangle: [DVec3; 4] =
+                    current.as_slice().try_into().map_err(|_| Error::StlReadFailed)?;
+                triangles.push(triangle);
+                current.clear();
+                continue;
+            },
+            _ => continue,
+        };
+
+        if keyword == "facet" && tokens.next() != Some("normal") {
+            return Err(Error::StlReadFailed);
+        }
+
+        let values = tokens
+            .map(|token| token.parse::<f64>().map_err(|_| Error::StlReadFailed))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values.as_slice() {
+            &[x, y, z] => current.push(dvec3(x, y, z)),
+            _ => return Err(Error::StlReadFailed),
+        }
+    }
+
+    Ok(triangles)
+}
+
+/// The unit of the coordinates in a 3MF file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ThreeMfUnit {
+    Micron,
+    #[default]
+    Millimeter,
+    Centimeter,
+    Inch,
+    Foot,
+    Meter,
+}
+
+impl ThreeMfUnit {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Micron => "micron",
+            Self::Millimeter => "millimeter",
+            Self::Centimeter => "centimeter",
+            Self::Inch => "inch",
+            Self::Foot => "foot",
+            Self::Meter => "meter",
+        }
+    }
+}
+
+/// Writes meshes as separate objects of a 3MF package.
+///
+/// 3MF requires each object to be a closed, manifold mesh, so meshes should be
+/// welded with [`Mesh::welded`] before they are added.
+pub struct ThreeMfWriter<'a> {
+    unit: ThreeMfUnit,
+    objects: Vec<(String, &'a Mesh)>,
+}
+
+impl Default for ThreeMfWriter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ThreeMfWriter<'a> {
+    pub fn new() -> Self {
+        Self { unit: ThreeMfUnit::default(), objects: vec![] }
+    }
+
+    pub fn unit(mut self, unit: ThreeMfUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn object(mut self, name: impl Into<String>, mesh: &'a Mesh) -> Self {
+        self.objects.push((name.into(), mesh));
+        self
+    }
+
+    pub fn write(&self, writer: impl Write + Seek) -> Result<(), Error> {
+        let mut zip = ZipWriter::new(writer);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        zip.start_file("[Content_Types].xml", options)?;
+        zip.write_all(CONTENT_TYPES.as_bytes())?;
+
+        zip.start_file("_rels/.rels", options)?;
+        zip.write_all(RELATIONSHIPS.as_bytes())?;
+
+        zip.start_file("3D/3dmodel.model", options)?;
+        zip.write_all(self.model().as_bytes())?;
+
+        zip.finish()?;
+
+        Ok(())
+    }
+
+    fn model(&self) -> String {
+        let mut model = String::new();
+
+        writeln!(model, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+        writeln!(
+            model,
+            r#"<model unit="{}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">"#,
+            self.unit.as_str()
+        )
+        .unwrap();
+        writeln!(model, r#"<metadata name="Application">opencascade-rs</metadata>"#).unwrap();
+        writeln!(model, "<resources>").unwrap();
+
+        for (i, (name, mesh)) in self.objects.iter().enumerate() {
+            writeln!(model, r#"<object id="{}" name="{}" type="model">"#, i + 1, xml_escape(name))
+                .unwrap();
+            writeln!(model, "<mesh>").unwrap();
+
+            writeln!(model, "<vertices>").unwrap();
+            for v in &mesh.vertices {
+                writeln!(model, r#"<vertex x="{}" y="{}" z="{}"/>"#, v.x, v.y, v.z).unwrap();
+            }
+            writeln!(model, "</vertices>").unwrap();
+
+            writeln!(model, "<triangles>").unwrap();
+            for triangle in mesh.indices.chunks_exact(3) {
+                writeln!(
+                    model,
+                    r#"<triangle v1="{}" v2="{}" v3="{}"/>"#,
+                    triangle[0], triangle[1], triangle[2]
+                )
+                .unwrap();
+            }
+            writeln!(model, "</triangles>").unwrap();
+
+            writeln!(model, "</mesh>").unwrap();
+            writeln!(model, "</object>").unwrap();
+        }
+
+        writeln!(model, "</resources>").unwrap();
+        writeln!(model, "<build>").unwrap();
+        for i in 0..self.objects.len() {
+            writeln!(model, r#"<item objectid="{}"/>"#, i + 1).unwrap();
+        }
+        writeln!(model, "</build>").unwrap();
+        writeln!(model, "</model>").unwrap();
+
+        model
+    }
+}
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+pub(crate) fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::{dvec2, dvec3};
+
+    fn triangle() -> Mesh {
+        Mesh {
+            vertices: vec![dvec3(0.0, 0.0, 0.0), dvec3(1.0, 0.0, 0.0), dvec3(0.0, 1.0, 0.0)],
+            uvs: vec![dvec2(0.0, 0.0), dvec2(1.0, 0.0), dvec2(0.0, 1.0)],
+            normals: vec![glam::DVec3::Z; 3],
+            indices: vec![0, 1, 2],
+            face_ids: vec![0],
+            edge_polylines: vec![],
+        }
+    }
+
+    #[test]
+    fn obj_faces_reference_uvs_and_normals() {
+        let mut obj = vec![];
+        triangle().write_obj(&mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+
+        assert!(obj.contains("g face_0\nf 1/1/1 2/2/2 3/3/3\n"));
+    }
+
+    #[test]
+    fn ply_binary_size() {
+        let mut ply = vec![];
+        triangle().write_ply(&mut ply).unwrap();
+
+        let header_end = b"end_header\n";
+        let body_start =
+            ply.windows(header_end.len()).position(|w| w == header_end).unwrap() + header_end.len();
+
+        // 3 vertices with 6 floats each, and 1 face with a count and 3 indices.
+        assert_eq!(ply.len() - body_start, 3 * 6 * 4 + (1 + 3 * 4));
+    }
+
+    #[test]
+    fn stl_round_trip() {
+        let mut stl = vec![];
+        triangle().write_stl(&mut stl).unwrap();
+        assert_eq!(stl.len(), 84 + 50);
+
+        let mesh = Mesh::read_stl(stl.as_slice()).unwrap();
+        assert_eq!(mesh.vertices, triangle().vertices);
+        assert_eq!(mesh.normals, triangle().normals);
+
+        let ascii = "solid test
+facet normal 0 0 1
+  outer loop
+    vertex 0 0 0
+    vertex 1 0 0
+    vertex 0 1 0
+  endloop
+endfacet
+endsolid test
+";
+        let mesh = Mesh::read_stl(ascii.as_bytes()).unwrap();
+        assert_eq!(mesh.vertices, triangle().vertices);
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn three_mf_with_two_objects() {
+        let (first, second) = (triangle(), triangle());
+        let mut package = std::io::Cursor::new(vec![]);
+        ThreeMfWriter::new()
+            .unit(ThreeMfUnit::Inch)
+            .object("first", &first)
+            .object("second & more", &second)
+            .write(&mut package)
+            .unwrap();
+
+        let mut archive = zip::ZipArchive::new(package).unwrap();
+        let mut model = String::new();
+        archive.by_name("3D/3dmodel.model").unwrap().read_to_string(&mut model).unwrap();
+
+        assert!(model.contains(r#"<model unit="inch""#));
+        assert_eq!(model.matches("<object ").count(), 2);
+        assert!(model.contains(r#"name="second &amp; more""#));
+        assert_eq!(model.matches("<item objectid=").count(), 2);
+    }
+}
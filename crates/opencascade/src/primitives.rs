@@ -97,18 +97,14 @@ pub fn make_point2d(p: DVec2) -> UniquePtr<ffi::gp::gp_Pnt2d> {
     ffi::gp::new_point_2d(p.x, p.y)
 }
 
-fn make_dir(p: DVec3) -> UniquePtr<ffi::gp::gp_Dir> {
+pub(crate) fn make_dir(p: DVec3) -> UniquePtr<ffi::gp::gp_Dir> {
     ffi::gp::gp_Dir_new(p.x, p.y, p.z)
 }
 
-fn make_vec(vec: DVec3) -> UniquePtr<ffi::gp::gp_Vec> {
+pub(crate) fn make_vec(vec: DVec3) -> UniquePtr<ffi::gp::gp_Vec> {
     ffi::gp::new_vec(vec.x, vec.y, vec.z)
 }
 
-fn make_axis_1(origin: DVec3, dir: DVec3) -> UniquePtr<ffi::gp::gp_Ax1> {
-    ffi::gp::gp_Ax1_new(&make_point(origin), &make_dir(dir))
-}
-
 pub fn make_axis_2(origin: DVec3, dir: DVec3) -> UniquePtr<ffi::gp::gp_Ax2> {
     ffi::gp::gp_Ax2_new(&make_point(origin), &make_dir(dir))
 }
@@ -267,3 +263,29 @@ impl From<JoinType> for ffi::geom_abs::GeomAbs_JoinType {
         }
     }
 }
+
+/// Geometric or parametric continuity between curves or surfaces.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Continuity {
+    C0,
+    G1,
+    C1,
+    G2,
+    C2,
+    C3,
+    CN,
+}
+
+impl From<Continuity> for ffi::geom_abs::GeomAbs_Shape {
+    fn from(value: Continuity) -> Self {
+        match value {
+            Continuity::C0 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_C0,
+            Continuity::G1 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_G1,
+            Continuity::C1 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_C1,
+            Continuity::G2 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_G2,
+            Continuity::C2 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_C2,
+            Continuity::C3 => ffi::geom_abs::GeomAbs_Shape::GeomAbs_C3,
+            Continuity::CN => ffi::geom_abs::GeomAbs_Shape::GeomAbs_CN,
+        }
+    }
+}
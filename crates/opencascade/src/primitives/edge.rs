@@ -1,5 +1,9 @@
 use super::make_vec;
-use crate::primitives::{make_axis_2, make_point};
+use crate::{
+    geometry::{CoordinateSystem, Transform},
+    primitives::make_point,
+    Error,
+};
 use cxx::UniquePtr;
 use glam::{dvec3, DVec3};
 use opencascade_sys as ffi;
@@ -36,6 +40,41 @@ impl From<ffi::geom_abs::GeomAbs_CurveType> for EdgeType {
     }
 }
 
+/// The geometry of a circle or circular arc edge.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CircleGeometry {
+    pub center: DVec3,
+    pub normal: DVec3,
+    pub radius: f64,
+}
+
+/// The geometry of a B-spline or Bezier curve edge, trimmed to the edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSplineGeometry {
+    pub degree: usize,
+    pub poles: Vec<DVec3>,
+    /// One weight per pole, all equal to 1.0 for non-rational curves.
+    pub weights: Vec<f64>,
+    /// The distinct knot values, each repeated `multiplicities[i]` times.
+    pub knots: Vec<f64>,
+    pub multiplicities: Vec<usize>,
+}
+
+impl BSplineGeometry {
+    pub fn is_rational(&self) -> bool {
+        self.weights.iter().any(|weight| (weight - self.weights[0]).abs() > 1.0e-12)
+    }
+
+    /// The knot vector with every knot repeated by its multiplicity.
+    pub fn flat_knots(&self) -> Vec<f64> {
+        self.knots
+            .iter()
+            .zip(&self.multiplicities)
+            .flat_map(|(&knot, &multiplicity)| std::iter::repeat_n(knot, multiplicity))
+            .collect()
+    }
+}
+
 pub struct Edge {
     pub(crate) inner: UniquePtr<ffi::topo_ds::TopoDS_Edge>,
 }
@@ -87,7 +126,13 @@ impl Edge {
     }
 
     pub fn circle(center: DVec3, normal: DVec3, radius: f64) -> Self {
-        let axis = make_axis_2(center, normal);
+        Self::circle_in(&CoordinateSystem::from_normal(center, normal), radius)
+    }
+
+    /// Make a circle around the origin of `coordinate_system`, in its XY plane,
+    /// starting on its X axis.
+    pub fn circle_in(coordinate_system: &CoordinateSystem, radius: f64) -> Self {
+        let axis = coordinate_system.to_gp_ax2();
 
         let make_circle = ffi::gp::gp_Circ_new(&axis, radius);
         let make_edge = ffi::b_rep_builder_api::BRepBuilderAPI_MakeEdge_circle(&make_circle);
@@ -128,6 +173,49 @@ impl Edge {
         Self::from_edge(edge)
     }
 
+    /// A B-spline or NURBS curve, such as one returned by [`Edge::bspline_geometry`].
+    pub fn bspline(geometry: &BSplineGeometry) -> Result<Self, Error> {
+        let mut poles = ffi::t_col_gp::TColgp_HArray1OfPnt_new(1, geometry.poles.len() as i32);
+        for (index, pole) in geometry.poles.iter().enumerate() {
+            poles.pin_mut().SetValue(index as i32 + 1, &make_point(*pole));
+        }
+
+        let mut weights =
+            ffi::t_col_std::TColStd_Array1OfReal_new(1, geometry.weights.len() as i32);
+        for (index, weight) in geometry.weights.iter().enumerate() {
+            weights.pin_mut().SetValue(index as i32 + 1, weight);
+        }
+
+        let mut knots = ffi::t_col_std::TColStd_Array1OfReal_new(1, geometry.knots.len() as i32);
+        let mut multiplicities =
+            ffi::t_col_std::TColStd_Array1OfInteger_new(1, geometry.multiplicities.len() as i32);
+        for (index, (knot, &multiplicity)) in
+            geometry.knots.iter().zip(&geometry.multiplicities).enumerate()
+        {
+            knots.pin_mut().SetValue(index as i32 + 1, knot);
+            multiplicities.pin_mut().SetValue(index as i32 + 1, &(multiplicity as i32));
+        }
+
+        let bspline_handle = ffi::geom::Geom_BSplineCurve_new(
+            &poles,
+            &weights,
+            &knots,
+            &multiplicities,
+            geometry.degree as i32,
+        )
+        .map_err(|_| Error::CurveConstructionFailed)?;
+        let curve_handle =
+            ffi::geom::new_HandleGeomCurve_from_HandleGeom_BSplineCurve(&bspline_handle);
+
+        let make_edge =
+            ffi::b_rep_builder_api::BRepBuilderAPI_MakeEdge_HandleGeomCurve(&curve_handle);
+        if !make_edge.IsDone() {
+            return Err(Error::CurveConstructionFailed);
+        }
+
+        Ok(Self::from_make_edge(make_edge))
+    }
+
     pub fn arc(p1: DVec3, p2: DVec3, p3: DVec3) -> Self {
         let make_arc = ffi::gc::GC_MakeArcOfCircle_point_point_point(
             &make_point(p1),
@@ -169,11 +257,85 @@ impl Edge {
 
     pub fn tangent_arc(_p1: DVec3, _tangent: DVec3, _p3: DVec3) {}
 
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let shape = transform.apply(ffi::topo_ds::cast_edge_to_shape(&self.inner));
+
+        Self::from_edge(ffi::topo_ds::TopoDS::Edge(&shape.inner))
+    }
+
     pub fn edge_type(&self) -> EdgeType {
         let curve = ffi::b_rep_adaptor::BRepAdaptor_Curve_new(&self.inner);
 
         EdgeType::from(curve.GetType())
     }
+
+    /// The first and last parameter of the underlying curve.
+    pub fn parameter_range(&self) -> (f64, f64) {
+        let curve = ffi::b_rep_adaptor::BRepAdaptor_Curve_new(&self.inner);
+
+        (curve.FirstParameter(), curve.LastParameter())
+    }
+
+    pub fn point_at(&self, parameter: f64) -> DVec3 {
+        let curve = ffi::b_rep_adaptor::BRepAdaptor_Curve_new(&self.inner);
+        let point = ffi::b_rep_adaptor::BRepAdaptor_Curve_value(&curve, parameter);
+
+        dvec3(point.X(), point.Y(), point.Z())
+    }
+
+    /// The circle this edge lies on, if it's a [`EdgeType::Circle`] edge.
+    pub fn circle_geometry(&self) -> Option<CircleGeometry> {
+        let curve = ffi::b_rep_adaptor::BRepAdaptor_Curve_new(&self.inner);
+        if EdgeType::from(curve.GetType()) != EdgeType::Circle {
+            return None;
+        }
+
+        let circle = ffi::b_rep_adaptor::BRepAdaptor_Curve_circle(&curve).ok()?;
+        let center = ffi::gp::gp_Circ_Location(&circle);
+        let normal = ffi::gp::gp_Circ_Normal(&circle);
+
+        Some(CircleGeometry {
+            center: dvec3(center.X(), center.Y(), center.Z()),
+            normal: dvec3(normal.X(), normal.Y(), normal.Z()),
+            radius: circle.Radius(),
+        })
+    }
+
+    /// The B-spline representation of a [`EdgeType::BSplineCurve`] or
+    /// [`EdgeType::BezierCurve`] edge. Periodic curves are converted to
+    /// non-periodic ones.
+    pub fn bspline_geometry(&self) -> Option<BSplineGeometry> {
+        let curve = ffi::b_rep_adaptor::BRepAdaptor_Curve_new(&self.inner);
+        if !matches!(
+            EdgeType::from(curve.GetType()),
+            EdgeType::BSplineCurve | EdgeType::BezierCurve
+        ) {
+            return None;
+        }
+
+        let mut poles = vec![];
+        let mut weights = vec![];
+        let mut knots = vec![];
+        let mut multiplicities = vec![];
+
+        let degree = ffi::b_rep_adaptor::BRepAdaptor_Curve_bspline_data(
+            &curve,
+            &mut poles,
+            &mut weights,
+            &mut knots,
+            &mut multiplicities,
+        )
+        .ok()?;
+
+        Some(BSplineGeometry {
+            degree: degree as usize,
+            poles: poles.chunks_exact(3).map(|p| dvec3(p[0], p[1], p[2])).collect(),
+            weights,
+            knots,
+            multiplicities: multiplicities.into_iter().map(|m| m as usize).collect(),
+        })
+    }
 }
 
 pub struct ApproximationSegmentIterator {
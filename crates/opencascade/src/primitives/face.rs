@@ -1,15 +1,25 @@
 use crate::{
     angle::Angle,
+    bounding_box,
+    geometry::{Axis1, Transform},
     law_function::law_function_from_graph,
     make_pipe_shell::make_pipe_shell_with_law_function,
     primitives::{
-        make_axis_1, make_point, make_vec, EdgeIterator, JoinType, Shape, Solid, Surface, Wire,
+        make_point, make_vec, Continuity, Edge, EdgeConnection, EdgeIterator, JoinType, Shape,
+        Solid, Surface, Wire,
     },
+    svg,
     workplane::Workplane,
+    Error,
 };
 use cxx::UniquePtr;
 use glam::{dvec3, DVec3};
 use opencascade_sys as ffi;
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
 
 pub struct Face {
     pub(crate) inner: UniquePtr<ffi::topo_ds::TopoDS_Face>,
@@ -42,6 +52,70 @@ impl Face {
         Self::from_make_face(make_face)
     }
 
+    /// A planar face bounded by `outer`, with a hole cut out for each of `holes`.
+    /// Fails if `outer` isn't a closed planar wire.
+    pub fn from_wire_with_holes<'a>(
+        outer: &Wire,
+        holes: impl IntoIterator<Item = &'a Wire>,
+    ) -> Result<Self, Error> {
+        if !ffi::b_rep::BRep_Tool::IsClosed(ffi::topo_ds::cast_wire_to_shape(&outer.inner)) {
+            return Err(Error::FaceConstructionFailed);
+        }
+
+        let only_plane = true;
+        let mut make_face =
+            ffi::b_rep_builder_api::BRepBuilderAPI_MakeFace_wire(&outer.inner, only_plane);
+
+        if !make_face.IsDone() {
+            return Err(Error::FaceConstructionFailed);
+        }
+
+        for hole in holes {
+            make_face.pin_mut().add_wire(&hole.inner);
+        }
+
+        if !make_face.IsDone() {
+            return Err(Error::FaceConstructionFailed);
+        }
+
+        // The holes have to be oriented against the outer wire.
+        let inner = ffi::shape_fix::ShapeFix_Face_fixed(make_face.Face());
+
+        Ok(Self { inner })
+    }
+
+    /// Read the outlines in a layer of an SVG file, in the XY plane. The largest
+    /// closed outline bounds the face, and the others are cut out of it as holes.
+    /// See [`svg::read_svg`] for the supported elements.
+    pub fn from_svg(path: impl AsRef<Path>, layer: &str) -> Result<Self, Error> {
+        Self::read_svg(BufReader::new(File::open(path)?), layer)
+    }
+
+    fn read_svg(reader: impl Read, layer: &str) -> Result<Self, Error> {
+        let curves = svg::read_svg(reader, layer)?;
+
+        let plane = Workplane::xy();
+        let mut edges = vec![];
+        for curve in &curves {
+            edges.extend(curve.to_edges(&plane)?);
+        }
+
+        let mut wires = Wire::connect_edges(edges, EdgeConnection::default());
+        let area = |wire: &Wire| {
+            let bounds = bounding_box::aabb(&Shape::from(wire));
+            let size = bounds.max() - bounds.min() - 2.0 * bounds.gap_vec();
+            size.x * size.y
+        };
+
+        let outer =
+            (0..wires.len())
+                .max_by(|&a, &b| area(&wires[a]).total_cmp(&area(&wires[b])))
+                .ok_or_else(|| Error::SvgReadFailed(format!("layer `{layer}` has no outlines")))?;
+        let outer = wires.remove(outer);
+
+        Self::from_wire_with_holes(&outer, &wires)
+    }
+
     pub fn from_surface(surface: &Surface) -> Self {
         const EDGE_TOLERANCE: f64 = 0.0001;
 
@@ -51,6 +125,53 @@ impl Face {
         Self::from_make_face(make_face)
     }
 
+    /// Fill the closed loop of `boundary` edges with a smooth N-sided surface,
+    /// built with `BRepOffsetAPI_MakeFilling`. The surface is further shaped by
+    /// the optional `constraints`, which it passes through without being bounded by.
+    pub fn fill<'a>(
+        boundary: impl IntoIterator<Item = FillEdge<'a>>,
+        constraints: impl IntoIterator<Item = FillConstraint<'a>>,
+    ) -> Result<Self, Error> {
+        let mut make_filling = ffi::b_rep_offset_api::BRepOffsetAPI_MakeFilling_new();
+
+        let is_bound = true;
+        for fill_edge in boundary {
+            match fill_edge {
+                FillEdge::G0(edge) => {
+                    make_filling.pin_mut().add_edge(&edge.inner, Continuity::C0.into(), is_bound);
+                },
+                FillEdge::G1 { edge, face } => {
+                    make_filling.pin_mut().add_edge_with_support(
+                        &edge.inner,
+                        &face.inner,
+                        Continuity::G1.into(),
+                        is_bound,
+                    );
+                },
+            }
+        }
+
+        let is_bound = false;
+        for constraint in constraints {
+            match constraint {
+                FillConstraint::Point(point) => {
+                    make_filling.pin_mut().add_point(&make_point(point));
+                },
+                FillConstraint::Curve(edge) => {
+                    make_filling.pin_mut().add_edge(&edge.inner, Continuity::C0.into(), is_bound);
+                },
+            }
+        }
+
+        make_filling.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_filling.IsDone() {
+            return Err(Error::FillingFailed);
+        }
+
+        Ok(Self::from_face(ffi::topo_ds::TopoDS::Face(make_filling.pin_mut().Shape())))
+    }
+
     #[must_use]
     pub fn extrude(&self, dir: DVec3) -> Solid {
         let prism_vec = make_vec(dir);
@@ -114,7 +235,12 @@ impl Face {
 
     #[must_use]
     pub fn revolve(&self, origin: DVec3, axis: DVec3, angle: Option<Angle>) -> Solid {
-        let revol_vec = make_axis_1(origin, axis);
+        self.revolve_around(Axis1::new(origin, axis), angle)
+    }
+
+    #[must_use]
+    pub fn revolve_around(&self, axis: impl Into<Axis1>, angle: Option<Angle>) -> Solid {
+        let revol_vec = axis.into().to_gp_ax1();
 
         let angle = angle.map(Angle::radians).unwrap_or(std::f64::consts::PI * 2.0);
         let copy = false;
@@ -128,6 +254,13 @@ impl Face {
         Solid::from_solid(solid)
     }
 
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let shape = transform.apply(ffi::topo_ds::cast_face_to_shape(&self.inner));
+
+        Self::from_face(ffi::topo_ds::TopoDS::Face(&shape.inner))
+    }
+
     /// Fillets the face edges by a given radius at each vertex
     #[must_use]
     pub fn fillet(&self, radius: f64) -> Self {
@@ -453,7 +586,12 @@ impl CompoundFace {
 
     #[must_use]
     pub fn revolve(&self, origin: DVec3, axis: DVec3, angle: Option<Angle>) -> Shape {
-        let revol_axis = make_axis_1(origin, axis);
+        self.revolve_around(Axis1::new(origin, axis), angle)
+    }
+
+    #[must_use]
+    pub fn revolve_around(&self, axis: impl Into<Axis1>, angle: Option<Angle>) -> Shape {
+        let revol_axis = axis.into().to_gp_ax1();
 
         let angle = angle.map(Angle::radians).unwrap_or(std::f64::consts::PI * 2.0);
         let copy = false;
@@ -523,6 +661,27 @@ impl CompoundFace {
     }
 }
 
+/// A boundary edge of a surface built with [`Face::fill`].
+#[derive(Copy, Clone)]
+pub enum FillEdge<'a> {
+    /// The surface passes through the edge.
+    G0(&'a Edge),
+
+    /// The surface passes through the edge and is tangent to the adjacent `face`
+    /// along it.
+    G1 { edge: &'a Edge, face: &'a Face },
+}
+
+/// An interior constraint of a surface built with [`Face::fill`].
+#[derive(Copy, Clone)]
+pub enum FillConstraint<'a> {
+    /// The surface passes through the point.
+    Point(DVec3),
+
+    /// The surface passes through the curve of the edge.
+    Curve(&'a Edge),
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum FaceOrientation {
     Forward,
@@ -548,6 +707,8 @@ impl From<ffi::top_abs::TopAbs_Orientation> for FaceOrientation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::ShapeType;
+    use std::iter::once;
 
     #[test]
     fn test_add() {
@@ -558,4 +719,88 @@ mod tests {
             face.surface_area()
         );
     }
+
+    #[test]
+    fn svg_face_with_hole() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <g id="cut">
+                <rect x="4" y="4" width="2" height="2"/>
+                <rect x="0" y="0" width="10" height="10"/>
+                <polygon points=""/>
+            </g>
+        </svg>"#;
+
+        let face = Face::read_svg(svg.as_bytes(), "cut").unwrap();
+        assert!((face.surface_area() - 96.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn svg_skips_zero_length_segments() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <g id="cut">
+                <path d="M 0 0 l 0,0 H 10 H 10 V 10 A 0 0 0 0 1 10 10 H 0 Z"/>
+            </g>
+        </svg>"#;
+
+        let face = Face::read_svg(svg.as_bytes(), "cut").unwrap();
+        assert!((face.surface_area() - 100.0).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn fill_closed_edge_loop() {
+        let corners = [
+            dvec3(0.0, 0.0, 0.0),
+            dvec3(10.0, 0.0, 2.0),
+            dvec3(10.0, 10.0, 0.0),
+            dvec3(0.0, 10.0, 2.0),
+        ];
+        let edges: Vec<_> =
+            (0..4).map(|i| Edge::segment(corners[i], corners[(i + 1) % 4])).collect();
+
+        let face = Face::fill(edges.iter().map(FillEdge::G0), []).unwrap();
+
+        let shape = Shape::from(&face);
+        assert_eq!(shape.shape_type(), ShapeType::Face);
+        assert!(shape.is_valid());
+        assert!(face.surface_area() > 100.0);
+    }
+
+    #[test]
+    fn fill_tangent_to_support_face() {
+        let shared = Edge::segment(dvec3(0.0, 0.0, 0.0), dvec3(10.0, 0.0, 0.0));
+
+        // A ramp which rises at 45 degrees towards the filled face.
+        let ramp = [
+            Edge::segment(dvec3(10.0, 0.0, 0.0), dvec3(10.0, -5.0, -5.0)),
+            Edge::segment(dvec3(10.0, -5.0, -5.0), dvec3(0.0, -5.0, -5.0)),
+            Edge::segment(dvec3(0.0, -5.0, -5.0), dvec3(0.0, 0.0, 0.0)),
+        ];
+        let support = Wire::from_edges(once(&shared).chain(&ramp)).to_face();
+
+        let others = [
+            Edge::segment(dvec3(10.0, 0.0, 0.0), dvec3(10.0, 10.0, 0.0)),
+            Edge::segment(dvec3(10.0, 10.0, 0.0), dvec3(0.0, 10.0, 0.0)),
+            Edge::segment(dvec3(0.0, 10.0, 0.0), dvec3(0.0, 0.0, 0.0)),
+        ];
+        let boundary = once(FillEdge::G1 { edge: &shared, face: &support })
+            .chain(others.iter().map(FillEdge::G0));
+
+        let face = Face::fill(boundary, [FillConstraint::Point(dvec3(5.0, 5.0, 2.0))]).unwrap();
+        assert!(Shape::from(&face).is_valid());
+
+        // Along the shared edge the filled face continues the slope of the ramp.
+        let ramp_normal = dvec3(0.0, -1.0, 1.0).normalize();
+        let normal = face.normal_at(dvec3(5.0, 0.0, 0.0)).normalize();
+        assert!(normal.dot(ramp_normal).abs() > 0.999);
+    }
+
+    #[test]
+    fn open_outline_is_an_error() {
+        let open = Wire::from_edges(&[
+            Edge::segment(dvec3(0.0, 0.0, 0.0), dvec3(1.0, 0.0, 0.0)),
+            Edge::segment(dvec3(1.0, 0.0, 0.0), dvec3(1.0, 1.0, 0.0)),
+        ]);
+
+        assert!(Face::from_wire_with_holes(&open, []).is_err());
+    }
 }
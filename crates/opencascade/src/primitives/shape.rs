@@ -1,15 +1,32 @@
 use crate::{
-    mesh::{Mesh, Mesher},
+    bounding_box,
+    dxf::DxfWriter,
+    geometry::{Axis1, CoordinateSystem, Transform},
+    gltf::{GltfNode, GltfWriter},
+    hole::{Hole, HoleFace, HoleShape},
+    mesh::{Mesh, MeshParameters, Mesher},
+    mesh_export::ThreeMfWriter,
     primitives::{
-        make_axis_1, make_axis_2, make_dir, make_point, make_point2d, make_vec, BooleanShape,
-        Compound, Edge, EdgeIterator, Face, FaceIterator, ShapeType, Shell, Solid, Vertex, Wire,
+        make_dir, make_point, make_point2d, make_vec, BooleanShape, Compound, Edge, EdgeIterator,
+        Face, FaceIterator, ShapeType, Shell, Solid, Vertex, Wire,
     },
+    projection::Projection2d,
+    slice::{self, Layer},
+    step::{self, StepReader, StepWriter},
+    svg::SvgWriter,
+    sweep::{AuxiliarySpineContact, SweepBuilder, SweepMode},
+    workplane::Workplane,
     Error,
 };
 use cxx::UniquePtr;
 use glam::{dvec2, dvec3, DVec3};
 use opencascade_sys as ffi;
-use std::path::Path;
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Read, Write},
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 pub struct Shape {
     pub(crate) inner: UniquePtr<ffi::topo_ds::TopoDS_Shape>,
@@ -140,14 +157,14 @@ impl From<BooleanShape> for Shape {
 }
 
 pub struct SphereBuilder {
-    center: DVec3,
+    position: CoordinateSystem,
     radius: f64,
     z_angle: f64,
 }
 
 impl SphereBuilder {
     pub fn build(self) -> Shape {
-        let axis = make_axis_2(self.center, DVec3::Z);
+        let axis = self.position.to_gp_ax2();
         let mut make_shere =
             ffi::b_rep_prim_api::BRepPrimAPI_MakeSphere_new(&axis, self.radius, self.z_angle);
 
@@ -155,7 +172,14 @@ impl SphereBuilder {
     }
 
     pub fn at(mut self, center: DVec3) -> Self {
-        self.center = center;
+        self.position = CoordinateSystem::new(center, self.position.z_dir(), self.position.x_dir());
+        self
+    }
+
+    /// Place the center of the sphere at the origin of `position`, with its
+    /// poles along the Z direction.
+    pub fn coordinate_system(mut self, position: CoordinateSystem) -> Self {
+        self.position = position;
         self
     }
 
@@ -166,7 +190,7 @@ impl SphereBuilder {
 }
 
 pub struct ConeBuilder {
-    pos: DVec3,
+    position: CoordinateSystem,
     height: f64,
     bottom_radius: f64,
     top_radius: f64,
@@ -175,7 +199,7 @@ pub struct ConeBuilder {
 
 impl ConeBuilder {
     pub fn build(self) -> Shape {
-        let axis = make_axis_2(self.pos, DVec3::Z);
+        let axis = self.position.to_gp_ax2();
         let mut make_cone = ffi::b_rep_prim_api::BRepPrimAPI_MakeCone_new(
             &axis,
             self.bottom_radius,
@@ -188,7 +212,14 @@ impl ConeBuilder {
     }
 
     pub fn at(mut self, pos: DVec3) -> Self {
-        self.pos = pos;
+        self.position = CoordinateSystem::new(pos, self.position.z_dir(), self.position.x_dir());
+        self
+    }
+
+    /// Place the bottom of the cone at the origin of `position`, extending
+    /// along the Z direction.
+    pub fn coordinate_system(mut self, position: CoordinateSystem) -> Self {
+        self.position = position;
         self
     }
 
@@ -214,8 +245,7 @@ impl ConeBuilder {
 }
 
 pub struct TorusBuilder {
-    pos: DVec3,
-    z_axis: DVec3,
+    position: CoordinateSystem,
     radius_1: f64,
     radius_2: f64,
     angle_1: f64,
@@ -225,7 +255,7 @@ pub struct TorusBuilder {
 
 impl TorusBuilder {
     pub fn build(self) -> Shape {
-        let axis = make_axis_2(self.pos, self.z_axis);
+        let axis = self.position.to_gp_ax2();
         let mut make_torus = ffi::b_rep_prim_api::BRepPrimAPI_MakeTorus_new(
             &axis,
             self.radius_1,
@@ -239,12 +269,18 @@ impl TorusBuilder {
     }
 
     pub fn at(mut self, pos: DVec3) -> Self {
-        self.pos = pos;
+        self.position = CoordinateSystem::new(pos, self.position.z_dir(), self.position.x_dir());
         self
     }
 
     pub fn z_axis(mut self, z_axis: DVec3) -> Self {
-        self.z_axis = z_axis;
+        self.position = CoordinateSystem::from_normal(self.position.origin(), z_axis);
+        self
+    }
+
+    /// Center the torus on the origin of `position`, around its Z axis.
+    pub fn coordinate_system(mut self, position: CoordinateSystem) -> Self {
+        self.position = position;
         self
     }
 
@@ -345,7 +381,13 @@ impl Shape {
     /// Make a cylinder with base at point `p`, radius `r`, and height `h`.
     /// Extends from `p` along axis `dir`.
     pub fn cylinder(p: DVec3, r: f64, dir: DVec3, h: f64) -> Self {
-        let cylinder_coord_system = make_axis_2(p, dir);
+        Self::cylinder_in(&CoordinateSystem::from_normal(p, dir), r, h)
+    }
+
+    /// Make a cylinder with radius `r` and height `h`, with its base at the
+    /// origin of `coordinate_system`, extending along its Z direction.
+    pub fn cylinder_in(coordinate_system: &CoordinateSystem, r: f64, h: f64) -> Self {
+        let cylinder_coord_system = coordinate_system.to_gp_ax2();
         let mut cylinder =
             ffi::b_rep_prim_api::BRepPrimAPI_MakeCylinder_new(&cylinder_coord_system, r, h);
 
@@ -368,17 +410,29 @@ impl Shape {
     /// Make a cylinder centered at point `p`, with radius `r`, and height `h`.
     /// Extends along axis `dir`.
     pub fn cylinder_centered(p: DVec3, r: f64, dir: DVec3, h: f64) -> Self {
-        let p = p - (dir.normalize() * (h / 2.0));
-        Self::cylinder(p, r, dir, h)
+        Self::cylinder_centered_in(&CoordinateSystem::from_normal(p, dir), r, h)
+    }
+
+    /// Make a cylinder with radius `r` and height `h`, centered at the origin of
+    /// `coordinate_system` and extending along its Z direction.
+    pub fn cylinder_centered_in(coordinate_system: &CoordinateSystem, r: f64, h: f64) -> Self {
+        let z_dir = coordinate_system.z_dir();
+        let base = coordinate_system.origin() - z_dir * (h / 2.0);
+
+        Self::cylinder_in(&CoordinateSystem::new(base, z_dir, coordinate_system.x_dir()), r, h)
     }
 
     pub fn sphere(radius: f64) -> SphereBuilder {
-        SphereBuilder { center: DVec3::ZERO, radius, z_angle: std::f64::consts::TAU }
+        SphereBuilder {
+            position: CoordinateSystem::world(),
+            radius,
+            z_angle: std::f64::consts::TAU,
+        }
     }
 
     pub fn cone() -> ConeBuilder {
         ConeBuilder {
-            pos: DVec3::ZERO,
+            position: CoordinateSystem::world(),
             height: 1.0,
             bottom_radius: 1.0,
             top_radius: 0.0,
@@ -388,8 +442,7 @@ impl Shape {
 
     pub fn torus() -> TorusBuilder {
         TorusBuilder {
-            pos: DVec3::ZERO,
-            z_axis: DVec3::Z,
+            position: CoordinateSystem::world(),
             radius_1: 20.0,
             radius_2: 10.0,
             angle_1: -std::f64::consts::PI,
@@ -402,6 +455,30 @@ impl Shape {
         self.inner.ShapeType().into()
     }
 
+    /// Check the topology and geometry of this shape, including that the faces of
+    /// its solids are closed and don't self-intersect.
+    pub fn is_valid(&self) -> bool {
+        ffi::b_rep_check::BRepCheck_Analyzer_new(&self.inner).IsValid()
+    }
+
+    /// The volume enclosed by the solids in this shape.
+    pub fn volume(&self) -> f64 {
+        let mut props = ffi::g_prop::GProps_new();
+
+        let only_closed = false;
+        let skip_shared = false;
+        let use_triangulation = false;
+        ffi::b_rep_g_prop::BRepGProp::VolumeProperties(
+            &self.inner,
+            props.pin_mut(),
+            only_closed,
+            skip_shared,
+            use_triangulation,
+        );
+
+        props.Mass()
+    }
+
     #[must_use]
     pub fn fillet_edge(&self, radius: f64, edge: &Edge) -> Self {
         self.fillet_edges(radius, [edge])
@@ -503,66 +580,111 @@ impl Shape {
         BooleanShape { shape, new_edges }
     }
 
+    /// Read a STEP file, in millimeters. See [`StepReader`] for other units and
+    /// for reading each root as a separate shape.
     pub fn read_step(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let mut reader = ffi::step_control::STEPControl_Reader_new();
+        Ok(StepReader::new().read(path)?.into_shape())
+    }
+
+    /// Read a STEP file from memory, or any other source of bytes.
+    pub fn read_step_from(source: impl Read) -> Result<Self, Error> {
+        Ok(StepReader::new().read_from(source)?.into_shape())
+    }
+
+    /// Write a STEP file with the default settings. See [`StepWriter`] for
+    /// other schemas, units and header fields.
+    pub fn write_step(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        StepWriter::new().product("", self).write(path)
+    }
+
+    pub fn write_step_to(&self, destination: impl Write) -> Result<(), Error> {
+        StepWriter::new().product("", self).write_to(destination)
+    }
 
-        let status = ffi::step_control::read_step(
+    pub fn read_iges(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let _settings = step::lock_settings();
+        let mut reader = ffi::iges_control::IGESControl_Reader_new();
+
+        // IGES shares its read settings with STEP, so undo any a StepReader left behind.
+        StepReader::new().apply_settings();
+
+        let status = ffi::iges_control::read_iges(
             reader.pin_mut(),
             path.as_ref().to_string_lossy().to_string(),
         );
 
         if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
-            return Err(Error::StepReadFailed);
+            return Err(Error::IgesReadFailed);
         }
 
         reader.pin_mut().TransferRoots(&ffi::message::Message_ProgressRange_new());
 
-        let inner = ffi::step_control::one_shape_step(&reader);
+        let inner = ffi::iges_control::one_shape_iges(&reader);
 
         Ok(Self { inner })
     }
 
-    pub fn write_step(&self, path: impl AsRef<Path>) -> Result<(), Error> {
-        let mut writer = ffi::step_control::STEPControl_Writer_new();
+    /// Read an IGES file from memory, or any other source of bytes.
+    ///
+    /// **This needs a writable filesystem**, and fails on targets without one
+    /// such as WASM. OpenCASCADE only supports reading STEP from streams, so the
+    /// IGES data is written to a temporary file and read back from there.
+    pub fn read_iges_from(mut source: impl Read) -> Result<Self, Error> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-        let status = ffi::step_control::transfer_shape(writer.pin_mut(), &self.inner);
+        // Never open an existing file, which could be a link planted by someone else.
+        let (path, mut file) = loop {
+            let path = std::env::temp_dir().join(format!(
+                "opencascade-{}-{}.igs",
+                std::process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
 
-        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
-            return Err(Error::StepWriteFailed);
-        }
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err.into()),
+            }
+        };
 
-        let status = ffi::step_control::write_step(
-            writer.pin_mut(),
-            path.as_ref().to_string_lossy().to_string(),
-        );
+        let copied = std::io::copy(&mut source, &mut file);
+        drop(file);
 
-        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
-            return Err(Error::StepWriteFailed);
-        }
+        let result = copied.map_err(Error::from).and_then(|_| Self::read_iges(&path));
+        let _ = std::fs::remove_file(&path);
 
-        Ok(())
+        result
     }
 
-    pub fn read_iges(path: impl AsRef<Path>) -> Result<Self, Error> {
-        let mut reader = ffi::iges_control::IGESControl_Reader_new();
+    pub fn write_iges(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let mut writer = self.iges_writer()?;
 
-        let status = ffi::iges_control::read_iges(
-            reader.pin_mut(),
+        let success = ffi::iges_control::write_iges(
+            writer.pin_mut(),
             path.as_ref().to_string_lossy().to_string(),
         );
 
-        reader.pin_mut().TransferRoots(&ffi::message::Message_ProgressRange_new());
+        if success {
+            Ok(())
+        } else {
+            Err(Error::IgesWriteFailed)
+        }
+    }
 
-        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
-            return Err(Error::IgesReadFailed);
+    pub fn write_iges_to(&self, mut destination: impl Write) -> Result<(), Error> {
+        let mut writer = self.iges_writer()?;
+
+        let mut data = vec![];
+        if !ffi::iges_control::write_iges_stream(writer.pin_mut(), &mut data) {
+            return Err(Error::IgesWriteFailed);
         }
 
-        let inner = ffi::iges_control::one_shape_iges(&reader);
+        destination.write_all(&data)?;
 
-        Ok(Self { inner })
+        Ok(())
     }
 
-    pub fn write_iges(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+    fn iges_writer(&self) -> Result<UniquePtr<ffi::iges_control::IGESControl_Writer>, Error> {
         let mut writer = ffi::iges_control::IGESControl_Writer_new();
 
         let success =
@@ -573,16 +695,8 @@ impl Shape {
         }
 
         writer.pin_mut().ComputeModel();
-        let success = ffi::iges_control::write_iges(
-            writer.pin_mut(),
-            path.as_ref().to_string_lossy().to_string(),
-        );
 
-        if success {
-            Ok(())
-        } else {
-            Err(Error::IgesWriteFailed)
-        }
+        Ok(writer)
     }
 
     pub fn write_brep_text(&self, path: impl AsRef<Path>) -> Result<(), Error> {
@@ -606,6 +720,26 @@ impl Shape {
         }
     }
 
+    pub fn write_brep_text_to(&self, mut destination: impl Write) -> Result<(), Error> {
+        let mut data = vec![];
+        ffi::b_rep_tools::write_stream(&self.inner, &mut data)
+            .map_err(|_| Error::BrepWriteFailed)?;
+
+        destination.write_all(&data)?;
+
+        Ok(())
+    }
+
+    pub fn read_brep_text_from(mut source: impl Read) -> Result<Self, Error> {
+        let mut data = vec![];
+        source.read_to_end(&mut data)?;
+
+        match ffi::b_rep_tools::read_stream(&data) {
+            Ok(inner) if !inner.is_null() => Ok(Self { inner }),
+            _ => Err(Error::BrepReadFailed),
+        }
+    }
+
     pub fn write_brep_bin(&self, path: impl AsRef<Path>) -> Result<(), Error> {
         let success =
             ffi::bin_tools::write(&self.inner, path.as_ref().to_string_lossy().to_string());
@@ -627,6 +761,25 @@ impl Shape {
         }
     }
 
+    pub fn write_brep_bin_to(&self, mut destination: impl Write) -> Result<(), Error> {
+        let mut data = vec![];
+        ffi::bin_tools::write_stream(&self.inner, &mut data).map_err(|_| Error::BrepWriteFailed)?;
+
+        destination.write_all(&data)?;
+
+        Ok(())
+    }
+
+    pub fn read_brep_bin_from(mut source: impl Read) -> Result<Self, Error> {
+        let mut data = vec![];
+        source.read_to_end(&mut data)?;
+
+        match ffi::bin_tools::read_stream(&data) {
+            Ok(inner) if !inner.is_null() => Ok(Self { inner }),
+            _ => Err(Error::BrepReadFailed),
+        }
+    }
+
     #[must_use]
     pub fn union(&self, other: &Shape) -> BooleanShape {
         let mut fuse_operation =
@@ -663,6 +816,86 @@ impl Shape {
         BooleanShape { shape, new_edges }
     }
 
+    /// Read a triangle mesh from an ASCII or binary STL file and convert it
+    /// into a shape, as with [`Shape::from_mesh`].
+    pub fn read_stl(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let triangulation = ffi::stl_api::read_stl(path.as_ref().to_string_lossy().to_string());
+
+        if triangulation.IsNull() {
+            return Err(Error::StlReadFailed);
+        }
+
+        Self::from_triangulation(&triangulation)
+    }
+
+    /// Read an ASCII or binary STL file from memory, or any other source of bytes.
+    ///
+    /// OpenCASCADE can only read STL from files, so unlike [`Shape::read_stl`]
+    /// this parses the file with [`Mesh::read_stl`] instead of `StlAPI`. Vertices
+    /// closer than `1.0e-9` are welded together before the mesh is converted with
+    /// [`Shape::from_mesh`].
+    pub fn read_stl_from(source: impl Read) -> Result<Self, Error> {
+        const WELD_TOLERANCE: f64 = 1.0e-9;
+
+        let mesh = Mesh::read_stl(source)?.welded(WELD_TOLERANCE);
+        Self::from_mesh(&mesh)
+    }
+
+    /// Convert a triangle mesh into a shape with one planar face per triangle.
+    ///
+    /// The faces are sewn together, and if they form a closed shell the result is
+    /// a solid which can be used in boolean operations. Call [`Shape::clean`] on
+    /// the result to merge coplanar triangles into larger faces.
+    pub fn from_mesh(mesh: &Mesh) -> Result<Self, Error> {
+        let mut triangulation = ffi::poly::Triangulation_new(
+            mesh.vertices.len() as i32,
+            (mesh.indices.len() / 3) as i32,
+            false,
+            false,
+        );
+
+        for (i, vertex) in mesh.vertices.iter().enumerate() {
+            triangulation.pin_mut().SetNode(i as i32 + 1, &make_point(*vertex));
+        }
+
+        for (i, triangle) in mesh.indices.chunks_exact(3).enumerate() {
+            let triangle = ffi::poly::Triangle_new(
+                triangle[0] as i32 + 1,
+                triangle[1] as i32 + 1,
+                triangle[2] as i32 + 1,
+            );
+            triangulation.pin_mut().SetTriangle(i as i32 + 1, &triangle);
+        }
+
+        Self::from_triangulation(&ffi::poly::Handle_Poly_Triangulation_new(triangulation))
+    }
+
+    fn from_triangulation(
+        triangulation: &ffi::poly::Handle_Poly_Triangulation,
+    ) -> Result<Self, Error> {
+        const SEWING_TOLERANCE: f64 = 1.0e-6;
+
+        let mut make_shape =
+            ffi::b_rep_builder_api::BRepBuilderAPI_MakeShapeOnMesh_new(triangulation);
+        make_shape.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_shape.IsDone() {
+            return Err(Error::MeshConversionFailed);
+        }
+
+        let sewn = Self::from_shape(make_shape.pin_mut().Shape()).sew(SEWING_TOLERANCE);
+
+        if sewn.shape_type() == ShapeType::Shell {
+            let shell = Shell::from_shell(ffi::topo_ds::TopoDS::Shell(&sewn.inner));
+
+            if let Ok(solid) = Solid::from_shell(&shell) {
+                return Ok(solid.into());
+            }
+        }
+
+        Ok(sewn)
+    }
+
     pub fn write_stl<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
         self.write_stl_with_tolerance(path, 0.001)
     }
@@ -672,8 +905,31 @@ impl Shape {
         path: P,
         triangulation_tolerance: f64,
     ) -> Result<(), Error> {
-        let mut stl_writer = ffi::stl_api::StlAPI_Writer_new();
         let mesher = Mesher::try_new(self, triangulation_tolerance)?;
+        Self::write_stl_from_mesher(path, &mesher)
+    }
+
+    pub fn write_stl_with_parameters<P: AsRef<Path>>(
+        &self,
+        path: P,
+        parameters: &MeshParameters,
+    ) -> Result<(), Error> {
+        let mesher = Mesher::try_with_parameters(self, parameters)?;
+        Self::write_stl_from_mesher(path, &mesher)
+    }
+
+    /// Write a binary STL file to memory, or any other destination.
+    ///
+    /// OpenCASCADE can only write STL to files, so unlike [`Shape::write_stl`]
+    /// this writes the file with [`Mesh::write_stl`] instead of `StlAPI`. The
+    /// shape is triangulated with the same tolerance of `0.001`. Use
+    /// [`Shape::mesh_with_parameters`] and [`Mesh::write_stl`] for other tolerances.
+    pub fn write_stl_to(&self, destination: impl Write) -> Result<(), Error> {
+        self.mesh_with_tolerance(0.001)?.write_stl(destination)
+    }
+
+    fn write_stl_from_mesher<P: AsRef<Path>>(path: P, mesher: &Mesher) -> Result<(), Error> {
+        let mut stl_writer = ffi::stl_api::StlAPI_Writer_new();
         let success = ffi::stl_api::write_stl(
             stl_writer.pin_mut(),
             mesher.inner.Shape(),
@@ -687,6 +943,126 @@ impl Shape {
         }
     }
 
+    /// Write this shape to a glTF 2.0 file. The binary format is used if the
+    /// path has a `.glb` extension. See [`GltfWriter`] for named nodes and colors.
+    pub fn write_gltf(
+        &self,
+        path: impl AsRef<Path>,
+        parameters: MeshParameters,
+    ) -> Result<(), Error> {
+        let name = path
+            .as_ref()
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        GltfWriter::new(parameters).node(GltfNode::new(name, self)).write(path)
+    }
+
+    pub fn write_obj(
+        &self,
+        path: impl AsRef<Path>,
+        parameters: MeshParameters,
+    ) -> Result<(), Error> {
+        let mesh = self.mesh_with_parameters(&parameters)?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        mesh.write_obj(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write_ply(
+        &self,
+        path: impl AsRef<Path>,
+        parameters: MeshParameters,
+    ) -> Result<(), Error> {
+        let mesh = self.mesh_with_parameters(&parameters)?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        mesh.write_ply(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Write this shape to a 3MF file as a single object, in millimeters. See
+    /// [`ThreeMfWriter`] for multiple objects and other units.
+    pub fn write_3mf(
+        &self,
+        path: impl AsRef<Path>,
+        parameters: MeshParameters,
+    ) -> Result<(), Error> {
+        const WELD_TOLERANCE: f64 = 1.0e-6;
+
+        let path = path.as_ref();
+        let name =
+            path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        let mesh = self.mesh_with_parameters(&parameters)?.welded(WELD_TOLERANCE);
+
+        ThreeMfWriter::new().object(name, &mesh).write(File::create(path)?)
+    }
+
+    /// Project this shape onto a plane facing `view_direction`, with hidden lines
+    /// removed. `up` is the direction which points up in the projection.
+    pub fn project_hlr(&self, view_direction: DVec3, up: DVec3) -> Result<Projection2d, Error> {
+        Projection2d::exact(self, view_direction, up)
+    }
+
+    /// Like [`Shape::project_hlr`], but faster and approximated with straight
+    /// edges, by projecting a triangulation of this shape with `deflection`.
+    pub fn project_hlr_polygonal(
+        &self,
+        view_direction: DVec3,
+        up: DVec3,
+        deflection: f64,
+    ) -> Result<Projection2d, Error> {
+        Projection2d::polygonal(self, view_direction, up, deflection)
+    }
+
+    /// Slice this shape into layers of `layer_height` along `plane_normal`, with
+    /// the outlines of each layer as polygons. Fails if `layer_height` isn't
+    /// positive or `plane_normal` is zero.
+    pub fn slice(&self, plane_normal: DVec3, layer_height: f64) -> Result<Vec<Layer>, Error> {
+        slice::slice(self, plane_normal, layer_height)
+    }
+
+    /// The faces where `plane` cuts through this shape.
+    pub fn cross_section(&self, plane: &Workplane) -> Result<Vec<Face>, Error> {
+        slice::cross_section(self, plane)
+    }
+
+    /// Write the edges of this shape to a DXF file, projected onto `plane`. See
+    /// [`DxfWriter`] for layers.
+    pub fn write_dxf(&self, path: impl AsRef<Path>, plane: &Workplane) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        DxfWriter::new().edges("0", self.edges(), plane).write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Write the edges of this shape to an SVG file, projected onto `plane`. See
+    /// [`SvgWriter`] for layers and styles.
+    pub fn write_svg(&self, path: impl AsRef<Path>, plane: &Workplane) -> Result<(), Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        SvgWriter::new().edges("edges", self.edges(), plane).write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Sew the faces of this shape together along edges which are within
+    /// `tolerance` of each other. Useful for turning imported surface models,
+    /// which are often loose collections of faces, into shells.
+    #[must_use]
+    pub fn sew(&self, tolerance: f64) -> Self {
+        let mut sewing = ffi::b_rep_builder_api::BRepBuilderAPI_Sewing_new(tolerance);
+        sewing.pin_mut().Add(&self.inner);
+        sewing.pin_mut().Perform(&ffi::message::Message_ProgressRange_new());
+
+        Self::from_shape(sewing.SewedShape())
+    }
+
     #[must_use]
     pub fn clean(&self) -> Self {
         let mut upgrader = ffi::shape_upgrade::UnifySameDomain_new(&self.inner, true, true, true);
@@ -696,6 +1072,12 @@ impl Shape {
         Self::from_shape(upgrader.shape())
     }
 
+    /// Apply a transform which may also scale, mirror or shear this shape.
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        transform.apply(&self.inner)
+    }
+
     pub fn set_global_translation(&mut self, translation: DVec3) {
         let mut transform = ffi::gp::new_transform();
         let translation_vec = make_vec(translation);
@@ -715,6 +1097,11 @@ impl Shape {
         mesher.mesh()
     }
 
+    pub fn mesh_with_parameters(&self, parameters: &MeshParameters) -> Result<Mesh, Error> {
+        let mesher = Mesher::try_with_parameters(self, parameters)?;
+        mesher.mesh()
+    }
+
     pub fn edges(&self) -> EdgeIterator {
         let explorer = ffi::top_exp::TopExp_Explorer_new(
             &self.inner,
@@ -809,7 +1196,13 @@ impl Shape {
     /// and direction `dir`, with `radius`.
     #[must_use]
     pub fn drill_hole(&self, p: DVec3, dir: DVec3, radius: f64) -> Self {
-        let hole_axis = make_axis_1(p, dir);
+        self.drill_hole_along((p, dir), radius)
+    }
+
+    /// Drill a cylindrical hole with `radius` along `axis`.
+    #[must_use]
+    pub fn drill_hole_along(&self, axis: impl Into<Axis1>, radius: f64) -> Self {
+        let hole_axis = axis.into().to_gp_ax1();
 
         let mut make_hole = ffi::b_rep_feat::BRepFeat_MakeCylindricalHole_new();
         make_hole.pin_mut().Init(&self.inner, &hole_axis);
@@ -819,6 +1212,126 @@ impl Shape {
 
         Self::from_shape(make_hole.pin_mut().Shape())
     }
+
+    /// Cut `hole` into this shape, starting at point `p` and going into the
+    /// material along `dir`.
+    pub fn hole(&self, p: DVec3, dir: DVec3, hole: &Hole) -> Result<HoleShape, Error> {
+        self.hole_along((p, dir), hole)
+    }
+
+    /// Cut `hole` into this shape, starting at the origin of `axis` and going
+    /// into the material along its direction.
+    pub fn hole_along(&self, axis: impl Into<Axis1>, hole: &Hole) -> Result<HoleShape, Error> {
+        hole.validate()?;
+
+        let axis = axis.into();
+        let (p, dir) = (axis.origin(), axis.direction());
+        let radial = dir.any_orthonormal_vector();
+
+        let bounds = bounding_box::aabb(self);
+        let through_depth = (bounds.max() - bounds.min()).length() + (bounds.min() - p).length();
+
+        let points = hole.profile(through_depth).into_iter().map(|(r, z)| p + radial * r + dir * z);
+        let tool = Wire::from_ordered_points(points)?.to_face().revolve(p, dir, None);
+        let tool = Shape::from(tool);
+
+        let mut cut_operation = ffi::b_rep_algo_api::BRepAlgoAPI_Cut_new(&self.inner, &tool.inner);
+        cut_operation.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !cut_operation.IsDone() {
+            return Err(Error::FeatureFailed);
+        }
+
+        let mut hole_faces = vec![];
+        for tool_face in tool.faces() {
+            let tool_face = ffi::topo_ds::cast_face_to_shape(&tool_face.inner);
+
+            let modified = cut_operation.pin_mut().Modified(tool_face);
+            let modified = ffi::topo_ds::shape_list_to_vector(modified);
+
+            if modified.is_empty() {
+                if !cut_operation.pin_mut().IsDeleted(tool_face) {
+                    let face = Face::from_face(ffi::topo_ds::TopoDS::Face(tool_face));
+                    hole_faces.push(HoleFace { face, hole: *hole });
+                }
+            } else {
+                for face in modified.iter() {
+                    let face = Face::from_face(ffi::topo_ds::TopoDS::Face(face));
+                    hole_faces.push(HoleFace { face, hole: *hole });
+                }
+            }
+        }
+
+        let shape = Shape::from_shape(cut_operation.pin_mut().Shape());
+
+        Ok(HoleShape { shape, hole_faces })
+    }
+
+    /// Add a stiffening rib of `thickness`, filling material from the open `profile`
+    /// wire out to the walls of this shape. `direction` is the normal of the plane
+    /// the profile lies in, and the rib is centered on that plane.
+    pub fn rib(&self, profile: &Wire, direction: DVec3, thickness: f64) -> Result<Self, Error> {
+        let profile_shape = Shape::from(profile);
+        let plane_origin = profile_shape
+            .edges()
+            .next()
+            .map(|edge| edge.start_point())
+            .ok_or(Error::FeatureFailed)?;
+
+        let direction = direction.normalize();
+        let plane = ffi::geom::Geom_Plane_new(&make_point(plane_origin), &make_dir(direction));
+        let half_thickness = direction * (thickness / 2.0);
+
+        let fuse = 1; // 0 = subtractive, 1 = additive
+        let modify = false;
+        let mut make_rib = ffi::b_rep_feat::BRepFeat_MakeLinearForm_new(
+            &self.inner,
+            &profile.inner,
+            &plane,
+            &make_vec(half_thickness),
+            &make_vec(-half_thickness),
+            fuse,
+            modify,
+        );
+
+        make_rib.pin_mut().Perform();
+
+        if !make_rib.IsDone() {
+            return Err(Error::FeatureFailed);
+        }
+
+        Ok(Self::from_shape(make_rib.pin_mut().Shape()))
+    }
+
+    /// Sweep a closed thread `profile` along a `helix` made with [`Wire::helix`],
+    /// producing the thread as a solid. Fuse it with a core cylinder for an
+    /// external thread, or subtract it from a drilled hole for an internal one.
+    ///
+    /// The profile keeps its orientation relative to the helix axis (the Z axis)
+    /// by using an auxiliary spine which runs halfway between the helix and its axis.
+    pub fn thread(profile: &Wire, helix: &Wire) -> Result<Self, Error> {
+        let mut scale_towards_axis = ffi::gp::new_gp_GTrsf();
+        scale_towards_axis.pin_mut().SetValue(1, 1, 0.5);
+        scale_towards_axis.pin_mut().SetValue(2, 2, 0.5);
+
+        let copy = true;
+        let mut make_auxiliary_spine = ffi::b_rep_builder_api::BRepBuilderAPI_GTransform_new(
+            ffi::topo_ds::cast_wire_to_shape(&helix.inner),
+            &scale_towards_axis,
+            copy,
+        );
+        let auxiliary_spine =
+            Wire::from_wire(ffi::topo_ds::TopoDS::Wire(make_auxiliary_spine.pin_mut().Shape()));
+
+        SweepBuilder::new(helix)
+            .profile(profile)
+            .mode(SweepMode::AuxiliarySpine {
+                spine: &auxiliary_spine,
+                curvilinear_equivalence: true,
+                contact: AuxiliarySpineContact::NoContact,
+            })
+            .build()
+    }
 }
 
 /// Information about a point where a line hits (i.e. intersects) a face
@@ -854,3 +1367,194 @@ impl ChamferMaker {
         Shape::from_shape(self.inner.pin_mut().Shape())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{angle::Angle, hole::MetricSize};
+    use std::f64::consts::PI;
+
+    const M8_PITCH: f64 = 1.25;
+
+    fn nearest_hit(shape: &Shape, origin: DVec3, dir: DVec3) -> f64 {
+        shape.faces_along_line(origin, dir).iter().map(|hit| hit.t).fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn rib_fills_a_channel_up_to_the_profile() {
+        // A channel open at the top and at both ends, with a floor at z = 2.
+        let channel = Shape::box_with_dimensions(20.0, 10.0, 10.0)
+            .subtract(&Shape::box_from_corners(dvec3(2.0, -1.0, 2.0), dvec3(18.0, 11.0, 11.0)))
+            .shape;
+
+        // The profile spans the channel wall to wall, 4 units above its floor.
+        let profile =
+            Wire::from_edges([&Edge::segment(dvec3(2.0, 5.0, 6.0), dvec3(18.0, 5.0, 6.0))]);
+        let ribbed = channel.rib(&profile, DVec3::Y, 2.0).unwrap();
+
+        // Inside the rib, the first face below is the top of the rib.
+        assert!((nearest_hit(&ribbed, dvec3(10.0, 5.0, 20.0), DVec3::NEG_Z) - 14.0).abs() < 1.0e-6);
+
+        // Next to the rib, the channel floor is still exposed.
+        assert!((nearest_hit(&ribbed, dvec3(10.0, 1.0, 20.0), DVec3::NEG_Z) - 18.0).abs() < 1.0e-6);
+    }
+
+    /// The minor radius of an M8 thread, 5/8 of the fundamental triangle height
+    /// below its major radius.
+    fn m8_minor_radius() -> f64 {
+        4.0 - 5.0 / 8.0 * (3.0f64.sqrt() / 2.0 * M8_PITCH)
+    }
+
+    fn m8_thread(height: f64) -> Shape {
+        let profile = Wire::iso_thread_profile(8.0, M8_PITCH).unwrap();
+        let helix = Wire::helix(M8_PITCH, height, 3.5, false, Angle::Degrees(0.0));
+
+        Shape::thread(&profile, &helix).unwrap()
+    }
+
+    #[test]
+    fn helix_rises_by_pitch_per_turn() {
+        let endpoints = |helix: &Wire| {
+            let edge = Shape::from(helix).edges().next().unwrap();
+            (edge.start_point(), edge.end_point())
+        };
+
+        // Two and a half turns end on the opposite side of the axis.
+        let (start, end) = endpoints(&Wire::helix(2.0, 5.0, 3.0, false, Angle::Degrees(0.0)));
+        assert!(start.abs_diff_eq(dvec3(3.0, 0.0, 0.0), 1.0e-6));
+        assert!(end.abs_diff_eq(dvec3(-3.0, 0.0, 5.0), 1.0e-6));
+
+        // A quarter turn shows which way the helix winds.
+        let (_, end) = endpoints(&Wire::helix(4.0, 1.0, 3.0, false, Angle::Degrees(0.0)));
+        assert!(end.abs_diff_eq(dvec3(0.0, 3.0, 1.0), 1.0e-6));
+        let (_, end) = endpoints(&Wire::helix(4.0, 1.0, 3.0, true, Angle::Degrees(0.0)));
+        assert!(end.abs_diff_eq(dvec3(0.0, -3.0, 1.0), 1.0e-6));
+    }
+
+    #[test]
+    fn iso_thread_profile_rejects_coarse_pitch() {
+        assert!(matches!(Wire::iso_thread_profile(1.0, 2.0), Err(Error::InvalidThread(_))));
+        assert!(matches!(Wire::iso_thread_profile(8.0, 0.0), Err(Error::InvalidThread(_))));
+    }
+
+    #[test]
+    fn external_iso_thread() {
+        let thread = m8_thread(10.0);
+        assert_eq!(thread.shape_type(), ShapeType::Solid);
+        assert!(thread.is_valid());
+
+        let bolt = Shape::cylinder_radius_height(m8_minor_radius(), 10.0).union(&thread).shape;
+        assert!(bolt.is_valid());
+
+        // Looking in at the axis, the crest of a tooth sits at the major diameter
+        // and the root between two teeth at the minor diameter.
+        let crest = 10.0 - nearest_hit(&bolt, dvec3(10.0, 0.0, 4.0 * M8_PITCH), DVec3::NEG_X);
+        assert!((crest - 4.0).abs() < 1.0e-2);
+
+        let root = 10.0 - nearest_hit(&bolt, dvec3(10.0, 0.0, 4.5 * M8_PITCH), DVec3::NEG_X);
+        assert!((root - m8_minor_radius()).abs() < 1.0e-2);
+    }
+
+    #[test]
+    fn internal_iso_thread() {
+        let thread = m8_thread(10.0);
+
+        let drill = Shape::cylinder(dvec3(0.0, 0.0, -1.0), m8_minor_radius(), DVec3::Z, 12.0);
+        let nut = Shape::box_from_corners(dvec3(-8.0, -8.0, 0.0), dvec3(8.0, 8.0, 10.0))
+            .subtract(&drill)
+            .shape
+            .subtract(&thread)
+            .shape;
+        assert!(nut.is_valid());
+
+        // Looking out from the axis, the groove cut by a tooth reaches the major
+        // diameter and the ridge between two grooves stays at the minor diameter.
+        let groove = nearest_hit(&nut, dvec3(0.0, 0.0, 4.0 * M8_PITCH), DVec3::X);
+        assert!((groove - 4.0).abs() < 1.0e-2);
+
+        let ridge = nearest_hit(&nut, dvec3(0.0, 0.0, 4.5 * M8_PITCH), DVec3::X);
+        assert!((ridge - m8_minor_radius()).abs() < 1.0e-2);
+    }
+
+    /// Drill `hole` down into the middle of the top of a 20 x 20 x 10 box, and
+    /// return the removed volume and the sorted areas of the tagged hole faces.
+    fn drill_box(hole: &Hole) -> (f64, Vec<f64>) {
+        let block = Shape::box_with_dimensions(20.0, 20.0, 10.0);
+        let drilled = block.hole(dvec3(10.0, 10.0, 10.0), DVec3::NEG_Z, hole).unwrap();
+        assert!(drilled.shape.is_valid());
+
+        let mut areas: Vec<_> =
+            drilled.hole_faces.iter().map(|hole_face| hole_face.face.surface_area()).collect();
+        areas.sort_by(f64::total_cmp);
+
+        (block.volume() - drilled.shape.volume(), areas)
+    }
+
+    #[test]
+    fn counterbored_hole() {
+        // An M4 counterbore is 8 wide and 4 deep, over a 4.5 wide clearance hole.
+        let (removed, areas) = drill_box(&Hole::counterbored(MetricSize::M4));
+
+        assert!((removed - PI * (4.0 * 4.0 * 4.0 + 2.25 * 2.25 * 6.0)).abs() < 1.0e-4);
+
+        // The counterbore shoulder, the clearance hole wall and the counterbore wall.
+        let expected = [PI * (4.0 * 4.0 - 2.25 * 2.25), PI * 4.5 * 6.0, PI * 8.0 * 4.0];
+        assert_eq!(areas.len(), expected.len());
+        for (area, expected) in areas.iter().zip(expected) {
+            assert!((area - expected).abs() < 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn countersunk_hole() {
+        // An M4 countersink is 8.96 wide at 90 degrees, over a 4.5 wide clearance hole.
+        let (removed, areas) = drill_box(&Hole::countersunk(MetricSize::M4));
+
+        let (sink_radius, radius) = (4.48, 2.25);
+        let sink_depth = sink_radius - radius;
+        let sink_volume = PI * sink_depth / 3.0
+            * (sink_radius * sink_radius + sink_radius * radius + radius * radius);
+        let bore_volume = PI * radius * radius * (10.0 - sink_depth);
+        assert!((removed - sink_volume - bore_volume).abs() < 1.0e-4);
+
+        // The clearance hole wall and the cone of the countersink.
+        let cone_area = PI * (sink_radius + radius) * sink_depth * 2.0f64.sqrt();
+        let cylinder_area = 2.0 * PI * radius * (10.0 - sink_depth);
+        let mut expected = [cone_area, cylinder_area];
+        expected.sort_by(f64::total_cmp);
+
+        assert_eq!(areas.len(), expected.len());
+        for (area, expected) in areas.iter().zip(expected) {
+            assert!((area - expected).abs() < 1.0e-4);
+        }
+    }
+
+    #[test]
+    fn stream_round_trips() {
+        let shape = Shape::box_with_dimensions(1.0, 2.0, 3.0);
+
+        let check = |format: &str, read: Result<Shape, Error>| {
+            let read = read.unwrap();
+            let area: f64 = read.faces().map(|face| face.surface_area()).sum();
+
+            assert_eq!(read.faces().count(), 6, "{format}");
+            assert!((area - 22.0).abs() < 1.0e-6, "{format}");
+        };
+
+        let mut data = vec![];
+        shape.write_step_to(&mut data).unwrap();
+        check("STEP", Shape::read_step_from(data.as_slice()));
+
+        let mut data = vec![];
+        shape.write_iges_to(&mut data).unwrap();
+        check("IGES", Shape::read_iges_from(data.as_slice()));
+
+        let mut data = vec![];
+        shape.write_brep_text_to(&mut data).unwrap();
+        check("BREP", Shape::read_brep_text_from(data.as_slice()));
+
+        let mut data = vec![];
+        shape.write_brep_bin_to(&mut data).unwrap();
+        check("binary BREP", Shape::read_brep_bin_from(data.as_slice()));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::primitives::Wire;
+use crate::{
+    primitives::{Face, ShapeType, Wire},
+    Error,
+};
 use cxx::UniquePtr;
 use opencascade_sys as ffi;
 
@@ -19,6 +22,30 @@ impl Shell {
         Self { inner }
     }
 
+    /// Sew `faces` together along edges which are within `tolerance` of each
+    /// other. Fails if the faces don't end up forming a single shell.
+    pub fn sew<T: AsRef<Face>>(
+        faces: impl IntoIterator<Item = T>,
+        tolerance: f64,
+    ) -> Result<Self, Error> {
+        let mut sewing = ffi::b_rep_builder_api::BRepBuilderAPI_Sewing_new(tolerance);
+
+        for face in faces {
+            sewing.pin_mut().Add(ffi::topo_ds::cast_face_to_shape(&face.as_ref().inner));
+        }
+
+        sewing.pin_mut().Perform(&ffi::message::Message_ProgressRange_new());
+
+        let sewed_shape = sewing.SewedShape();
+        let shape_type: ShapeType = sewed_shape.ShapeType().into();
+
+        if shape_type != ShapeType::Shell {
+            return Err(Error::SewingFailed);
+        }
+
+        Ok(Self::from_shell(ffi::topo_ds::TopoDS::Shell(sewed_shape)))
+    }
+
     pub fn loft<T: AsRef<Wire>>(wires: impl IntoIterator<Item = T>) -> Self {
         let is_solid = false;
         let mut make_loft = ffi::b_rep_offset_api::BRepOffsetAPI_ThruSections_new(is_solid);
@@ -36,3 +63,21 @@ impl Shell {
         Self::from_shell(shell)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::{Shape, Solid};
+
+    #[test]
+    fn sew_box_faces_into_solid() {
+        let faces: Vec<_> = Shape::box_centered(1.0, 2.0, 3.0).faces().collect();
+
+        let shell = Shell::sew(&faces, 1.0e-6).unwrap();
+        let solid = Solid::from_shell(&shell).unwrap();
+        let shape = Shape::from(solid);
+
+        assert_eq!(shape.shape_type(), ShapeType::Solid);
+        assert_eq!(shape.faces().count(), 6);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    primitives::{BooleanShape, Compound, Edge, Face, Shape, Wire},
+    primitives::{BooleanShape, Compound, Edge, Face, Shape, Shell, Wire},
     Error,
 };
 use cxx::UniquePtr;
@@ -23,6 +23,25 @@ impl Solid {
         Self { inner }
     }
 
+    /// Make a solid bounded by `shell`, which must be closed. The shell is
+    /// reoriented if needed so the solid doesn't end up inside out.
+    pub fn from_shell(shell: &Shell) -> Result<Self, Error> {
+        let mut make_solid = ffi::b_rep_builder_api::BRepBuilderAPI_MakeSolid_new(&shell.inner);
+        make_solid.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_solid.IsDone() {
+            return Err(Error::SolidConstructionFailed);
+        }
+
+        let mut solid = Self::from_solid(ffi::topo_ds::TopoDS::Solid(make_solid.pin_mut().Shape()));
+
+        if !ffi::b_rep_lib::BRepLib::OrientClosedSolid(solid.inner.pin_mut()) {
+            return Err(Error::SolidConstructionFailed);
+        }
+
+        Ok(solid)
+    }
+
     // TODO(bschwind) - Do some cool stuff from this link:
     // https://neweopencascade.wordpress.com/2018/10/17/lets-talk-about-fillets/
     // Key takeaway: Use the `SectionEdges` function to retrieve edges that were
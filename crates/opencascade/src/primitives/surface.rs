@@ -1,4 +1,4 @@
-use crate::primitives::make_point;
+use crate::{primitives::make_point, Error};
 use cxx::UniquePtr;
 use glam::DVec3;
 use opencascade_sys as ffi;
@@ -7,28 +7,254 @@ pub struct Surface {
     pub(crate) inner: UniquePtr<ffi::geom::Handle_Geom_Surface>,
 }
 
+/// The knot vector of one parametric direction of a B-spline surface.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BSplineKnots {
+    pub degree: u32,
+    /// The distinct knot values, in increasing order.
+    pub knots: Vec<f64>,
+    /// How many times each knot in `knots` is repeated.
+    pub multiplicities: Vec<u32>,
+}
+
+impl BSplineKnots {
+    /// A uniform knot vector over `[0, 1]` which makes the surface pass through
+    /// the poles at its boundary.
+    pub fn clamped_uniform(pole_count: usize, degree: u32) -> Result<Self, Error> {
+        if pole_count <= degree as usize {
+            return Err(Error::NotEnoughPoles { pole_count, degree });
+        }
+
+        let knot_count = pole_count + 1 - degree as usize;
+        let knots = (0..knot_count).map(|i| i as f64 / (knot_count - 1) as f64).collect::<Vec<_>>();
+
+        let mut multiplicities = vec![1; knot_count];
+        multiplicities[0] = degree + 1;
+        multiplicities[knot_count - 1] = degree + 1;
+
+        Ok(Self { degree, knots, multiplicities })
+    }
+
+    fn to_arrays(
+        &self,
+    ) -> (
+        UniquePtr<ffi::t_col_std::TColStd_Array1OfReal>,
+        UniquePtr<ffi::t_col_std::TColStd_Array1OfInteger>,
+    ) {
+        let mut knots = ffi::t_col_std::TColStd_Array1OfReal_new(1, self.knots.len() as i32);
+        for (index, knot) in self.knots.iter().enumerate() {
+            knots.pin_mut().SetValue(index as i32 + 1, knot);
+        }
+
+        let mut multiplicities =
+            ffi::t_col_std::TColStd_Array1OfInteger_new(1, self.multiplicities.len() as i32);
+        for (index, multiplicity) in self.multiplicities.iter().enumerate() {
+            multiplicities.pin_mut().SetValue(index as i32 + 1, &(*multiplicity as i32));
+        }
+
+        (knots, multiplicities)
+    }
+}
+
 impl Surface {
     pub fn bezier(poles: impl IntoIterator<Item = impl IntoIterator<Item = DVec3>>) -> Self {
-        let poles: Vec<Vec<_>> =
-            poles.into_iter().map(|poles| poles.into_iter().collect()).collect();
-
-        let mut pole_array = ffi::t_col_gp::TColgp_Array2OfPnt_new(
-            0,
-            poles.len() as i32 - 1,
-            0,
-            poles.first().map(|first| first.len()).unwrap_or(0) as i32 - 1,
+        let poles = collect_grid(poles);
+        let pole_array = make_point_grid(&poles, 0);
+
+        let bezier = ffi::geom::Geom_BezierSurface_new(&pole_array);
+        let inner = ffi::geom::bezier_to_surface(&bezier);
+
+        Self { inner }
+    }
+
+    /// Create a non-rational B-spline surface from a grid of poles, where rows
+    /// run along U and columns along V.
+    pub fn bspline(
+        poles: impl IntoIterator<Item = impl IntoIterator<Item = DVec3>>,
+        u_knots: &BSplineKnots,
+        v_knots: &BSplineKnots,
+    ) -> Result<Self, Error> {
+        let poles = collect_grid(poles);
+        let weights = poles.iter().map(|row| vec![1.0; row.len()]).collect::<Vec<_>>();
+
+        Self::rational_bspline(poles, weights, u_knots, v_knots)
+    }
+
+    /// Create a rational B-spline surface from a grid of poles and a matching
+    /// grid of weights.
+    pub fn rational_bspline(
+        poles: impl IntoIterator<Item = impl IntoIterator<Item = DVec3>>,
+        weights: impl IntoIterator<Item = impl IntoIterator<Item = f64>>,
+        u_knots: &BSplineKnots,
+        v_knots: &BSplineKnots,
+    ) -> Result<Self, Error> {
+        let poles = collect_grid(poles);
+        let weights = collect_grid(weights);
+
+        let shape_matches = poles.len() == weights.len()
+            && poles.iter().zip(&weights).all(|(poles, weights)| poles.len() == weights.len());
+        if !is_rectangular(&poles) || !shape_matches {
+            return Err(Error::SurfaceConstructionFailed);
+        }
+
+        let pole_array = make_point_grid(&poles, 1);
+
+        let column_count = weights.first().map(Vec::len).unwrap_or(0);
+        let mut weight_array = ffi::t_col_std::TColStd_Array2OfReal_new(
+            1,
+            weights.len() as i32,
+            1,
+            column_count as i32,
         );
+        for (row, weights) in weights.iter().enumerate() {
+            for (column, weight) in weights.iter().enumerate() {
+                weight_array.pin_mut().SetValue(row as i32 + 1, column as i32 + 1, weight);
+            }
+        }
+
+        let (u_knot_array, u_mult_array) = u_knots.to_arrays();
+        let (v_knot_array, v_mult_array) = v_knots.to_arrays();
+
+        let bspline = ffi::geom::Geom_BSplineSurface_new(
+            &pole_array,
+            &weight_array,
+            &u_knot_array,
+            &v_knot_array,
+            &u_mult_array,
+            &v_mult_array,
+            u_knots.degree as i32,
+            v_knots.degree as i32,
+        )
+        .map_err(|_| Error::SurfaceConstructionFailed)?;
+        let inner = ffi::geom::bspline_to_surface(&bspline);
+
+        Ok(Self { inner })
+    }
+
+    /// Create a B-spline surface passing exactly through a grid of points,
+    /// where rows run along U and columns along V.
+    pub fn interpolate(
+        points: impl IntoIterator<Item = impl IntoIterator<Item = DVec3>>,
+    ) -> Result<Self, Error> {
+        let points = collect_grid(points);
+        if !is_rectangular(&points) || points.len() < 2 || points[0].len() < 2 {
+            return Err(Error::NotEnoughPoints);
+        }
+
+        let point_array = make_point_grid(&points, 1);
+
+        let periodic = false;
+        let mut interpolation = ffi::geom_api::GeomAPI_PointsToBSplineSurface_new_unbuilt();
+        interpolation
+            .pin_mut()
+            .Interpolate(&point_array, periodic)
+            .map_err(|_| Error::SurfaceConstructionFailed)?;
+
+        if !interpolation.IsDone() {
+            return Err(Error::SurfaceConstructionFailed);
+        }
+
+        let bspline = ffi::geom_api::GeomAPI_PointsToBSplineSurface_Surface(&interpolation);
+        let inner = ffi::geom::bspline_to_surface(&bspline);
+
+        Ok(Self { inner })
+    }
+}
+
+fn collect_grid<T>(grid: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Vec<Vec<T>> {
+    grid.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+fn is_rectangular<T>(grid: &[Vec<T>]) -> bool {
+    let column_count = grid.first().map(Vec::len).unwrap_or(0);
+    column_count > 0 && grid.iter().all(|row| row.len() == column_count)
+}
+
+fn make_point_grid(
+    points: &[Vec<DVec3>],
+    lower_bound: i32,
+) -> UniquePtr<ffi::t_col_gp::TColgp_Array2OfPnt> {
+    let mut point_array = ffi::t_col_gp::TColgp_Array2OfPnt_new(
+        lower_bound,
+        lower_bound + points.len() as i32 - 1,
+        lower_bound,
+        lower_bound + points.first().map(|first| first.len()).unwrap_or(0) as i32 - 1,
+    );
+
+    for (row, points) in points.iter().enumerate() {
+        for (column, point) in points.iter().enumerate() {
+            let point = &make_point(*point);
+            point_array.pin_mut().SetValue(
+                lower_bound + row as i32,
+                lower_bound + column as i32,
+                point,
+            );
+        }
+    }
+
+    point_array
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::{Face, Shape};
+    use glam::dvec3;
 
-        for (row, poles) in poles.iter().enumerate() {
-            for (column, pole) in poles.iter().enumerate() {
-                let pole = &make_point(*pole);
-                pole_array.pin_mut().SetValue(row as i32, column as i32, pole);
+    #[test]
+    fn clamped_uniform_knots() {
+        let knots = BSplineKnots::clamped_uniform(5, 3).unwrap();
+
+        assert_eq!(knots.knots, vec![0.0, 0.5, 1.0]);
+        assert_eq!(knots.multiplicities, vec![4, 1, 4]);
+        // The multiplicities of a valid knot vector sum to poles + degree + 1.
+        assert_eq!(knots.multiplicities.iter().sum::<u32>(), 5 + 3 + 1);
+    }
+
+    #[test]
+    fn clamped_uniform_needs_more_poles_than_degree() {
+        assert!(matches!(
+            BSplineKnots::clamped_uniform(3, 3),
+            Err(Error::NotEnoughPoles { pole_count: 3, degree: 3 })
+        ));
+    }
+
+    #[test]
+    fn interpolated_surface_passes_through_points() {
+        let height = |x: f64, y: f64| (x * 0.7).sin() + (y * 0.4).cos();
+        let points: Vec<Vec<DVec3>> = (0..5)
+            .map(|i| {
+                (0..5).map(|j| dvec3(i as f64, j as f64, height(i as f64, j as f64))).collect()
+            })
+            .collect();
+
+        let face = Face::from_surface(&Surface::interpolate(points.clone()).unwrap());
+        let shape = Shape::from(&face);
+
+        // The points at the boundary of the grid lie on the edges of the face,
+        // so only check the interior ones.
+        for row in &points[1..4] {
+            for &point in &row[1..4] {
+                let hits = shape.faces_along_line(point + DVec3::Z * 10.0, DVec3::NEG_Z);
+                assert_eq!(hits.len(), 1);
+                assert!(hits[0].point.abs_diff_eq(point, 1.0e-6));
             }
         }
+    }
 
-        let bezier = ffi::geom::Geom_BezierSurface_new(&pole_array);
-        let inner = ffi::geom::bezier_to_surface(&bezier);
+    #[test]
+    fn bilinear_bspline_surface() {
+        let knots = BSplineKnots::clamped_uniform(2, 1).unwrap();
+        let poles = [
+            [dvec3(0.0, 0.0, 0.0), dvec3(0.0, 10.0, 0.0)],
+            [dvec3(10.0, 0.0, 0.0), dvec3(10.0, 10.0, 0.0)],
+        ];
 
-        Self { inner }
+        let face = Face::from_surface(&Surface::bspline(poles, &knots, &knots).unwrap());
+        assert!((face.surface_area() - 100.0).abs() < 1.0e-6);
+
+        let weights = [[1.0, 1.0]];
+        let mismatched = Surface::rational_bspline(poles, weights, &knots, &knots);
+        assert!(matches!(mismatched, Err(Error::SurfaceConstructionFailed)));
     }
 }
@@ -1,14 +1,23 @@
 use crate::{
     angle::{Angle, ToAngle},
+    dxf,
+    geometry::{Axis1, Transform},
     law_function::law_function_from_graph,
     make_pipe_shell::make_pipe_shell_with_law_function,
     primitives::{make_dir, make_point, make_vec, Edge, Face, JoinType, Shape, Shell},
+    workplane::Workplane,
     Error,
 };
 use cxx::UniquePtr;
 use glam::{dvec3, DVec3};
 use opencascade_sys as ffi;
-use std::iter::once;
+use std::{
+    f64::consts::TAU,
+    fs::File,
+    io::{BufReader, Read},
+    iter::once,
+    path::Path,
+};
 
 pub struct Wire {
     pub(crate) inner: UniquePtr<ffi::topo_ds::TopoDS_Wire>,
@@ -84,6 +93,20 @@ impl Wire {
         unordered_edges: impl IntoIterator<Item = T>,
         edge_connection: EdgeConnection,
     ) -> Self {
+        let mut make_wire = ffi::b_rep_builder_api::BRepBuilderAPI_MakeWire_new();
+
+        for wire in Self::connect_edges(unordered_edges, edge_connection) {
+            make_wire.pin_mut().add_wire(&wire.inner);
+        }
+
+        Self::from_make_wire(make_wire)
+    }
+
+    /// Connect unordered edges into as many wires as needed.
+    pub(crate) fn connect_edges<T: AsRef<Edge>>(
+        unordered_edges: impl IntoIterator<Item = T>,
+        edge_connection: EdgeConnection,
+    ) -> Vec<Self> {
         let mut edges = ffi::top_tools::new_Handle_TopTools_HSequenceOfShape();
 
         for edge in unordered_edges {
@@ -105,18 +128,42 @@ impl Wire {
             wires.pin_mut(),
         );
 
-        let mut make_wire = ffi::b_rep_builder_api::BRepBuilderAPI_MakeWire_new();
-
         let wire_len = ffi::top_tools::TopTools_HSequenceOfShape_length(&wires);
 
-        for index in 1..=wire_len {
-            let wire_shape = ffi::top_tools::TopTools_HSequenceOfShape_value(&wires, index);
-            let wire = ffi::topo_ds::TopoDS::Wire(wire_shape);
+        (1..=wire_len)
+            .map(|index| {
+                let wire_shape = ffi::top_tools::TopTools_HSequenceOfShape_value(&wires, index);
+                Self::from_wire(ffi::topo_ds::TopoDS::Wire(wire_shape))
+            })
+            .collect()
+    }
 
-            make_wire.pin_mut().add_wire(wire);
+    /// Read the lines, arcs, circles, polylines and splines of a DXF file, in
+    /// the XY plane, and connect them into a wire. Fails if they don't connect
+    /// into a single wire.
+    pub fn from_dxf(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::read_dxf(BufReader::new(File::open(path)?))
+    }
+
+    fn read_dxf(reader: impl Read) -> Result<Self, Error> {
+        let entities = dxf::read_dxf(reader)?;
+
+        let plane = Workplane::xy();
+        let mut edges = vec![];
+        for entity in &entities {
+            edges.extend(entity.curve.to_edges(&plane)?);
         }
 
-        Self::from_make_wire(make_wire)
+        let mut make_wire = ffi::b_rep_builder_api::BRepBuilderAPI_MakeWire_new();
+        for wire in Self::connect_edges(edges, EdgeConnection::default()) {
+            make_wire.pin_mut().add_wire(&wire.inner);
+        }
+
+        if !make_wire.IsDone() {
+            return Err(Error::DxfReadFailed("the outlines don't form a single wire".to_string()));
+        }
+
+        Ok(Self::from_make_wire(make_wire))
     }
 
     pub fn from_wires<'a>(wires: impl IntoIterator<Item = &'a Wire>) -> Self {
@@ -131,22 +178,14 @@ impl Wire {
 
     #[must_use]
     pub fn mirror_along_axis(&self, axis_origin: DVec3, axis_dir: DVec3) -> Self {
-        let axis_dir = make_dir(axis_dir);
-        let axis = ffi::gp::gp_Ax1_new(&make_point(axis_origin), &axis_dir);
-
-        let mut transform = ffi::gp::new_transform();
-
-        transform.pin_mut().set_mirror_axis(&axis);
-
-        let wire_shape = ffi::topo_ds::cast_wire_to_shape(&self.inner);
-
-        let mut brep_transform =
-            ffi::b_rep_builder_api::BRepBuilderAPI_Transform_new(wire_shape, &transform, false);
-
-        let mirrored_shape = brep_transform.pin_mut().Shape();
-        let mirrored_wire = ffi::topo_ds::TopoDS::Wire(mirrored_shape);
+        self.mirror_along((axis_origin, axis_dir))
+    }
 
-        Self::from_wire(mirrored_wire)
+    /// Mirror this wire around `axis`, which is the same as rotating it a half
+    /// turn around it.
+    #[must_use]
+    pub fn mirror_along(&self, axis: impl Into<Axis1>) -> Self {
+        self.transformed(&Transform::mirror_axis(axis))
     }
 
     pub fn rect(width: f64, height: f64) -> Self {
@@ -166,6 +205,93 @@ impl Wire {
         Self::from_edges([&top, &right, &bottom, &left])
     }
 
+    /// Create a helix around the Z axis, starting at `(radius, 0, 0)` and rising by
+    /// `pitch` every turn until it reaches `height`.
+    ///
+    /// The helix is built as a straight line in the parameter space of a cylinder,
+    /// or of a cone when `taper_angle` is non-zero. A positive taper angle makes
+    /// the radius grow along +Z.
+    #[must_use]
+    pub fn helix(
+        pitch: f64,
+        height: f64,
+        radius: f64,
+        left_handed: bool,
+        taper_angle: Angle,
+    ) -> Self {
+        let axis = ffi::gp::gp_Ax2_new_with_x_dir(
+            &make_point(DVec3::ZERO),
+            &make_dir(DVec3::Z),
+            &make_dir(DVec3::X),
+        );
+        let axis = ffi::gp::gp_Ax3_from_gp_Ax2(&axis);
+
+        // On both surfaces U is the angle around the axis. V is the height along
+        // the axis for a cylinder, and the distance along the slant for a cone.
+        let taper = taper_angle.radians();
+        let (surface, v_per_turn) = if taper == 0.0 {
+            let cylinder = ffi::geom::Geom_CylindricalSurface_new(&axis, radius);
+            (ffi::geom::cylinder_to_surface(&cylinder), pitch)
+        } else {
+            let cone = ffi::geom::Geom_ConicalSurface_new(&axis, taper, radius);
+            (ffi::geom::cone_to_surface(&cone), pitch / taper.cos())
+        };
+
+        let u_per_turn = if left_handed { -TAU } else { TAU };
+        let turns = height / pitch;
+
+        let line = ffi::geom2d::Geom2d_Line_new(
+            &ffi::gp::new_point_2d(0.0, 0.0),
+            &ffi::gp::gp_Dir2d_new(u_per_turn, v_per_turn),
+        );
+        let line = ffi::geom2d::line_to_HandleGeom2d_Curve(&line);
+
+        let length = turns * u_per_turn.hypot(v_per_turn);
+        let segment = ffi::geom2d::Geom2d_TrimmedCurve_new(&line, 0.0, length);
+        let segment = ffi::geom2d::HandleGeom2d_TrimmedCurve_to_curve(&segment);
+
+        let mut make_edge =
+            ffi::b_rep_builder_api::BRepBuilderAPI_MakeEdge_CurveSurface2d(&segment, &surface);
+        let edge = Edge::from_edge(make_edge.pin_mut().Edge());
+        ffi::b_rep_lib::BRepLib::BuildCurves3d(ffi::topo_ds::cast_edge_to_shape(&edge.inner));
+
+        Self::from_edges([&edge])
+    }
+
+    /// Create the closed profile of an ISO metric thread tooth with the given major
+    /// diameter and pitch, in the XZ plane and centered on Z = 0.
+    ///
+    /// The tooth points along +X and extends slightly below the minor diameter so
+    /// it overlaps the core it is fused with. The same profile cuts an internal
+    /// thread out of a hole drilled at the minor diameter.
+    pub fn iso_thread_profile(major_diameter: f64, pitch: f64) -> Result<Self, Error> {
+        if pitch <= 0.0 {
+            return Err(Error::InvalidThread(format!("pitch must be positive, got {pitch}")));
+        }
+
+        let fundamental_height = 3.0f64.sqrt() / 2.0 * pitch;
+        let major_radius = major_diameter / 2.0;
+        let minor_radius = major_radius - 5.0 / 8.0 * fundamental_height;
+        let core_radius = minor_radius - fundamental_height / 8.0;
+
+        if core_radius <= 0.0 {
+            return Err(Error::InvalidThread(format!(
+                "a pitch of {pitch} is too coarse for a major diameter of {major_diameter}"
+            )));
+        }
+
+        let points = [
+            dvec3(core_radius, 0.0, -3.0 / 8.0 * pitch),
+            dvec3(minor_radius, 0.0, -3.0 / 8.0 * pitch),
+            dvec3(major_radius, 0.0, -pitch / 16.0),
+            dvec3(major_radius, 0.0, pitch / 16.0),
+            dvec3(minor_radius, 0.0, 3.0 / 8.0 * pitch),
+            dvec3(core_radius, 0.0, 3.0 / 8.0 * pitch),
+        ];
+
+        Self::from_ordered_points(points)
+    }
+
     #[must_use]
     pub fn fillet(&self, radius: f64) -> Wire {
         // Create a face from this wire
@@ -255,6 +381,39 @@ impl Wire {
         Self::from_wire(translated_wire)
     }
 
+    /// Rotate this wire by `angle` around `rotation_axis`, then translate it by
+    /// `translation`.
+    #[must_use]
+    pub fn transform_around(
+        &self,
+        translation: DVec3,
+        rotation_axis: impl Into<Axis1>,
+        angle: Angle,
+    ) -> Self {
+        let transform =
+            Transform::rotation(rotation_axis, angle).then(&Transform::translation(translation));
+
+        self.transformed(&transform)
+    }
+
+    /// Apply a transform which may also scale, mirror or shear this wire.
+    #[must_use]
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let shape = transform.apply(ffi::topo_ds::cast_wire_to_shape(&self.inner));
+
+        Self::from_wire(ffi::topo_ds::TopoDS::Wire(&shape.inner))
+    }
+
+    /// Write this wire to a DXF file, projected onto `plane`.
+    pub fn write_dxf(&self, path: impl AsRef<Path>, plane: &Workplane) -> Result<(), Error> {
+        Shape::from(self).write_dxf(path, plane)
+    }
+
+    /// Write this wire to an SVG file, projected onto `plane`.
+    pub fn write_svg(&self, path: impl AsRef<Path>, plane: &Workplane) -> Result<(), Error> {
+        Shape::from(self).write_svg(path, plane)
+    }
+
     pub fn to_face(self) -> Face {
         let only_plane = false;
         let make_face =
@@ -292,3 +451,34 @@ impl WireBuilder {
         Wire::from_make_wire(self.inner)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dxf_skips_degenerate_entities() {
+        let line = |(x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+            format!("0\nLINE\n8\n0\n10\n{x1}\n20\n{y1}\n11\n{x2}\n21\n{y2}\n")
+        };
+        let arc = |(x, y): (f64, f64), radius: f64, start: f64, end: f64| {
+            format!("0\nARC\n8\n0\n10\n{x}\n20\n{y}\n40\n{radius}\n50\n{start}\n51\n{end}\n")
+        };
+
+        let dxf = [
+            "0\nSECTION\n2\nENTITIES\n".to_string(),
+            line((0.0, 0.0), (10.0, 0.0)),
+            line((10.0, 0.0), (10.0, 0.0)),
+            line((10.0, 0.0), (10.0, 10.0)),
+            arc((10.0, 10.0), 0.0, 0.0, 90.0),
+            line((10.0, 10.0), (0.0, 10.0)),
+            line((0.0, 10.0), (0.0, 0.0)),
+            arc((0.0, 0.0), 2.0, 45.0, 45.0 + 1.0e-9),
+            "0\nENDSEC\n0\nEOF\n".to_string(),
+        ]
+        .concat();
+
+        let wire = Wire::read_dxf(dxf.as_bytes()).unwrap();
+        assert_eq!(Shape::from(&wire).edges().count(), 4);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    primitives::{make_dir, make_point, Edge, Shape},
+    Error,
+};
+use cxx::UniquePtr;
+use ffi::hlr_b_rep::HLRBRep_TypeOfResultingEdge;
+use glam::DVec3;
+use opencascade_sys as ffi;
+
+/// The edges of a projection, grouped by the kind of edge they come from.
+#[derive(Default)]
+pub struct ProjectedEdges {
+    /// Edges between faces which meet at an angle.
+    pub sharp: Vec<Edge>,
+    /// Edges between faces which meet tangentially, such as the ends of a fillet.
+    pub smooth: Vec<Edge>,
+    /// Silhouettes of curved faces, such as the sides of a cylinder.
+    pub outline: Vec<Edge>,
+}
+
+impl ProjectedEdges {
+    pub fn iter(&self) -> impl Iterator<Item = &Edge> {
+        self.sharp.iter().chain(&self.smooth).chain(&self.outline)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sharp.is_empty() && self.smooth.is_empty() && self.outline.is_empty()
+    }
+}
+
+/// A shape projected onto a view plane with its hidden lines removed, for
+/// technical drawings.
+///
+/// The edges lie in the XY plane, with X pointing to the right of the view and
+/// Y pointing up.
+pub struct Projection2d {
+    pub visible: ProjectedEdges,
+    pub hidden: ProjectedEdges,
+}
+
+impl Projection2d {
+    /// Project the exact geometry of `shape` with `HLRBRep_Algo`.
+    pub fn exact(shape: &Shape, view_direction: DVec3, up: DVec3) -> Result<Self, Error> {
+        let axis = view_axis(view_direction, up);
+        let mut to_shape = ffi::hlr_b_rep::HLRBRep_Algo_project(&shape.inner, &axis)
+            .map_err(|_| Error::ProjectionFailed)?;
+
+        Ok(Self::from_compounds(|edge_type, visible| {
+            ffi::hlr_b_rep::HLRBRep_HLRToShape_compound(to_shape.pin_mut(), edge_type, visible)
+        }))
+    }
+
+    /// Project the triangulation of `shape` with `HLRBRep_PolyAlgo`, which is
+    /// faster than [`Projection2d::exact`], but only produces straight edges.
+    /// The shape is triangulated with `deflection` first.
+    pub fn polygonal(
+        shape: &Shape,
+        view_direction: DVec3,
+        up: DVec3,
+        deflection: f64,
+    ) -> Result<Self, Error> {
+        let axis = view_axis(view_direction, up);
+        let mut to_shape =
+            ffi::hlr_b_rep::HLRBRep_PolyAlgo_project(&shape.inner, &axis, deflection)
+                .map_err(|_| Error::ProjectionFailed)?;
+
+        Ok(Self::from_compounds(|edge_type, visible| {
+            ffi::hlr_b_rep::HLRBRep_PolyHLRToShape_compound(to_shape.pin_mut(), edge_type, visible)
+        }))
+    }
+
+    fn from_compounds(
+        mut compound: impl FnMut(
+            HLRBRep_TypeOfResultingEdge,
+            bool,
+        ) -> UniquePtr<ffi::topo_ds::TopoDS_Shape>,
+    ) -> Self {
+        let mut edges = |edge_type, visible| -> Vec<Edge> {
+            Shape::from_shape(&compound(edge_type, visible)).edges().collect()
+        };
+
+        let mut projected_edges = |visible| ProjectedEdges {
+            sharp: edges(HLRBRep_TypeOfResultingEdge::HLRBRep_Sharp, visible),
+            smooth: edges(HLRBRep_TypeOfResultingEdge::HLRBRep_Rg1Line, visible),
+            outline: edges(HLRBRep_TypeOfResultingEdge::HLRBRep_OutLine, visible),
+        };
+
+        Self { visible: projected_edges(true), hidden: projected_edges(false) }
+    }
+}
+
+/// The right and up directions of a view which looks along `view_direction`,
+/// with `up` projected onto the view plane.
+pub(crate) fn view_axes(view_direction: DVec3, up: DVec3) -> (DVec3, DVec3) {
+    let towards_viewer = -view_direction.normalize();
+    let x_dir = up.cross(towards_viewer).try_normalize();
+    // Any right direction will do when looking straight up or down.
+    let x_dir = x_dir.unwrap_or_else(|| towards_viewer.any_orthonormal_vector());
+
+    (x_dir, towards_viewer.cross(x_dir))
+}
+
+/// The coordinate system of a view, with its main direction pointing towards
+/// the viewer.
+fn view_axis(view_direction: DVec3, up: DVec3) -> UniquePtr<ffi::gp::gp_Ax2> {
+    let (x_dir, y_dir) = view_axes(view_direction, up);
+
+    ffi::gp::gp_Ax2_new_with_x_dir(
+        &make_point(DVec3::ZERO),
+        &make_dir(x_dir.cross(y_dir)),
+        &make_dir(x_dir),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cylinder_side_view() {
+        let cylinder = Shape::cylinder_radius_height(1.0, 4.0);
+        let projection = Projection2d::exact(&cylinder, DVec3::Y, DVec3::Z).unwrap();
+
+        // The sides of the cylinder are silhouettes, and the circles at its ends
+        // are seen edge on.
+        assert!(!projection.visible.outline.is_empty());
+        assert!(!projection.visible.sharp.is_empty());
+
+        for edge in projection.visible.iter() {
+            assert!(edge.start_point().z.abs() < 1.0e-9);
+            assert!(edge.start_point().y >= -1.0e-9 && edge.start_point().y <= 4.0 + 1.0e-9);
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::primitives::Shape;
+use crate::{
+    geometry::Plane,
+    primitives::{Edge, EdgeConnection, Shape, Wire},
+};
 use cxx::UniquePtr;
 use opencascade_sys as ffi;
 
@@ -9,25 +12,52 @@ pub struct Section {
 impl Section {
     /// Create a new `Section` to intersect `target` by `tool`.
     pub fn new(target: &Shape, tool: &Shape) -> Section {
-        Section { inner: ffi::b_rep_algo_api::BRepAlgoAPI_Section_new(&target.inner, &tool.inner) }
+        Section {
+            inner: ffi::b_rep_algo_api::BRepAlgoAPI_Section_new_unbuilt(
+                &target.inner,
+                &tool.inner,
+                false,
+            ),
+        }
+    }
+
+    /// Create a new `Section` to intersect `target` by an infinite plane, given
+    /// as a [`Plane`] or an `(origin, normal)` pair.
+    pub fn with_plane(target: &Shape, plane: impl Into<Plane>) -> Section {
+        let plane = plane.into().to_gp_pln();
+
+        Section {
+            inner: ffi::b_rep_algo_api::BRepAlgoAPI_Section_new_with_plane(
+                &target.inner,
+                &plane,
+                false,
+            ),
+        }
+    }
+
+    /// Approximate the resulting edges with BSpline curves, instead of keeping
+    /// the exact intersection curves.
+    pub fn approximated(mut self, approximate: bool) -> Self {
+        self.inner.pin_mut().Approximation(approximate);
+        self
     }
 
     /// Get the edges of the resulting intersection.
-    pub fn section_edges(mut self) -> Vec<Shape> {
+    pub fn section_edges(mut self) -> Vec<Edge> {
+        self.inner.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
         let edges = ffi::topo_ds::shape_list_to_vector(self.inner.pin_mut().SectionEdges());
 
-        let mut vec = vec![];
-
-        for e in edges.iter() {
-            vec.push(Shape::from_shape(e));
-        }
+        edges.iter().map(|edge| Edge::from_edge(ffi::topo_ds::TopoDS::Edge(edge))).collect()
+    }
 
-        vec
+    /// Get the edges of the resulting intersection, connected into wires.
+    pub fn section_wires(self) -> Vec<Wire> {
+        Wire::connect_edges(self.section_edges(), EdgeConnection::default())
     }
 }
 
 /// Creates a `Section` from two shapes, performs the intersection, and returns the resulting edges.
-pub fn edges(target: &Shape, tool: &Shape) -> Vec<Shape> {
+pub fn edges(target: &Shape, tool: &Shape) -> Vec<Edge> {
     let section = Section::new(target, tool);
     section.section_edges()
 }
@@ -35,11 +65,8 @@ pub fn edges(target: &Shape, tool: &Shape) -> Vec<Shape> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{
-        primitives::{IntoShape, ShapeType},
-        workplane::Workplane,
-    };
-    use glam::dvec3;
+    use crate::{primitives::IntoShape, workplane::Workplane};
+    use glam::{dvec3, DVec3};
 
     #[test]
     fn section_new() {
@@ -51,13 +78,18 @@ mod test {
         let edges = s.section_edges();
         assert_eq!(edges.len(), 1);
 
-        let s = edges.first().unwrap();
-
-        assert_eq!(s.shape_type(), ShapeType::Edge);
-
-        let e = s.edges().next().expect("There should be only one edge");
+        let e = edges.first().unwrap();
 
         assert_eq!(e.start_point(), dvec3(0.0, -0.5, 0.0));
         assert_eq!(e.end_point(), dvec3(0.0, 0.5, 0.0));
     }
+
+    #[test]
+    fn section_plane_wires() {
+        let block = Shape::box_with_dimensions(2.0, 2.0, 2.0);
+
+        let wires = Section::with_plane(&block, (dvec3(0.0, 0.0, 1.0), DVec3::Z)).section_wires();
+        assert_eq!(wires.len(), 1);
+        assert_eq!(Shape::from(&wires[0]).edges().count(), 4);
+    }
 }
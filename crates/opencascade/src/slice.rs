@@ -0,0 +1,247 @@
+use crate::{
+    bounding_box,
+    primitives::{Face, Shape, Wire},
+    section::Section,
+    workplane::Workplane,
+    Error,
+};
+use glam::{dvec3, DVec2, DVec3};
+
+/// A closed polygon with holes, in the coordinates of a workplane. The outer
+/// boundary is counter-clockwise, and the holes are clockwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    pub outer: Vec<DVec2>,
+    pub holes: Vec<Vec<DVec2>>,
+}
+
+/// A planar slice through a shape.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    /// The distance of the layer along the slicing direction, from the origin.
+    pub height: f64,
+    /// The plane of the layer, which the polygons are in.
+    pub plane: Workplane,
+    pub polygons: Vec<Polygon>,
+}
+
+/// Slice `shape` with planes facing `plane_normal`, through the middle of every
+/// layer of `layer_height` which the shape spans.
+pub fn slice(shape: &Shape, plane_normal: DVec3, layer_height: f64) -> Result<Vec<Layer>, Error> {
+    if !(layer_height > 0.0 && layer_height.is_finite()) {
+        return Err(Error::InvalidSlice(format!(
+            "layer height must be positive, got {layer_height}"
+        )));
+    }
+
+    let normal = plane_normal.try_normalize().ok_or_else(|| {
+        Error::InvalidSlice(format!("plane normal must be non-zero, got {plane_normal}"))
+    })?;
+    let x_dir =
+        if normal.cross(DVec3::Z).length() < 1.0e-9 { DVec3::X } else { DVec3::Z.cross(normal) };
+    let base_plane = Workplane::new(x_dir, normal);
+
+    let bounds = bounding_box::aabb(shape);
+    // The bounding box is enlarged by its gap on every side.
+    let (min, max) = (bounds.min() + bounds.gap_vec(), bounds.max() - bounds.gap_vec());
+    let corners = (0..8).map(|i| {
+        dvec3(
+            if i & 1 == 0 { min.x } else { max.x },
+            if i & 2 == 0 { min.y } else { max.y },
+            if i & 4 == 0 { min.z } else { max.z },
+        )
+        .dot(normal)
+    });
+    let (bottom, top) = corners
+        .fold((f64::MAX, f64::MIN), |(bottom, top), height| (bottom.min(height), top.max(height)));
+
+    let layer_count = ((top - bottom) / layer_height - 1.0e-6).ceil().max(1.0) as usize;
+    let layers = (0..layer_count)
+        .map(|i| {
+            // The last layer may be thinner than the others.
+            let layer_bottom = bottom + layer_height * i as f64;
+            let height = (layer_bottom + (layer_bottom + layer_height).min(top)) / 2.0;
+            let plane = base_plane.translated(dvec3(0.0, 0.0, height));
+
+            let loops = section_loops(shape, &plane);
+            let polygons = nest(&loops)
+                .into_iter()
+                .map(|(outer, holes)| Polygon {
+                    outer: oriented(&loops[outer].1, true),
+                    holes: holes.iter().map(|&hole| oriented(&loops[hole].1, false)).collect(),
+                })
+                .collect();
+
+            Layer { height, plane, polygons }
+        })
+        .collect();
+
+    Ok(layers)
+}
+
+/// The faces where `plane` cuts through `shape`, with holes.
+pub fn cross_section(shape: &Shape, plane: &Workplane) -> Result<Vec<Face>, Error> {
+    let loops = section_loops(shape, plane);
+
+    nest(&loops)
+        .into_iter()
+        .map(|(outer, holes)| {
+            Face::from_wire_with_holes(&loops[outer].0, holes.iter().map(|&hole| &loops[hole].0))
+        })
+        .collect()
+}
+
+/// The closed wires where `plane` cuts through `shape`, with their polygons in
+/// the coordinates of `plane`.
+fn section_loops(shape: &Shape, plane: &Workplane) -> Vec<(Wire, Vec<DVec2>)> {
+    Section::with_plane(shape, plane.coordinate_system())
+        .section_wires()
+        .into_iter()
+        .filter_map(|wire| {
+            let polygon = wire_polygon(&wire, plane);
+            (polygon.len() >= 3).then_some((wire, polygon))
+        })
+        .collect()
+}
+
+/// The points along a wire in order, chaining its edges end to end.
+fn wire_polygon(wire: &Wire, plane: &Workplane) -> Vec<DVec2> {
+    let mut segments: Vec<Vec<DVec2>> = Shape::from(wire)
+        .edges()
+        .map(|edge| {
+            edge.approximation_segments()
+                .map(|point| plane.to_local_pos(point).truncate())
+                .collect()
+        })
+        .filter(|points: &Vec<DVec2>| points.len() >= 2)
+        .collect();
+
+    let Some(mut polygon) = segments.pop() else {
+        return vec![];
+    };
+
+    while !segments.is_empty() {
+        let end = *polygon.last().unwrap();
+        let distance = |points: &Vec<DVec2>| {
+            points[0].distance(end).min(points[points.len() - 1].distance(end))
+        };
+
+        let nearest = (0..segments.len())
+            .min_by(|&a, &b| distance(&segments[a]).total_cmp(&distance(&segments[b])))
+            .unwrap();
+        let mut next = segments.swap_remove(nearest);
+        if next[0].distance(end) > next[next.len() - 1].distance(end) {
+            next.reverse();
+        }
+
+        polygon.extend(next.into_iter().skip(1));
+    }
+
+    // Closed polygons don't repeat their first point.
+    if polygon.len() > 1 && polygon[0].distance(polygon[polygon.len() - 1]) < 1.0e-9 {
+        polygon.pop();
+    }
+
+    polygon
+}
+
+/// Group loops into outer boundaries and their holes. A loop inside of an odd
+/// number of other loops is a hole in the smallest loop around it.
+fn nest<T>(loops: &[(T, Vec<DVec2>)]) -> Vec<(usize, Vec<usize>)> {
+    let contains = |outer: usize, inner: usize| {
+        outer != inner && point_in_polygon(loops[inner].1[0], &loops[outer].1)
+    };
+    let area = |index: usize| signed_area(&loops[index].1).abs();
+
+    let parents: Vec<Vec<usize>> = (0..loops.len())
+        .map(|inner| (0..loops.len()).filter(|&outer| contains(outer, inner)).collect())
+        .collect();
+
+    let mut nested: Vec<(usize, Vec<usize>)> = (0..loops.len())
+        .filter(|&index| parents[index].len().is_multiple_of(2))
+        .map(|index| (index, vec![]))
+        .collect();
+
+    for (hole, hole_parents) in parents.iter().enumerate() {
+        if !hole_parents.len().is_multiple_of(2) {
+            let parent = hole_parents
+                .iter()
+                .copied()
+                .filter(|&parent| parents[parent].len().is_multiple_of(2))
+                .min_by(|&a, &b| area(a).total_cmp(&area(b)));
+
+            if let Some((_, holes)) = nested.iter_mut().find(|(outer, _)| Some(*outer) == parent) {
+                holes.push(hole);
+            }
+        }
+    }
+
+    nested
+}
+
+fn point_in_polygon(point: DVec2, polygon: &[DVec2]) -> bool {
+    let mut inside = false;
+
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Positive for counter-clockwise polygons.
+fn signed_area(polygon: &[DVec2]) -> f64 {
+    (0..polygon.len()).map(|i| polygon[i].perp_dot(polygon[(i + 1) % polygon.len()])).sum::<f64>()
+        / 2.0
+}
+
+fn oriented(polygon: &[DVec2], counter_clockwise: bool) -> Vec<DVec2> {
+    let mut polygon = polygon.to_vec();
+    if (signed_area(&polygon) > 0.0) != counter_clockwise {
+        polygon.reverse();
+    }
+
+    polygon
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_box_with_hole() {
+        let block = Shape::box_with_dimensions(10.0, 10.0, 10.0);
+        let hole = Shape::cylinder(dvec3(5.0, 5.0, -1.0), 2.0, DVec3::Z, 12.0);
+        let shape: Shape = block.subtract(&hole).into();
+
+        let layers = slice(&shape, DVec3::Z, 2.5).unwrap();
+        assert_eq!(layers.len(), 4);
+        assert!((layers[0].height - 1.25).abs() < 1.0e-6);
+
+        for layer in &layers {
+            assert_eq!(layer.polygons.len(), 1);
+            assert_eq!(layer.polygons[0].holes.len(), 1);
+            assert!(signed_area(&layer.polygons[0].outer) > 0.0);
+            assert!(signed_area(&layer.polygons[0].holes[0]) < 0.0);
+        }
+
+        let faces =
+            cross_section(&shape, &Workplane::xy().translated(dvec3(0.0, 0.0, 5.0))).unwrap();
+        assert_eq!(faces.len(), 1);
+    }
+
+    #[test]
+    fn slice_rejects_invalid_parameters() {
+        let block = Shape::box_with_dimensions(10.0, 10.0, 10.0);
+
+        for layer_height in [0.0, -1.0, f64::NAN] {
+            assert!(matches!(slice(&block, DVec3::Z, layer_height), Err(Error::InvalidSlice(_))));
+        }
+        assert!(matches!(slice(&block, DVec3::ZERO, 1.0), Err(Error::InvalidSlice(_))));
+    }
+}
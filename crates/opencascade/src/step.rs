@@ -0,0 +1,496 @@
+use crate::{
+    primitives::{make_point, Compound, Shape},
+    Error,
+};
+use cxx::UniquePtr;
+use glam::DVec3;
+use opencascade_sys as ffi;
+use std::{
+    io::{Read, Write},
+    path::Path,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// The STEP and IGES settings in `Interface_Static` are shared by the whole process, so
+/// they're only set and used while holding this lock.
+static SETTINGS: Mutex<()> = Mutex::new(());
+
+pub(crate) fn lock_settings() -> MutexGuard<'static, ()> {
+    SETTINGS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A unit of length used when reading or writing STEP files.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LengthUnit {
+    #[default]
+    Millimeter,
+    Centimeter,
+    Meter,
+    Inch,
+    Foot,
+}
+
+impl LengthUnit {
+    pub(crate) fn as_occt_str(self) -> &'static str {
+        match self {
+            Self::Millimeter => "MM",
+            Self::Centimeter => "CM",
+            Self::Meter => "M",
+            Self::Inch => "INCH",
+            Self::Foot => "FT",
+        }
+    }
+}
+
+/// Which tolerance is given to imported geometry.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum PrecisionMode {
+    /// Use the uncertainty stored in the file.
+    #[default]
+    File,
+    /// Use the given tolerance, in the target unit.
+    User(f64),
+}
+
+/// A root entity of a STEP file, usually a product.
+pub struct StepRoot {
+    /// The product name, if the root is a product with a name.
+    pub name: Option<String>,
+    pub shape: Shape,
+}
+
+/// The result of reading a STEP file with a [`StepReader`].
+pub struct StepImport {
+    pub roots: Vec<StepRoot>,
+    /// Warnings from loading the file and transferring its entities.
+    pub warnings: Vec<String>,
+    /// Errors about entities which couldn't be read or transferred. The
+    /// remaining entities are still imported.
+    pub errors: Vec<String>,
+}
+
+impl StepImport {
+    /// All roots combined into a compound, or the only root if there is just one.
+    #[must_use]
+    pub fn into_shape(mut self) -> Shape {
+        if self.roots.len() == 1 {
+            return self.roots.remove(0).shape;
+        }
+
+        Compound::from_shapes(self.roots.iter().map(|root| &root.shape)).into()
+    }
+}
+
+/// Reads STEP files with control over units and precision, keeping each
+/// root as a separate shape.
+#[derive(Debug, Clone)]
+pub struct StepReader {
+    unit: LengthUnit,
+    scale: f64,
+    precision: PrecisionMode,
+}
+
+impl Default for StepReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StepReader {
+    pub fn new() -> Self {
+        Self { unit: LengthUnit::default(), scale: 1.0, precision: PrecisionMode::default() }
+    }
+
+    /// The unit imported shapes are converted to, regardless of the unit used
+    /// in the file. Defaults to millimeters.
+    pub fn unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// An extra scale factor applied to the imported shapes, after converting
+    /// them to the target unit.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn precision(mut self, precision: PrecisionMode) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn read(&self, path: impl AsRef<Path>) -> Result<StepImport, Error> {
+        let _settings = lock_settings();
+        let mut reader = self.reader();
+
+        let status = ffi::step_control::read_step(
+            reader.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+
+        self.transfer(reader, status)
+    }
+
+    pub fn read_from(&self, mut source: impl Read) -> Result<StepImport, Error> {
+        let mut data = vec![];
+        source.read_to_end(&mut data)?;
+
+        let _settings = lock_settings();
+        let mut reader = self.reader();
+        let status = ffi::step_control::read_step_stream(reader.pin_mut(), &data);
+
+        self.transfer(reader, status)
+    }
+
+    fn reader(&self) -> UniquePtr<ffi::step_control::STEPControl_Reader> {
+        // The reader registers the parameters, so it has to be created first.
+        let reader = ffi::step_control::STEPControl_Reader_new();
+        self.apply_settings();
+
+        reader
+    }
+
+    /// Set the global parameters which readers pick up when loading and
+    /// transferring a file. Hold [`lock_settings`] while doing so.
+    pub(crate) fn apply_settings(&self) {
+        ffi::interface::Interface_Static_SetCVal("xstep.cascade.unit", self.unit.as_occt_str());
+
+        match self.precision {
+            PrecisionMode::File => {
+                ffi::interface::Interface_Static_SetIVal("read.precision.mode", 0);
+            },
+            PrecisionMode::User(tolerance) => {
+                ffi::interface::Interface_Static_SetIVal("read.precision.mode", 1);
+                ffi::interface::Interface_Static_SetRVal("read.precision.val", tolerance);
+            },
+        }
+    }
+
+    fn transfer(
+        &self,
+        mut reader: UniquePtr<ffi::step_control::STEPControl_Reader>,
+        status: ffi::if_select::IFSelect_ReturnStatus,
+    ) -> Result<StepImport, Error> {
+        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
+            return Err(Error::StepReadFailed);
+        }
+
+        let mut roots = vec![];
+
+        for i in 1..=reader.pin_mut().NbRootsForTransfer() {
+            let name = ffi::step_control::step_root_name(reader.pin_mut(), i);
+            let name = (!name.is_empty()).then_some(name);
+
+            let shape_count = reader.NbShapes();
+            if !reader.pin_mut().TransferRoot(i, &ffi::message::Message_ProgressRange_new()) {
+                continue;
+            }
+
+            for j in shape_count + 1..=reader.NbShapes() {
+                let shape = ffi::step_control::step_reader_shape(&reader, j);
+                let shape = self.scaled(Shape { inner: shape });
+
+                roots.push(StepRoot { name: name.clone(), shape });
+            }
+        }
+
+        if roots.is_empty() {
+            return Err(Error::StepReadFailed);
+        }
+
+        let mut warnings = vec![];
+        let mut errors = vec![];
+        ffi::step_control::step_reader_checks(&reader, &mut warnings, &mut errors);
+
+        Ok(StepImport { roots, warnings, errors })
+    }
+
+    fn scaled(&self, shape: Shape) -> Shape {
+        if self.scale == 1.0 {
+            return shape;
+        }
+
+        let mut transform = ffi::gp::new_transform();
+        transform.pin_mut().SetScale(&make_point(DVec3::ZERO), self.scale);
+
+        let mut operation =
+            ffi::b_rep_builder_api::BRepBuilderAPI_Transform_new(&shape.inner, &transform, false);
+
+        Shape::from_shape(operation.pin_mut().Shape())
+    }
+}
+
+/// The application protocol a STEP file is written with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StepSchema {
+    /// Configuration controlled 3D design, supported by most older software.
+    Ap203,
+    /// Automotive design, which adds colors and layers.
+    #[default]
+    Ap214,
+    /// Managed model based 3D engineering, the successor of AP203 and AP214.
+    Ap242,
+}
+
+impl StepSchema {
+    fn as_occt_str(self) -> &'static str {
+        match self {
+            Self::Ap203 => "AP203",
+            Self::Ap214 => "AP214IS",
+            Self::Ap242 => "AP242DIS",
+        }
+    }
+}
+
+/// How shapes are represented in a written STEP file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum StepTransferMode {
+    /// Pick the representation from the type of each shape.
+    #[default]
+    AsIs,
+    /// Solids, as `MANIFOLD_SOLID_BREP`.
+    ManifoldSolidBrep,
+    /// Faces and shells, as `SHELL_BASED_SURFACE_MODEL`.
+    ShellBasedSurfaceModel,
+    /// Solids with only planar faces, as `FACETED_BREP`.
+    FacetedBrep,
+}
+
+impl From<StepTransferMode> for ffi::step_control::STEPControl_StepModelType {
+    fn from(mode: StepTransferMode) -> Self {
+        match mode {
+            StepTransferMode::AsIs => Self::STEPControl_AsIs,
+            StepTransferMode::ManifoldSolidBrep => Self::STEPControl_ManifoldSolidBrep,
+            StepTransferMode::ShellBasedSurfaceModel => Self::STEPControl_ShellBasedSurfaceModel,
+            StepTransferMode::FacetedBrep => Self::STEPControl_FacetedBrep,
+        }
+    }
+}
+
+/// Writes STEP files with control over the schema, units and header, with
+/// each shape as a separate product.
+pub struct StepWriter<'a> {
+    schema: StepSchema,
+    unit: LengthUnit,
+    transfer_mode: StepTransferMode,
+    tolerance: Option<f64>,
+    name: String,
+    author: String,
+    organization: String,
+    products: Vec<(String, &'a Shape)>,
+}
+
+impl Default for StepWriter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> StepWriter<'a> {
+    pub fn new() -> Self {
+        Self {
+            schema: StepSchema::default(),
+            unit: LengthUnit::default(),
+            transfer_mode: StepTransferMode::default(),
+            tolerance: None,
+            name: String::new(),
+            author: String::new(),
+            organization: String::new(),
+            products: vec![],
+        }
+    }
+
+    pub fn schema(mut self, schema: StepSchema) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// The unit lengths are written in. Shapes are assumed to be in millimeters.
+    pub fn unit(mut self, unit: LengthUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn transfer_mode(mut self, transfer_mode: StepTransferMode) -> Self {
+        self.transfer_mode = transfer_mode;
+        self
+    }
+
+    /// The uncertainty written to the file. Defaults to the average tolerance
+    /// of the shapes.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// The name in the file header.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    pub fn organization(mut self, organization: impl Into<String>) -> Self {
+        self.organization = organization.into();
+        self
+    }
+
+    /// Add a shape as a product with the given name. An empty name keeps the
+    /// default product name.
+    pub fn product(mut self, name: impl Into<String>, shape: &'a Shape) -> Self {
+        self.products.push((name.into(), shape));
+        self
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let _settings = lock_settings();
+        let mut writer = self.writer()?;
+
+        let status = ffi::step_control::write_step(
+            writer.pin_mut(),
+            path.as_ref().to_string_lossy().to_string(),
+        );
+
+        if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
+            return Err(Error::StepWriteFailed);
+        }
+
+        Ok(())
+    }
+
+    pub fn write_to(&self, mut destination: impl Write) -> Result<(), Error> {
+        let mut data = vec![];
+
+        {
+            let _settings = lock_settings();
+            let mut writer = self.writer()?;
+            let status = ffi::step_control::write_step_stream(writer.pin_mut(), &mut data);
+
+            if status != ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone {
+                return Err(Error::StepWriteFailed);
+            }
+        }
+
+        destination.write_all(&data)?;
+
+        Ok(())
+    }
+
+    fn writer(&self) -> Result<UniquePtr<ffi::step_control::STEPControl_Writer>, Error> {
+        // The writer registers the parameters, so it has to be created first.
+        let mut writer = ffi::step_control::STEPControl_Writer_new();
+        self.apply_settings();
+
+        for (name, shape) in &self.products {
+            let status = ffi::step_control::transfer_shape_as(
+                writer.pin_mut(),
+                &shape.inner,
+                self.transfer_mode.into(),
+                name,
+            );
+
+            if !matches!(status, Ok(ffi::if_select::IFSelect_ReturnStatus::IFSelect_RetDone)) {
+                return Err(Error::StepWriteFailed);
+            }
+        }
+
+        ffi::step_control::step_writer_set_header(
+            writer.pin_mut(),
+            &self.name,
+            &self.author,
+            &self.organization,
+        );
+
+        Ok(writer)
+    }
+
+    /// Set the global parameters which writers pick up when the first shape is
+    /// transferred. Hold [`lock_settings`] while doing so.
+    pub(crate) fn apply_settings(&self) {
+        // Shapes are in millimeters, whatever a reader last converted to.
+        ffi::interface::Interface_Static_SetCVal("xstep.cascade.unit", "MM");
+        ffi::interface::Interface_Static_SetCVal("write.step.schema", self.schema.as_occt_str());
+        ffi::interface::Interface_Static_SetCVal("write.step.unit", self.unit.as_occt_str());
+
+        match self.tolerance {
+            Some(tolerance) => {
+                ffi::interface::Interface_Static_SetIVal("write.precision.mode", 2);
+                ffi::interface::Interface_Static_SetRVal("write.precision.val", tolerance);
+            },
+            None => {
+                ffi::interface::Interface_Static_SetIVal("write.precision.mode", 0);
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_names_and_units_round_trip() {
+        let small = Shape::box_with_dimensions(1.0, 1.0, 1.0);
+        let large = Shape::box_with_dimensions(25.4, 25.4, 25.4);
+
+        let mut data = vec![];
+        StepWriter::new()
+            .schema(StepSchema::Ap242)
+            .author("author")
+            .product("small", &small)
+            .product("large", &large)
+            .write_to(&mut data)
+            .unwrap();
+
+        let import = StepReader::new().unit(LengthUnit::Inch).read_from(data.as_slice()).unwrap();
+        let names: Vec<_> = import.roots.iter().map(|root| root.name.as_deref()).collect();
+        assert_eq!(names, vec![Some("small"), Some("large")]);
+
+        let bounds = crate::bounding_box::aabb(&import.roots[1].shape);
+        let size = bounds.max() - bounds.min() - 2.0 * bounds.gap_vec();
+        assert!((size.x - 1.0).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn writer_ignores_last_read_unit() {
+        let shape = Shape::box_with_dimensions(25.4, 25.4, 25.4);
+
+        let mut data = vec![];
+        shape.write_step_to(&mut data).unwrap();
+        StepReader::new().unit(LengthUnit::Inch).read_from(data.as_slice()).unwrap();
+
+        let mut data = vec![];
+        shape.write_step_to(&mut data).unwrap();
+        let shape = Shape::read_step_from(data.as_slice()).unwrap();
+
+        let bounds = crate::bounding_box::aabb(&shape);
+        let size = bounds.max() - bounds.min() - 2.0 * bounds.gap_vec();
+        assert!((size.x - 25.4).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn iges_reader_ignores_last_step_read_unit() {
+        let shape = Shape::box_with_dimensions(25.4, 25.4, 25.4);
+
+        let mut data = vec![];
+        shape.write_step_to(&mut data).unwrap();
+        StepReader::new()
+            .unit(LengthUnit::Inch)
+            .precision(PrecisionMode::User(0.1))
+            .read_from(data.as_slice())
+            .unwrap();
+
+        let mut data = vec![];
+        shape.write_iges_to(&mut data).unwrap();
+        let shape = Shape::read_iges_from(data.as_slice()).unwrap();
+
+        let bounds = crate::bounding_box::aabb(&shape);
+        let size = bounds.max() - bounds.min() - 2.0 * bounds.gap_vec();
+        assert!((size.x - 25.4).abs() < 1.0e-3);
+    }
+}
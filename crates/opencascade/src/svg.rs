@@ -0,0 +1,842 @@
+use crate::{
+    curve2d::{self, angle_of, format_number, Curve2d},
+    mesh_export::xml_escape,
+    primitives::Edge,
+    workplane::Workplane,
+    Error,
+};
+use glam::{dvec2, DAffine2, DMat2, DVec2};
+use std::{
+    f64::consts::{FRAC_PI_2, TAU},
+    fmt::Write as _,
+    io::{Read, Write},
+};
+
+/// Writes planar curves to an SVG file, in millimeters. Each layer is written
+/// as a group of paths.
+///
+/// SVG has its Y axis pointing down, so curves are mirrored to keep the Y axis
+/// of the drawing pointing up.
+#[derive(Debug, Clone)]
+pub struct SvgWriter {
+    layers: Vec<SvgLayer>,
+    stroke_width: f64,
+    margin: f64,
+}
+
+#[derive(Debug, Clone)]
+struct SvgLayer {
+    name: String,
+    style: String,
+    curves: Vec<Curve2d>,
+}
+
+impl Default for SvgWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgWriter {
+    pub fn new() -> Self {
+        Self { layers: vec![], stroke_width: 0.25, margin: 5.0 }
+    }
+
+    /// The default stroke width, in millimeters.
+    pub fn stroke_width(mut self, stroke_width: f64) -> Self {
+        self.stroke_width = stroke_width;
+        self
+    }
+
+    /// The space around the drawing, in millimeters.
+    pub fn margin(mut self, margin: f64) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Add curves to a layer, which is created if it doesn't exist yet.
+    pub fn curves(mut self, layer: &str, curves: impl IntoIterator<Item = Curve2d>) -> Self {
+        self.layer_mut(layer).curves.extend(curves);
+        self
+    }
+
+    /// Add edges to a layer, projected onto `plane`.
+    pub fn edges<T: AsRef<Edge>>(
+        self,
+        layer: &str,
+        edges: impl IntoIterator<Item = T>,
+        plane: &Workplane,
+    ) -> Self {
+        self.curves(layer, Curve2d::from_edges(edges, plane))
+    }
+
+    /// Extra SVG presentation attributes for the paths of a layer, such as
+    /// `stroke="gray" stroke-dasharray="2 1"`.
+    pub fn layer_style(mut self, layer: &str, style: &str) -> Self {
+        self.layer_mut(layer).style = style.to_string();
+        self
+    }
+
+    fn layer_mut(&mut self, layer: &str) -> &mut SvgLayer {
+        let index = match self.layers.iter().position(|l| l.name == layer) {
+            Some(index) => index,
+            None => {
+                self.layers.push(SvgLayer {
+                    name: layer.to_string(),
+                    style: String::new(),
+                    curves: vec![],
+                });
+                self.layers.len() - 1
+            },
+        };
+
+        &mut self.layers[index]
+    }
+
+    pub fn write(&self, mut writer: impl Write) -> Result<(), Error> {
+        let (min, max) = curve2d::bounds(self.layers.iter().flat_map(|layer| &layer.curves))
+            .unwrap_or((DVec2::ZERO, DVec2::ZERO));
+        let min = min - DVec2::splat(self.margin);
+        let max = max + DVec2::splat(self.margin);
+        let size = max - min;
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="{x} {y} {w} {h}">"#,
+            x = format_number(min.x),
+            y = format_number(-max.y),
+            w = format_number(size.x),
+            h = format_number(size.y),
+        )?;
+
+        for layer in &self.layers {
+            writeln!(
+                writer,
+                r#"<g id="{}" fill="none" stroke="black" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" {}>"#,
+                xml_escape(&layer.name),
+                format_number(self.stroke_width),
+                layer.style,
+            )?;
+
+            for curve in &layer.curves {
+                writeln!(writer, r#"<path d="{}"/>"#, path_data(curve))?;
+            }
+
+            writeln!(writer, "</g>")?;
+        }
+
+        writeln!(writer, "</svg>")?;
+
+        Ok(())
+    }
+}
+
+/// The SVG path data for a curve, with the Y axis flipped.
+pub(crate) fn path_data(curve: &Curve2d) -> String {
+    let point = |p: DVec2| format!("{} {}", format_number(p.x), format_number(-p.y));
+    let mut d = String::new();
+
+    match curve {
+        Curve2d::Line { start, end } => {
+            write!(d, "M {} L {}", point(*start), point(*end)).unwrap();
+        },
+        Curve2d::Arc { center, radius, start_angle, end_angle } => {
+            let end = curve2d::point_on_circle(*center, *radius, *end_angle);
+            let large_arc = (end_angle - start_angle > std::f64::consts::PI) as u8;
+            let r = format_number(*radius);
+
+            // Counter-clockwise arcs become clockwise ones, when the Y axis is flipped.
+            write!(d, "M {} A {r} {r} 0 {large_arc} 0 {}", point(curve.start_point()), point(end))
+                .unwrap();
+        },
+        Curve2d::Circle { center, radius } => {
+            let r = format_number(*radius);
+            let right = *center + DVec2::X * *radius;
+            let left = *center - DVec2::X * *radius;
+
+            write!(
+                d,
+                "M {} A {r} {r} 0 1 0 {} A {r} {r} 0 1 0 {} Z",
+                point(right),
+                point(left),
+                point(right)
+            )
+            .unwrap();
+        },
+        Curve2d::Spline { degree, .. } if curve.is_polynomial_up_to_cubic() => {
+            let segments = curve.bezier_segments();
+            write!(d, "M {}", point(curve.start_point())).unwrap();
+
+            let command = match degree {
+                1 => "L",
+                2 => "Q",
+                _ => "C",
+            };
+
+            for segment in segments {
+                write!(d, " {command}").unwrap();
+                for control_point in &segment[1..] {
+                    write!(d, " {}", point(control_point.truncate() / control_point.z)).unwrap();
+                }
+            }
+        },
+        Curve2d::Spline { .. } | Curve2d::Polyline { .. } => {
+            let points = curve.sample(std::f64::consts::TAU / 64.0);
+            write!(d, "M {}", point(points[0])).unwrap();
+            for p in &points[1..] {
+                write!(d, " L {}", point(*p)).unwrap();
+            }
+
+            if matches!(curve, Curve2d::Polyline { closed: true, .. }) {
+                write!(d, " Z").unwrap();
+            }
+        },
+    }
+
+    d
+}
+
+/// Read the outlines in a layer of an SVG file. A layer is a `<g>` element with
+/// a matching `id` or Inkscape layer label.
+///
+/// `path`, `line`, `rect`, `circle`, `ellipse`, `polyline` and `polygon`
+/// elements are read, in user units, with their transforms applied. The Y axis
+/// is flipped to point up, the same way [`SvgWriter`] flips it.
+pub fn read_svg(mut reader: impl Read, layer: &str) -> Result<Vec<Curve2d>, Error> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let flip = DAffine2::from_scale(dvec2(1.0, -1.0));
+    // The transform and whether each open element is inside of the layer.
+    let mut stack: Vec<(DAffine2, bool)> = vec![];
+    let mut found_layer = false;
+    let mut curves = vec![];
+
+    for tag in tags(&text)? {
+        if tag.closing {
+            stack.pop();
+            continue;
+        }
+
+        let (parent_transform, parent_in_layer) = stack.last().copied().unwrap_or((flip, false));
+        let transform = match tag.attribute("transform") {
+            Some(transform) => parent_transform * parse_transform(transform)?,
+            None => parent_transform,
+        };
+
+        let is_layer = tag.name == "g"
+            && (tag.attribute("id") == Some(layer)
+                || tag.attribute("inkscape:label") == Some(layer));
+        found_layer |= is_layer;
+        let in_layer = parent_in_layer || is_layer;
+
+        if in_layer {
+            for curve in tag.curves()? {
+                curves.extend(transform_curve(&curve, transform));
+            }
+        }
+
+        if !tag.self_closing {
+            stack.push((transform, in_layer));
+        }
+    }
+
+    if !found_layer {
+        return Err(Error::SvgReadFailed(format!("no layer named `{layer}`")));
+    }
+
+    Ok(curves)
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    attributes: Vec<(&'a str, String)>,
+    closing: bool,
+    self_closing: bool,
+}
+
+impl Tag<'_> {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(n, _)| *n == name).map(|(_, value)| value.as_str())
+    }
+
+    fn number(&self, name: &str) -> Result<f64, Error> {
+        let value = self.attribute(name).unwrap_or("0");
+        let number = value.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+
+        number.trim().parse().map_err(|_| Error::SvgReadFailed(format!("invalid number `{value}`")))
+    }
+
+    fn curves(&self) -> Result<Vec<Curve2d>, Error> {
+        Ok(match self.name {
+            "path" => parse_path(self.attribute("d").unwrap_or_default())?,
+            "line" => vec![Curve2d::Line {
+                start: dvec2(self.number("x1")?, self.number("y1")?),
+                end: dvec2(self.number("x2")?, self.number("y2")?),
+            }],
+            "rect" => {
+                let min = dvec2(self.number("x")?, self.number("y")?);
+                let max = min + dvec2(self.number("width")?, self.number("height")?);
+                let points = vec![min, dvec2(max.x, min.y), max, dvec2(min.x, max.y)];
+                vec![Curve2d::Polyline { points, closed: true }]
+            },
+            "circle" => vec![Curve2d::Circle {
+                center: dvec2(self.number("cx")?, self.number("cy")?),
+                radius: self.number("r")?,
+            }],
+            "ellipse" => ellipse_beziers(
+                dvec2(self.number("cx")?, self.number("cy")?),
+                dvec2(self.number("rx")?, self.number("ry")?),
+                0.0,
+                0.0,
+                TAU,
+            ),
+            "polyline" | "polygon" => {
+                let mut parser = PathParser::new(self.attribute("points").unwrap_or_default());
+                let mut points = vec![];
+                while let Some(x) = parser.number_opt()? {
+                    points.push(dvec2(x, parser.number()?));
+                }
+
+                if points.len() < 2 {
+                    vec![]
+                } else {
+                    vec![Curve2d::Polyline { points, closed: self.name == "polygon" }]
+                }
+            },
+            _ => vec![],
+        })
+    }
+}
+
+/// Split an XML document into its element tags, skipping text, comments,
+/// processing instructions and declarations.
+fn tags(text: &str) -> Result<Vec<Tag<'_>>, Error> {
+    let unterminated = || Error::SvgReadFailed("unterminated tag".to_string());
+    let mut tags = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+
+        let skip_to = if rest.starts_with("!--") {
+            Some("-->")
+        } else if rest.starts_with("![CDATA[") {
+            Some("]]>")
+        } else if rest.starts_with('?') || rest.starts_with('!') {
+            Some(">")
+        } else {
+            None
+        };
+
+        if let Some(terminator) = skip_to {
+            let end = rest.find(terminator).ok_or_else(unterminated)?;
+            rest = &rest[end + terminator.len()..];
+            continue;
+        }
+
+        let mut tag = Tag { name: "", attributes: vec![], closing: false, self_closing: false };
+        if let Some(closing) = rest.strip_prefix('/') {
+            tag.closing = true;
+            rest = closing;
+        }
+
+        let name_end = rest.find(|c: char| c.is_whitespace() || c == '/' || c == '>');
+        let name_end = name_end.ok_or_else(unterminated)?;
+        tag.name = &rest[..name_end];
+        rest = &rest[name_end..];
+
+        loop {
+            rest = rest.trim_start();
+
+            if let Some(after) = rest.strip_prefix("/>") {
+                tag.self_closing = true;
+                rest = after;
+                break;
+            } else if let Some(after) = rest.strip_prefix('>') {
+                rest = after;
+                break;
+            }
+
+            let equals = rest.find('=').ok_or_else(unterminated)?;
+            let name = rest[..equals].trim();
+            rest = rest[equals + 1..].trim_start();
+
+            let quote = rest.chars().next().ok_or_else(unterminated)?;
+            if quote != '"' && quote != '\'' {
+                return Err(Error::SvgReadFailed(format!("unquoted value for `{name}`")));
+            }
+
+            let end = rest[1..].find(quote).ok_or_else(unterminated)?;
+            tag.attributes.push((name, xml_unescape(&rest[1..end + 1])));
+            rest = &rest[end + 2..];
+        }
+
+        tags.push(tag);
+    }
+
+    Ok(tags)
+}
+
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Parse an SVG transform list, such as `translate(10 20) rotate(45)`.
+fn parse_transform(value: &str) -> Result<DAffine2, Error> {
+    let mut transform = DAffine2::IDENTITY;
+    let mut rest = value;
+
+    while let Some(open) = rest.find('(') {
+        let name = rest[..open].trim_matches(|c: char| c.is_whitespace() || c == ',');
+        let close = rest
+            .find(')')
+            .ok_or_else(|| Error::SvgReadFailed(format!("unterminated transform `{value}`")))?;
+
+        let mut parser = PathParser::new(&rest[open + 1..close]);
+        let mut args = vec![];
+        while let Some(arg) = parser.number_opt()? {
+            args.push(arg);
+        }
+        rest = &rest[close + 1..];
+
+        let arg = |index: usize, default: f64| args.get(index).copied().unwrap_or(default);
+        transform *= match name {
+            "matrix" if args.len() == 6 => {
+                DAffine2::from_cols_array(&[args[0], args[1], args[2], args[3], args[4], args[5]])
+            },
+            "translate" => DAffine2::from_translation(dvec2(arg(0, 0.0), arg(1, 0.0))),
+            "scale" => DAffine2::from_scale(dvec2(arg(0, 1.0), arg(1, arg(0, 1.0)))),
+            "rotate" => {
+                let pivot = dvec2(arg(1, 0.0), arg(2, 0.0));
+                DAffine2::from_translation(pivot)
+                    * DAffine2::from_angle(arg(0, 0.0).to_radians())
+                    * DAffine2::from_translation(-pivot)
+            },
+            "skewX" => DAffine2::from_mat2(DMat2::from_cols_array(&[
+                1.0,
+                0.0,
+                arg(0, 0.0).to_radians().tan(),
+                1.0,
+            ])),
+            "skewY" => DAffine2::from_mat2(DMat2::from_cols_array(&[
+                1.0,
+                arg(0, 0.0).to_radians().tan(),
+                0.0,
+                1.0,
+            ])),
+            _ => return Err(Error::SvgReadFailed(format!("invalid transform `{value}`"))),
+        };
+    }
+
+    Ok(transform)
+}
+
+/// Parse SVG path data into lines, arcs, and quadratic and cubic Bezier curves.
+fn parse_path(data: &str) -> Result<Vec<Curve2d>, Error> {
+    let mut parser = PathParser::new(data);
+    let mut curves = vec![];
+
+    let mut current = DVec2::ZERO;
+    let mut subpath_start = DVec2::ZERO;
+    // The control point to reflect for smooth curve commands.
+    let mut last_control: Option<(u8, DVec2)> = None;
+    let mut command = None;
+
+    loop {
+        command = match parser.command() {
+            Some(next) => Some(next),
+            None if parser.is_done() => break,
+            // Repeated coordinates continue the previous command, with moves becoming lines.
+            None => match command {
+                Some(b'M') => Some(b'L'),
+                Some(b'm') => Some(b'l'),
+                Some(b'Z' | b'z') | None => {
+                    return Err(Error::SvgReadFailed(format!("invalid path data `{data}`")))
+                },
+                previous => previous,
+            },
+        };
+
+        let Some(command) = command else { break };
+        let relative = command.is_ascii_lowercase();
+        let origin = if relative { current } else { DVec2::ZERO };
+        let mut control = None;
+
+        let kind = command.to_ascii_uppercase();
+        match kind {
+            b'M' => {
+                current = origin + parser.point()?;
+                subpath_start = current;
+            },
+            b'L' => {
+                let end = origin + parser.point()?;
+                curves.push(Curve2d::Line { start: current, end });
+                current = end;
+            },
+            b'H' => {
+                let end = dvec2(origin.x + parser.number()?, current.y);
+                curves.push(Curve2d::Line { start: current, end });
+                current = end;
+            },
+            b'V' => {
+                let end = dvec2(current.x, origin.y + parser.number()?);
+                curves.push(Curve2d::Line { start: current, end });
+                current = end;
+            },
+            b'C' | b'S' => {
+                let first = if kind == b'C' {
+                    origin + parser.point()?
+                } else {
+                    match last_control {
+                        Some((b'C', point)) => 2.0 * current - point,
+                        _ => current,
+                    }
+                };
+                let second = origin + parser.point()?;
+                let end = origin + parser.point()?;
+
+                curves.push(bezier(vec![current, first, second, end]));
+                control = Some((b'C', second));
+                current = end;
+            },
+            b'Q' | b'T' => {
+                let middle = if kind == b'Q' {
+                    origin + parser.point()?
+                } else {
+                    match last_control {
+                        Some((b'Q', point)) => 2.0 * current - point,
+                        _ => current,
+                    }
+                };
+                let end = origin + parser.point()?;
+
+                curves.push(bezier(vec![current, middle, end]));
+                control = Some((b'Q', middle));
+                current = end;
+            },
+            b'A' => {
+                let radii = parser.point()?;
+                let rotation = parser.number()?.to_radians();
+                let large_arc = parser.flag()?;
+                let sweep = parser.flag()?;
+                let end = origin + parser.point()?;
+
+                curves.extend(svg_arc(current, end, radii, rotation, large_arc, sweep));
+                current = end;
+            },
+            b'Z' => {
+                if current.distance(subpath_start) > 1.0e-12 {
+                    curves.push(Curve2d::Line { start: current, end: subpath_start });
+                }
+                current = subpath_start;
+            },
+            _ => {
+                return Err(Error::SvgReadFailed(format!(
+                    "invalid path command `{}`",
+                    command as char
+                )))
+            },
+        }
+
+        last_control = control;
+    }
+
+    Ok(curves)
+}
+
+struct PathParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self { data: data.as_bytes(), position: 0 }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.data.get(self.position).is_some_and(|c| c.is_ascii_whitespace() || *c == b',') {
+            self.position += 1;
+        }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_separators();
+        self.position == self.data.len()
+    }
+
+    fn command(&mut self) -> Option<u8> {
+        self.skip_separators();
+        let command = *self.data.get(self.position)?;
+        // `e` and `E` are only valid inside of numbers.
+        if command.is_ascii_alphabetic() && !matches!(command, b'e' | b'E') {
+            self.position += 1;
+            Some(command)
+        } else {
+            None
+        }
+    }
+
+    fn number_opt(&mut self) -> Result<Option<f64>, Error> {
+        self.skip_separators();
+        let start = self.position;
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+
+        while let Some(&c) = self.data.get(self.position) {
+            let previous = (self.position > start).then(|| self.data[self.position - 1]);
+            let accepted = match c {
+                b'0'..=b'9' => true,
+                b'+' | b'-' => self.position == start || matches!(previous, Some(b'e' | b'E')),
+                b'.' if !seen_dot && !seen_exponent => {
+                    seen_dot = true;
+                    true
+                },
+                b'e' | b'E' if !seen_exponent && self.position > start => {
+                    seen_exponent = true;
+                    true
+                },
+                _ => false,
+            };
+
+            if !accepted {
+                break;
+            }
+            self.position += 1;
+        }
+
+        if self.position == start {
+            return Ok(None);
+        }
+
+        let number = std::str::from_utf8(&self.data[start..self.position]).unwrap_or_default();
+        number
+            .parse()
+            .map(Some)
+            .map_err(|_| Error::SvgReadFailed(format!("invalid number `{number}`")))
+    }
+
+    fn number(&mut self) -> Result<f64, Error> {
+        self.number_opt()?.ok_or_else(|| Error::SvgReadFailed("expected a number".to_string()))
+    }
+
+    fn point(&mut self) -> Result<DVec2, Error> {
+        Ok(dvec2(self.number()?, self.number()?))
+    }
+
+    /// Arc flags are a single digit, which may not be followed by a separator.
+    fn flag(&mut self) -> Result<bool, Error> {
+        self.skip_separators();
+        let flag = match self.data.get(self.position) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(Error::SvgReadFailed("expected an arc flag".to_string())),
+        };
+
+        self.position += 1;
+        Ok(flag)
+    }
+}
+
+/// A single Bezier curve segment, through its first and last control points.
+fn bezier(control_points: Vec<DVec2>) -> Curve2d {
+    let degree = control_points.len() - 1;
+    let knots = [vec![0.0; degree + 1], vec![1.0; degree + 1]].concat();
+
+    Curve2d::Spline { degree, weights: vec![1.0; degree + 1], control_points, knots }
+}
+
+/// Convert an SVG arc from its endpoints to a circular arc, or to cubic Bezier
+/// curves when it is elliptical.
+fn svg_arc(
+    start: DVec2,
+    end: DVec2,
+    radii: DVec2,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+) -> Vec<Curve2d> {
+    let mut radii = radii.abs();
+    if start.distance(end) < 1.0e-12 {
+        return vec![];
+    } else if radii.x < 1.0e-12 || radii.y < 1.0e-12 {
+        return vec![Curve2d::Line { start, end }];
+    }
+
+    // The center parameterization from the SVG specification, appendix B.2.4.
+    let rotate = DMat2::from_angle(rotation);
+    let half = rotate.transpose() * ((start - end) / 2.0);
+
+    let lambda = (half / radii).length_squared();
+    if lambda > 1.0 {
+        radii *= lambda.sqrt();
+    }
+
+    let (rx2, ry2) = (radii.x * radii.x, radii.y * radii.y);
+    let numerator = rx2 * ry2 - rx2 * half.y * half.y - ry2 * half.x * half.x;
+    let denominator = rx2 * half.y * half.y + ry2 * half.x * half.x;
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let factor = sign * (numerator / denominator).max(0.0).sqrt();
+
+    let center_prime = factor * dvec2(radii.x * half.y / radii.y, -radii.y * half.x / radii.x);
+    let center = rotate * center_prime + (start + end) / 2.0;
+
+    let u = (half - center_prime) / radii;
+    let v = (-half - center_prime) / radii;
+    let start_angle = angle_of(u);
+    let mut delta = angle_of(v) - start_angle;
+    if sweep && delta < 0.0 {
+        delta += TAU;
+    } else if !sweep && delta > 0.0 {
+        delta -= TAU;
+    }
+
+    if (radii.x - radii.y).abs() > 1.0e-9 * radii.x.max(radii.y) {
+        return ellipse_beziers(center, radii, rotation, start_angle, delta);
+    }
+
+    // Arcs are counter-clockwise, so negative sweeps start at the end point.
+    let first = if delta > 0.0 { start_angle } else { start_angle + delta };
+    let first = (first + rotation).rem_euclid(TAU);
+
+    vec![Curve2d::Arc {
+        center,
+        radius: radii.x,
+        start_angle: first,
+        end_angle: first + delta.abs(),
+    }]
+}
+
+/// Approximate an elliptical arc with cubic Bezier curves, each spanning at most
+/// a quarter of the ellipse.
+pub(crate) fn ellipse_beziers(
+    center: DVec2,
+    radii: DVec2,
+    rotation: f64,
+    start_angle: f64,
+    sweep: f64,
+) -> Vec<Curve2d> {
+    let rotate = DMat2::from_angle(rotation);
+    let point = |angle: f64| center + rotate * (radii * dvec2(angle.cos(), angle.sin()));
+    let tangent = |angle: f64| rotate * (radii * dvec2(-angle.sin(), angle.cos()));
+
+    let count = ((sweep.abs() / FRAC_PI_2 - 1.0e-9).ceil() as usize).max(1);
+    let step = sweep / count as f64;
+    let handle = 4.0 / 3.0 * (step / 4.0).tan();
+
+    (0..count)
+        .map(|i| {
+            let a = start_angle + step * i as f64;
+            let b = a + step;
+            bezier(vec![
+                point(a),
+                point(a) + handle * tangent(a),
+                point(b) - handle * tangent(b),
+                point(b),
+            ])
+        })
+        .collect()
+}
+
+/// Apply a transform to a curve, turning arcs and circles into Bezier curves
+/// when it stretches or skews them.
+fn transform_curve(curve: &Curve2d, transform: DAffine2) -> Vec<Curve2d> {
+    let x = transform.matrix2.x_axis;
+    let y = transform.matrix2.y_axis;
+    let tolerance = 1.0e-9 * x.length().max(y.length());
+    let is_similarity = (x.length() - y.length()).abs() < tolerance && x.dot(y).abs() < tolerance;
+
+    let curves = match *curve {
+        Curve2d::Arc { center, radius, start_angle, end_angle } if !is_similarity => {
+            ellipse_beziers(center, DVec2::splat(radius), 0.0, start_angle, end_angle - start_angle)
+        },
+        Curve2d::Circle { center, radius } if !is_similarity => {
+            ellipse_beziers(center, DVec2::splat(radius), 0.0, 0.0, TAU)
+        },
+        _ => vec![curve.clone()],
+    };
+
+    curves.iter().map(|curve| curve.transformed(transform)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::dvec2;
+
+    #[test]
+    fn arc_path_is_flipped() {
+        let arc = Curve2d::Arc {
+            center: DVec2::ZERO,
+            radius: 2.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::FRAC_PI_2,
+        };
+
+        assert_eq!(path_data(&arc), "M 2 0 A 2 2 0 0 0 0 -2");
+    }
+
+    #[test]
+    fn cubic_spline_path() {
+        let spline = Curve2d::Spline {
+            degree: 3,
+            control_points: vec![
+                dvec2(0.0, 0.0),
+                dvec2(1.0, 1.0),
+                dvec2(2.0, 1.0),
+                dvec2(3.0, 0.0),
+            ],
+            weights: vec![1.0; 4],
+            knots: vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+        };
+
+        assert_eq!(path_data(&spline), "M 0 0 C 1 -1 2 -1 3 0");
+    }
+
+    #[test]
+    fn read_written_curves() {
+        let curves = vec![
+            Curve2d::Line { start: dvec2(0.0, 0.0), end: dvec2(4.0, 0.0) },
+            Curve2d::Arc {
+                center: dvec2(4.0, 2.0),
+                radius: 2.0,
+                start_angle: 1.5 * std::f64::consts::PI,
+                end_angle: TAU,
+            },
+            bezier(vec![dvec2(6.0, 2.0), dvec2(7.0, 3.0), dvec2(8.0, 3.0), dvec2(9.0, 2.0)]),
+        ];
+
+        let mut svg = vec![];
+        SvgWriter::new().curves("outline", curves.clone()).write(&mut svg).unwrap();
+        let read = read_svg(svg.as_slice(), "outline").unwrap();
+
+        assert_eq!(read.len(), curves.len());
+        for (read, curve) in read.iter().zip(&curves) {
+            assert!(read.start_point().abs_diff_eq(curve.start_point(), 1.0e-9));
+            assert!(read.end_point().abs_diff_eq(curve.end_point(), 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn relative_path_in_transformed_layer() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <!-- A 2x2 square -->
+            <g id="other"><path d="M 0 0 L 1 1"/></g>
+            <g id="cut" transform="translate(10, 0)"><path d="m0 0h2v2h-2z"/></g>
+        </svg>"#;
+
+        let curves = read_svg(svg.as_bytes(), "cut").unwrap();
+
+        assert_eq!(curves.len(), 4);
+        assert_eq!(curves[1], Curve2d::Line { start: dvec2(12.0, 0.0), end: dvec2(12.0, -2.0) });
+        assert_eq!(curves[3].end_point(), dvec2(10.0, 0.0));
+        assert!(read_svg(svg.as_bytes(), "missing").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{
+    geometry::CoordinateSystem,
+    primitives::{make_dir, Shape, Vertex, Wire},
+    Error,
+};
+use glam::DVec3;
+use opencascade_sys as ffi;
+
+/// Controls how the profiles are oriented as they travel along the spine.
+#[derive(Copy, Clone, Default)]
+pub enum SweepMode<'a> {
+    /// Follow the Frenet trihedron of the spine. Profiles can twist on spines
+    /// with inflection points.
+    Frenet,
+
+    /// A Frenet trihedron corrected to minimize twisting.
+    #[default]
+    CorrectedFrenet,
+
+    /// Keep the profiles at the fixed orientation of a coordinate system.
+    Fixed(CoordinateSystem),
+
+    /// Keep the binormal of the trihedron constant. Useful for helical spines,
+    /// where `binormal` is the helix axis.
+    Binormal(DVec3),
+
+    /// Derive the normal of the trihedron from the direction to a second,
+    /// auxiliary spine.
+    AuxiliarySpine {
+        spine: &'a Wire,
+        curvilinear_equivalence: bool,
+        contact: AuxiliarySpineContact,
+    },
+
+    /// Build the trihedron from a discrete approximation of the spine. This is
+    /// often the most robust choice for paths made of straight segments.
+    Discrete,
+}
+
+/// Whether the swept shape must touch the auxiliary spine of [`SweepMode::AuxiliarySpine`].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum AuxiliarySpineContact {
+    #[default]
+    NoContact,
+    Contact,
+    ContactOnBorder,
+}
+
+impl From<AuxiliarySpineContact> for ffi::b_rep_offset_api::BRepFill_TypeOfContact {
+    fn from(contact: AuxiliarySpineContact) -> Self {
+        match contact {
+            AuxiliarySpineContact::NoContact => Self::BRepFill_NoContact,
+            AuxiliarySpineContact::Contact => Self::BRepFill_Contact,
+            AuxiliarySpineContact::ContactOnBorder => Self::BRepFill_ContactOnBorder,
+        }
+    }
+}
+
+/// How the sweep is continued at corners of the spine where it isn't tangent
+/// continuous.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum TransitionMode {
+    /// Discontinuities are treated by modifying the trihedron.
+    #[default]
+    Transformed,
+
+    /// Sweep up to the corner and intersect the two segments there.
+    RightCorner,
+
+    /// Fill the corner by rotating the profile around it.
+    RoundCorner,
+}
+
+impl From<TransitionMode> for ffi::b_rep_offset_api::BRepBuilderAPI_TransitionMode {
+    fn from(mode: TransitionMode) -> Self {
+        match mode {
+            TransitionMode::Transformed => Self::BRepBuilderAPI_Transformed,
+            TransitionMode::RightCorner => Self::BRepBuilderAPI_RightCorner,
+            TransitionMode::RoundCorner => Self::BRepBuilderAPI_RoundCorner,
+        }
+    }
+}
+
+/// Sweeps one or more profiles along a spine, built with `BRepOffsetAPI_MakePipeShell`.
+pub struct SweepBuilder<'a> {
+    spine: &'a Wire,
+    profiles: Vec<(&'a Wire, Option<&'a Vertex>)>,
+    mode: SweepMode<'a>,
+    transition: TransitionMode,
+    with_contact: bool,
+    with_correction: bool,
+    solid: bool,
+}
+
+impl<'a> SweepBuilder<'a> {
+    pub fn new(spine: &'a Wire) -> Self {
+        Self {
+            spine,
+            profiles: vec![],
+            mode: SweepMode::default(),
+            transition: TransitionMode::default(),
+            with_contact: false,
+            with_correction: false,
+            solid: true,
+        }
+    }
+
+    /// Add a profile to sweep. Profiles without a location are placed at
+    /// their current position along the spine.
+    pub fn profile(mut self, profile: &'a Wire) -> Self {
+        self.profiles.push((profile, None));
+        self
+    }
+
+    /// Add a profile which is moved to the spine vertex `location`.
+    pub fn profile_at(mut self, profile: &'a Wire, location: &'a Vertex) -> Self {
+        self.profiles.push((profile, Some(location)));
+        self
+    }
+
+    pub fn mode(mut self, mode: SweepMode<'a>) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn transition(mut self, transition: TransitionMode) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Translate the profiles so they touch the spine.
+    pub fn with_contact(mut self, with_contact: bool) -> Self {
+        self.with_contact = with_contact;
+        self
+    }
+
+    /// Rotate the profiles so they are orthogonal to the spine tangent.
+    pub fn with_correction(mut self, with_correction: bool) -> Self {
+        self.with_correction = with_correction;
+        self
+    }
+
+    /// Close the ends of the sweep to produce a solid. Enabled by default.
+    pub fn solid(mut self, solid: bool) -> Self {
+        self.solid = solid;
+        self
+    }
+
+    pub fn build(self) -> Result<Shape, Error> {
+        let mut make_pipe_shell =
+            ffi::b_rep_offset_api::BRepOffsetAPI_MakePipeShell_new(&self.spine.inner);
+
+        match self.mode {
+            SweepMode::Frenet => make_pipe_shell.pin_mut().SetMode(true),
+            SweepMode::CorrectedFrenet => make_pipe_shell.pin_mut().SetMode(false),
+            SweepMode::Fixed(coordinate_system) => {
+                make_pipe_shell.pin_mut().set_mode_fixed(&coordinate_system.to_gp_ax2())
+            },
+            SweepMode::Binormal(binormal) => {
+                make_pipe_shell.pin_mut().set_mode_binormal(&make_dir(binormal))
+            },
+            SweepMode::AuxiliarySpine { spine, curvilinear_equivalence, contact } => {
+                make_pipe_shell.pin_mut().set_mode_auxiliary_spine(
+                    &spine.inner,
+                    curvilinear_equivalence,
+                    contact.into(),
+                )
+            },
+            SweepMode::Discrete => make_pipe_shell.pin_mut().SetDiscreteMode(),
+        }
+
+        make_pipe_shell.pin_mut().SetTransitionMode(self.transition.into());
+
+        for (profile, location) in &self.profiles {
+            let profile = ffi::topo_ds::cast_wire_to_shape(&profile.inner);
+
+            match location {
+                Some(location) => make_pipe_shell.pin_mut().add_at_vertex(
+                    profile,
+                    &location.inner,
+                    self.with_contact,
+                    self.with_correction,
+                ),
+                None => {
+                    make_pipe_shell.pin_mut().Add(profile, self.with_contact, self.with_correction)
+                },
+            }
+        }
+
+        make_pipe_shell.pin_mut().Build(&ffi::message::Message_ProgressRange_new());
+
+        if !make_pipe_shell.IsDone() {
+            return Err(Error::SweepFailed);
+        }
+
+        if self.solid && !make_pipe_shell.pin_mut().MakeSolid() {
+            return Err(Error::SweepFailed);
+        }
+
+        Ok(Shape::from_shape(make_pipe_shell.pin_mut().Shape()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        angle::Angle,
+        bounding_box::{self, max_z},
+        primitives::Edge,
+        workplane::Workplane,
+    };
+    use glam::dvec3;
+
+    /// A quarter circle of radius 10 from the origin, bending from +Z over to +X.
+    fn quarter_arc() -> Wire {
+        let mid = std::f64::consts::FRAC_1_SQRT_2 * 10.0;
+        Wire::from_edges([&Edge::arc(
+            DVec3::ZERO,
+            dvec3(10.0 - mid, 0.0, mid),
+            dvec3(10.0, 0.0, 10.0),
+        )])
+    }
+
+    #[test]
+    fn sweep_rect_along_line() {
+        let spine = Wire::from_edges([&Edge::segment(DVec3::ZERO, dvec3(0.0, 0.0, 10.0))]);
+        let profile = Workplane::xy().rect(2.0, 2.0);
+
+        let shape = SweepBuilder::new(&spine).profile(&profile).build().unwrap();
+
+        assert_eq!(shape.faces().count(), 6);
+    }
+
+    #[test]
+    fn fixed_mode_keeps_profile_orientation() {
+        let spine = quarter_arc();
+        let profile = Workplane::xy().rect(2.0, 2.0);
+
+        // A fixed profile stays parallel to XY, so it never rises above the spine.
+        let fixed = SweepBuilder::new(&spine)
+            .profile(&profile)
+            .mode(SweepMode::Fixed(CoordinateSystem::world()))
+            .build()
+            .unwrap();
+        assert!(max_z(&fixed) < 10.5);
+
+        // A profile following the spine ends up standing in the YZ plane.
+        let frenet = SweepBuilder::new(&spine).profile(&profile).build().unwrap();
+        assert!(max_z(&frenet) > 10.5);
+    }
+
+    #[test]
+    fn round_corner_transition_adds_faces() {
+        // An open L, with a right angle corner at (0, 0, 10).
+        let spine = Wire::from_edges([
+            &Edge::segment(DVec3::ZERO, dvec3(0.0, 0.0, 10.0)),
+            &Edge::segment(dvec3(0.0, 0.0, 10.0), dvec3(10.0, 0.0, 10.0)),
+        ]);
+        let profile = Workplane::xy().rect(2.0, 2.0);
+
+        let right = SweepBuilder::new(&spine)
+            .profile(&profile)
+            .transition(TransitionMode::RightCorner)
+            .build()
+            .unwrap();
+        let round = SweepBuilder::new(&spine)
+            .profile(&profile)
+            .transition(TransitionMode::RoundCorner)
+            .build()
+            .unwrap();
+
+        assert!(round.faces().count() > right.faces().count());
+    }
+
+    #[test]
+    fn auxiliary_spine_twists_profile() {
+        let spine = Wire::from_edges([&Edge::segment(DVec3::ZERO, dvec3(0.0, 0.0, 10.0))]);
+        // A quarter turn of a helix around the spine, from +X over to +Y.
+        let auxiliary_spine = Wire::helix(40.0, 10.0, 5.0, false, Angle::Degrees(0.0));
+        let profile = Workplane::xy().rect(4.0, 1.0);
+
+        let mode = |contact| SweepMode::AuxiliarySpine {
+            spine: &auxiliary_spine,
+            curvilinear_equivalence: true,
+            contact,
+        };
+
+        // The long side of the profile turns from X over to Y along the sweep.
+        let twisted = SweepBuilder::new(&spine)
+            .profile(&profile)
+            .mode(mode(AuxiliarySpineContact::NoContact))
+            .build()
+            .unwrap();
+        let bounds = bounding_box::aabb(&twisted);
+        assert!((bounds.max() - bounds.gap_vec()).y > 1.5);
+
+        for contact in [AuxiliarySpineContact::Contact, AuxiliarySpineContact::ContactOnBorder] {
+            let shape = SweepBuilder::new(&spine).profile(&profile).mode(mode(contact)).build();
+            assert!(shape.is_ok());
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
     angle::{Angle, RVec},
+    geometry::CoordinateSystem,
     primitives::{Edge, Wire},
 };
 use glam::{dvec3, DAffine3, DMat3, DVec3, EulerRot};
 
+/// The orientation of a [`Workplane`], as named planes or a custom X direction
+/// and normal. See [`crate::geometry::Plane`] for positioned, infinite planes.
 #[derive(Debug, Copy, Clone)]
-pub enum Plane {
+pub enum PlaneOrientation {
     XY,
     YZ,
     ZX,
@@ -21,90 +24,105 @@ pub enum Plane {
     Custom { x_dir: (f64, f64, f64), normal_dir: (f64, f64, f64) },
 }
 
-impl Plane {
+#[deprecated(note = "renamed to `PlaneOrientation`, to avoid confusion with `geometry::Plane`")]
+pub type Plane = PlaneOrientation;
+
+impl PlaneOrientation {
     pub fn transform_point(&self, point: DVec3) -> DVec3 {
         self.transform().transform_point3(point)
     }
 
     pub fn transform(&self) -> DAffine3 {
-        match self {
-            Self::XY => DAffine3::from_cols(DVec3::X, DVec3::Y, DVec3::Z, DVec3::ZERO),
-            Self::YZ => DAffine3::from_cols(DVec3::Y, DVec3::Z, DVec3::X, DVec3::ZERO),
-            Self::ZX => DAffine3::from_cols(DVec3::Z, DVec3::X, DVec3::Y, DVec3::ZERO),
-            Self::XZ => DAffine3::from_cols(DVec3::X, DVec3::Z, DVec3::NEG_Y, DVec3::ZERO),
-            Self::YX => DAffine3::from_cols(DVec3::Y, DVec3::X, DVec3::NEG_Z, DVec3::ZERO),
-            Self::ZY => DAffine3::from_cols(DVec3::Z, DVec3::Y, DVec3::NEG_X, DVec3::ZERO),
-            Self::Front => DAffine3::from_cols(DVec3::X, DVec3::Y, DVec3::Z, DVec3::ZERO),
-            Self::Back => DAffine3::from_cols(DVec3::NEG_X, DVec3::Y, DVec3::NEG_Z, DVec3::ZERO),
-            Self::Left => DAffine3::from_cols(DVec3::Z, DVec3::Y, DVec3::NEG_X, DVec3::ZERO),
-            Self::Right => DAffine3::from_cols(DVec3::NEG_Z, DVec3::Y, DVec3::X, DVec3::ZERO),
-            Self::Top => DAffine3::from_cols(DVec3::X, DVec3::NEG_Z, DVec3::Y, DVec3::ZERO),
-            Self::Bottom => DAffine3::from_cols(DVec3::X, DVec3::Z, DVec3::NEG_Y, DVec3::ZERO),
-            Self::Custom { x_dir, normal_dir } => {
-                let x_axis = dvec3(x_dir.0, x_dir.1, x_dir.2).normalize();
-                let z_axis = dvec3(normal_dir.0, normal_dir.1, normal_dir.2).normalize();
-                let y_axis = z_axis.cross(x_axis).normalize();
-
-                DAffine3::from_cols(x_axis, y_axis, z_axis, DVec3::ZERO)
-            },
-        }
+        self.coordinate_system().transform()
+    }
+
+    /// The coordinate system with this orientation, at the global origin.
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        let (x_dir, normal_dir) = match *self {
+            Self::XY => (DVec3::X, DVec3::Z),
+            Self::YZ => (DVec3::Y, DVec3::X),
+            Self::ZX => (DVec3::Z, DVec3::Y),
+            Self::XZ => (DVec3::X, DVec3::NEG_Y),
+            Self::YX => (DVec3::Y, DVec3::NEG_Z),
+            Self::ZY => (DVec3::Z, DVec3::NEG_X),
+            Self::Front => (DVec3::X, DVec3::Z),
+            Self::Back => (DVec3::NEG_X, DVec3::NEG_Z),
+            Self::Left => (DVec3::Z, DVec3::NEG_X),
+            Self::Right => (DVec3::NEG_Z, DVec3::X),
+            Self::Top => (DVec3::X, DVec3::Y),
+            Self::Bottom => (DVec3::X, DVec3::NEG_Y),
+            Self::Custom { x_dir, normal_dir } => (x_dir.into(), normal_dir.into()),
+        };
+
+        CoordinateSystem::new(DVec3::ZERO, normal_dir, x_dir)
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct Workplane {
-    transform: DAffine3,
+    coordinate_system: CoordinateSystem,
+}
+
+impl From<CoordinateSystem> for Workplane {
+    fn from(coordinate_system: CoordinateSystem) -> Self {
+        Self { coordinate_system }
+    }
+}
+
+impl From<PlaneOrientation> for Workplane {
+    fn from(orientation: PlaneOrientation) -> Self {
+        orientation.coordinate_system().into()
+    }
 }
 
 impl Workplane {
     pub fn new(x_dir: DVec3, normal_dir: DVec3) -> Self {
-        Self {
-            transform: Plane::Custom {
-                x_dir: x_dir.normalize().into(),
-                normal_dir: normal_dir.normalize().into(),
-            }
-            .transform(),
-        }
+        PlaneOrientation::Custom { x_dir: x_dir.into(), normal_dir: normal_dir.into() }.into()
     }
 
     pub fn xy() -> Self {
-        Self { transform: Plane::XY.transform() }
+        PlaneOrientation::XY.into()
     }
 
     pub fn yz() -> Self {
-        Self { transform: Plane::YZ.transform() }
+        PlaneOrientation::YZ.into()
     }
 
     pub fn zx() -> Self {
-        Self { transform: Plane::ZX.transform() }
+        PlaneOrientation::ZX.into()
     }
 
     pub fn xz() -> Self {
-        Self { transform: Plane::XZ.transform() }
+        PlaneOrientation::XZ.into()
     }
 
     pub fn zy() -> Self {
-        Self { transform: Plane::ZY.transform() }
+        PlaneOrientation::ZY.into()
     }
 
     pub fn yx() -> Self {
-        Self { transform: Plane::YX.transform() }
+        PlaneOrientation::YX.into()
     }
 
     pub fn origin(&self) -> DVec3 {
-        self.transform.translation
+        self.coordinate_system.origin()
     }
 
     pub fn normal(&self) -> DVec3 {
-        self.transform.matrix3.z_axis
+        self.coordinate_system.z_dir()
     }
 
     pub fn x_dir(&self) -> DVec3 {
-        self.transform.matrix3.x_axis
+        self.coordinate_system.x_dir()
     }
 
     pub fn y_dir(&self) -> DVec3 {
-        self.transform.matrix3.y_axis
+        self.coordinate_system.y_dir()
+    }
+
+    /// The coordinate system of this workplane, with its Z direction along the normal.
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        self.coordinate_system
     }
 
     // TODO(bschwind) - Test this.
@@ -112,44 +130,30 @@ impl Workplane {
         let rotation_matrix =
             DMat3::from_euler(EulerRot::XYZ, rot_x.radians(), rot_y.radians(), rot_z.radians());
 
-        let translation = self.transform.translation;
-
-        let x_dir = DVec3::X;
-        let normal_dir = DVec3::Z;
-
-        self.transform = Plane::Custom {
-            x_dir: rotation_matrix.mul_vec3(x_dir).into(),
-            normal_dir: rotation_matrix.mul_vec3(normal_dir).into(),
-        }
-        .transform();
-
-        self.set_translation(translation);
+        self.coordinate_system = CoordinateSystem::new(
+            self.origin(),
+            rotation_matrix.mul_vec3(DVec3::Z),
+            rotation_matrix.mul_vec3(DVec3::X),
+        );
     }
 
     pub fn rotate_by(&mut self, (rot_x, rot_y, rot_z): (Angle, Angle, Angle)) {
         let rotation_matrix =
             DMat3::from_euler(EulerRot::XYZ, rot_x.radians(), rot_y.radians(), rot_z.radians());
 
-        let translation = self.transform.translation;
-
-        let x_dir = rotation_matrix.mul_vec3(DVec3::X);
-        let normal_dir = rotation_matrix.mul_vec3(DVec3::Z);
+        let transform = self.coordinate_system.transform();
+        let x_dir = transform.transform_vector3(rotation_matrix.mul_vec3(DVec3::X));
+        let normal_dir = transform.transform_vector3(rotation_matrix.mul_vec3(DVec3::Z));
 
-        self.transform = Plane::Custom {
-            x_dir: self.transform.transform_vector3(x_dir).into(),
-            normal_dir: self.transform.transform_vector3(normal_dir).into(),
-        }
-        .transform();
-
-        self.set_translation(translation);
+        self.coordinate_system = CoordinateSystem::new(self.origin(), normal_dir, x_dir);
     }
 
     pub fn set_translation(&mut self, pos: DVec3) {
-        self.transform.translation = pos;
+        self.coordinate_system = CoordinateSystem::new(pos, self.normal(), self.x_dir());
     }
 
     pub fn translate_by(&mut self, offset: DVec3) {
-        self.transform.translation += offset;
+        self.set_translation(self.origin() + offset);
     }
 
     pub fn transformed(&self, offset: DVec3, rotate: RVec) -> Self {
@@ -157,15 +161,14 @@ impl Workplane {
         let new_origin = new.to_world_pos(offset);
 
         new.rotate_by((rotate.x, rotate.y, rotate.z));
-        new.transform.translation = new_origin;
+        new.set_translation(new_origin);
 
         new
     }
 
     pub fn translated(&self, offset: DVec3) -> Self {
         let mut new = self.clone();
-        let new_origin = new.to_world_pos(offset);
-        new.transform.translation = new_origin;
+        new.set_translation(self.to_world_pos(offset));
 
         new
     }
@@ -178,11 +181,11 @@ impl Workplane {
     }
 
     pub fn to_world_pos(&self, pos: DVec3) -> DVec3 {
-        self.transform.transform_point3(pos)
+        self.coordinate_system.transform().transform_point3(pos)
     }
 
     pub fn to_local_pos(&self, pos: DVec3) -> DVec3 {
-        self.transform.inverse().transform_point3(pos)
+        self.coordinate_system.transform().inverse().transform_point3(pos)
     }
 
     pub fn rect(&self, width: f64, height: f64) -> Wire {
@@ -315,3 +318,22 @@ impl Sketch {
         Wire::from_edges(&self.edges)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::angle::{rvec, ToAngle};
+
+    #[test]
+    fn workplane_follows_its_coordinate_system() {
+        let workplane = Workplane::xz().translated(dvec3(1.0, 2.0, 3.0));
+
+        assert_eq!(workplane.normal(), DVec3::NEG_Y);
+        assert_eq!(workplane.origin(), dvec3(1.0, -3.0, 2.0));
+        assert_eq!(workplane.to_world_pos(DVec3::X), dvec3(2.0, -3.0, 2.0));
+
+        let rotated = workplane.rotated(rvec(0.degrees(), 0.degrees(), 90.degrees()));
+        assert!(rotated.x_dir().abs_diff_eq(DVec3::Z, 1.0e-9));
+        assert_eq!(rotated.origin(), workplane.origin());
+    }
+}
@@ -1,28 +1,41 @@
-// This example demonstrates using a bezier surface to create an airfoil like shape
+// This example demonstrates lofting a tapered wing through bezier airfoil sections
 
-use glam::dvec3;
-use opencascade::primitives::{Face, Shape, Surface};
+use glam::{dvec3, DVec3};
+use opencascade::{
+    loft::LoftBuilder,
+    primitives::{Edge, Shape, Wire},
+};
 
-pub fn shape() -> Shape {
-    let points1 = [
+fn airfoil_section(scale: f64, offset: DVec3) -> Wire {
+    let upper = [
         dvec3(0.0, 0.0, 0.0),
         dvec3(5.0, 0.0, 10.0),
         dvec3(20.0, 0.0, 10.0),
         dvec3(50.0, 0.0, 10.0),
         dvec3(100.0, 0.0, 0.0),
     ];
-    let points2 = [
-        dvec3(0.0, 0.0, 0.0),
-        dvec3(-2.0, 0.0, -8.0),
+    let lower = [
+        dvec3(100.0, 0.0, 0.0),
         dvec3(50.0, 0.0, 0.0),
         dvec3(50.0, 0.0, 0.0),
-        dvec3(100.0, 0.0, 0.0),
+        dvec3(-2.0, 0.0, -8.0),
+        dvec3(0.0, 0.0, 0.0),
     ];
 
-    let surface = Surface::bezier([points1, points2]);
-    let face = Face::from_surface(&surface);
+    let upper = Edge::bezier(upper.map(|p| p * scale + offset));
+    let lower = Edge::bezier(lower.map(|p| p * scale + offset));
 
-    let airfoil = face.extrude(dvec3(0.0, 50.0, 0.0));
+    Wire::from_edges([&upper, &lower])
+}
+
+pub fn shape() -> Shape {
+    let root = airfoil_section(1.0, DVec3::ZERO);
+    let middle = airfoil_section(0.8, dvec3(10.0, 25.0, 0.0));
+    let tip = airfoil_section(0.5, dvec3(30.0, 50.0, 0.0));
 
-    airfoil.into()
+    LoftBuilder::new()
+        .sections([&root, &middle, &tip])
+        .smoothing(true)
+        .build()
+        .expect("failed to loft wing")
 }
@@ -29,7 +29,8 @@ pub fn shape() -> Shape {
         .into_shape();
 
     // Compute the intersection edges between the swept shape and the transformed rectangle
-    let edges = section::edges(&shape, &p);
+    let edges: Vec<Shape> =
+        section::edges(&shape, &p).into_iter().map(|e| e.into_shape()).collect();
 
     // Combine the intersection edges, the swept shape, and the rectangle's edges into a compound shape
     let all_shapes = [